@@ -0,0 +1,35 @@
+use crate::*;
+use runtime::RtLimits;
+
+pub struct CompletionsAction {
+    pub shell: Shell,
+}
+
+impl ActionMethods for CompletionsAction {
+    fn validate(&self, actions: &[Action]) -> Result<(), E> {
+        if actions
+            .iter()
+            .any(|act| !matches!(act, Action::Completions(..) | Action::Scenario(..)))
+        {
+            Err(E::StandaloneParameter(
+                Parameters::Completions.key().join(", "),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+    fn run(&self, artifacts: &mut Vec<ActionArtifact>) -> Result<RunArtifact, E> {
+        let scenario = if let Some(ActionArtifact::Scenario(scenario)) = artifacts
+            .iter()
+            .find(|art| matches!(art, ActionArtifact::Scenario(..)))
+            .cloned()
+        {
+            scenario
+        } else {
+            Scenario::new()?
+        };
+        let script = Script::new(scenario, None, None, None, RtLimits::default())?;
+        print!("{}", Completions::new(script.tasks()).script(&self.shell));
+        Ok(RunArtifact::Void)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::*;
+use driver::Driver;
+use std::fs;
+
+pub struct FmtAction {
+    pub check: bool,
+}
+
+impl ActionMethods for FmtAction {
+    fn validate(&self, actions: &[Action]) -> Result<(), E> {
+        if actions
+            .iter()
+            .any(|act| !matches!(act, Action::Fmt(..) | Action::Scenario(..)))
+            || actions
+                .iter()
+                .filter(|act| matches!(act, Action::Fmt(..)))
+                .count()
+                > 1
+        {
+            Err(E::StandaloneParameter(
+                [Parameters::Fmt.key(), Parameters::FmtCheck.key()]
+                    .concat()
+                    .join(", "),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+    fn run(&self, artifacts: &mut Vec<ActionArtifact>) -> Result<RunArtifact, E> {
+        let scenario = if let Some(ActionArtifact::Scenario(scenario)) = artifacts
+            .iter()
+            .find(|art| matches!(art, ActionArtifact::Scenario(..)))
+            .cloned()
+        {
+            scenario
+        } else {
+            Scenario::new()?
+        };
+        let filepath = scenario.filepath.to_string_lossy().to_string();
+        let mut driver = Driver::new(&scenario.filepath, true);
+        driver.read()?;
+        let formatted = match driver.format() {
+            Ok(formatted) => formatted.text(),
+            Err(err) => {
+                driver.print_errs()?;
+                return Err(err.into());
+            }
+        };
+        let content = fs::read_to_string(&scenario.filepath)?;
+        if content == formatted {
+            println!("{filepath} is formatted");
+        } else if self.check {
+            return Err(E::NotFormatted(filepath));
+        } else {
+            fs::write(&scenario.filepath, formatted)?;
+            println!("{filepath} has been formatted");
+        }
+        Ok(RunArtifact::Void)
+    }
+}
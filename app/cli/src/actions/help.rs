@@ -1,4 +1,5 @@
 use crate::*;
+use runtime::RtLimits;
 
 pub struct HelpAction {
     pub inner: bool,
@@ -10,10 +11,16 @@ impl ActionMethods for HelpAction {
     }
     fn run(&self, artifacts: &mut Vec<ActionArtifact>) -> Result<RunArtifact, E> {
         if self.inner {
-            let lines: Vec<String> = Parameters::as_vec()
+            let mut lines: Vec<String> = Parameters::as_vec()
                 .iter()
                 .map(|param| format!("[b]{}[/b][>>]: {}", param.key().join(", "), param.desc()))
                 .collect();
+            lines.push("\n[b]Exit codes[/b]".to_owned());
+            lines.extend(
+                EXIT_CODES
+                    .iter()
+                    .map(|(code, desc)| format!("[b]{code}[/b][>>]: {desc}")),
+            );
             term::print(lines.join("\n"));
             return Ok(RunArtifact::Void);
         }
@@ -40,7 +47,7 @@ impl ActionMethods for HelpAction {
         } else {
             Scenario::new()?
         };
-        let script = Script::new(scenario, component, task, None)?;
+        let script = Script::new(scenario, component, task, None, RtLimits::default())?;
         script.print()?;
         Ok(RunArtifact::Void)
     }
@@ -0,0 +1,38 @@
+use std::time::Duration;
+
+use crate::*;
+use runtime::RtLimits;
+
+/// Overriding of the default limit of execution
+#[derive(Clone, Debug)]
+pub enum Limit {
+    Iterations(Option<usize>),
+    Depth(Option<usize>),
+    Timeout(Option<Duration>),
+    Jobs(Option<usize>),
+}
+
+impl Limit {
+    /// Applies all given limits to defaults
+    pub fn collect(artifacts: &[ActionArtifact]) -> RtLimits {
+        let mut limits = RtLimits::default();
+        artifacts.iter().for_each(|art| match art {
+            ActionArtifact::Limit(Limit::Iterations(vl)) => limits.iterations = *vl,
+            ActionArtifact::Limit(Limit::Depth(vl)) => limits.depth = *vl,
+            ActionArtifact::Limit(Limit::Timeout(vl)) => limits.timeout = *vl,
+            ActionArtifact::Limit(Limit::Jobs(vl)) => limits.jobs = *vl,
+            _ => {}
+        });
+        limits
+    }
+}
+
+pub struct LimitAction {
+    pub limit: Limit,
+}
+
+impl ActionMethods for LimitAction {
+    fn artifact(&self, _actions: &[Action]) -> Result<Vec<ActionArtifact>, E> {
+        Ok(vec![ActionArtifact::Limit(self.limit.clone())])
+    }
+}
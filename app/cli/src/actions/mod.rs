@@ -1,6 +1,11 @@
+mod completions;
 mod component;
+mod format;
 mod help;
+mod limit;
 mod lsp;
+mod progress;
+mod report;
 mod scenario;
 mod sessions;
 mod task;
@@ -9,10 +14,16 @@ mod version;
 use core::fmt;
 
 use crate::*;
+use runtime::ProgressMode;
 
+pub(crate) use completions::*;
 pub(crate) use component::*;
+pub(crate) use format::*;
 pub(crate) use help::*;
+pub(crate) use limit::*;
 pub(crate) use lsp::*;
+pub(crate) use progress::*;
+pub(crate) use report::*;
 pub(crate) use scenario::*;
 pub(crate) use sessions::*;
 pub(crate) use task::*;
@@ -26,6 +37,12 @@ pub enum ActionArtifact {
     Task(String, Vec<String>),
     /// `String` - name of component
     Component(String),
+    /// Overriding of the default limit of execution
+    Limit(Limit),
+    /// Mode of progress rendering
+    Progress(ProgressMode),
+    /// Report requested after the run
+    Report(Report),
     HelpRequest,
 }
 
@@ -56,6 +73,11 @@ pub enum Action {
     Version(VersionAction),
     Sessions(SessionsAction),
     Lsp(LspAction),
+    Fmt(FmtAction),
+    Limit(LimitAction),
+    Progress(ProgressAction),
+    Report(ReportAction),
+    Completions(CompletionsAction),
 }
 
 impl fmt::Debug for Action {
@@ -68,6 +90,11 @@ impl fmt::Debug for Action {
             Self::Version(_) => write!(f, "VersionAction"),
             Self::Sessions(_) => write!(f, "SessionsAction"),
             Self::Lsp(_) => write!(f, "LspAction"),
+            Self::Fmt(_) => write!(f, "FmtAction"),
+            Self::Limit(_) => write!(f, "LimitAction"),
+            Self::Progress(_) => write!(f, "ProgressAction"),
+            Self::Report(_) => write!(f, "ReportAction"),
+            Self::Completions(_) => write!(f, "CompletionsAction"),
         }
     }
 }
@@ -82,6 +109,11 @@ impl ActionMethods for Action {
             Self::Version(act) => act.validate(actions),
             Self::Sessions(act) => act.validate(actions),
             Self::Lsp(act) => act.validate(actions),
+            Self::Fmt(act) => act.validate(actions),
+            Self::Limit(act) => act.validate(actions),
+            Self::Progress(act) => act.validate(actions),
+            Self::Report(act) => act.validate(actions),
+            Self::Completions(act) => act.validate(actions),
         }
     }
     fn artifact(&self, actions: &[Action]) -> Result<Vec<ActionArtifact>, E> {
@@ -93,6 +125,11 @@ impl ActionMethods for Action {
             Self::Version(act) => act.artifact(actions),
             Self::Sessions(act) => act.artifact(actions),
             Self::Lsp(act) => act.artifact(actions),
+            Self::Fmt(act) => act.artifact(actions),
+            Self::Limit(act) => act.artifact(actions),
+            Self::Progress(act) => act.artifact(actions),
+            Self::Report(act) => act.artifact(actions),
+            Self::Completions(act) => act.artifact(actions),
         }
     }
     fn run(&self, artifacts: &mut Vec<ActionArtifact>) -> Result<RunArtifact, E> {
@@ -104,6 +141,11 @@ impl ActionMethods for Action {
             Self::Version(act) => act.run(artifacts),
             Self::Sessions(act) => act.run(artifacts),
             Self::Lsp(act) => act.run(artifacts),
+            Self::Fmt(act) => act.run(artifacts),
+            Self::Limit(act) => act.run(artifacts),
+            Self::Progress(act) => act.run(artifacts),
+            Self::Report(act) => act.run(artifacts),
+            Self::Completions(act) => act.run(artifacts),
         }
     }
 }
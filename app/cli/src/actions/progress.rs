@@ -0,0 +1,25 @@
+use crate::*;
+use runtime::ProgressMode;
+
+pub struct ProgressAction {
+    pub mode: ProgressMode,
+}
+
+impl ProgressAction {
+    /// Returns the mode of progress if it's defined
+    pub fn collect(artifacts: &[ActionArtifact]) -> Option<ProgressMode> {
+        artifacts.iter().find_map(|art| {
+            if let ActionArtifact::Progress(mode) = art {
+                Some(*mode)
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl ActionMethods for ProgressAction {
+    fn artifact(&self, _actions: &[Action]) -> Result<Vec<ActionArtifact>, E> {
+        Ok(vec![ActionArtifact::Progress(self.mode)])
+    }
+}
@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use crate::*;
+
+/// Report, which is produced after the run of task
+#[derive(Clone, Debug)]
+pub enum Report {
+    /// Table of timings printed into stderr
+    Timings,
+    /// Chrome trace events written into the file
+    Trace(PathBuf),
+}
+
+impl Report {
+    /// Returns all requested reports
+    pub fn collect(artifacts: &[ActionArtifact]) -> Vec<Report> {
+        artifacts
+            .iter()
+            .filter_map(|art| {
+                if let ActionArtifact::Report(report) = art {
+                    Some(report.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct ReportAction {
+    pub report: Report,
+}
+
+impl ActionMethods for ReportAction {
+    fn artifact(&self, _actions: &[Action]) -> Result<Vec<ActionArtifact>, E> {
+        Ok(vec![ActionArtifact::Report(self.report.clone())])
+    }
+}
@@ -42,17 +42,23 @@ impl ActionMethods for TaskAction {
         };
         let scenario = if let Some(ActionArtifact::Scenario(scenario)) = artifacts
             .iter()
-            .find(|art| matches!(art, ActionArtifact::Scenario(..))).cloned()
+            .find(|art| matches!(art, ActionArtifact::Scenario(..)))
+            .cloned()
         {
             scenario
         } else {
             Scenario::new()?
         };
-        Ok(RunArtifact::Script(Script::new(
-            scenario,
-            Some(component),
-            Some(self.name.clone()),
-            Some(self.args.clone()),
-        )?))
+        Ok(RunArtifact::Script(
+            Script::new(
+                scenario,
+                Some(component),
+                Some(self.name.clone()),
+                Some(self.args.clone()),
+                Limit::collect(artifacts),
+            )?
+            .with_progress(ProgressAction::collect(artifacts))
+            .with_reports(Report::collect(artifacts)),
+        ))
     }
 }
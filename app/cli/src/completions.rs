@@ -0,0 +1,329 @@
+use runtime::{DeterminedTy, RtValue, TaskEntity, Ty};
+
+use crate::*;
+
+/// Shell to generate completions for
+#[derive(Clone, Debug)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl TryFrom<&str> for Shell {
+    type Error = E;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "bash" => Ok(Self::Bash),
+            "zsh" => Ok(Self::Zsh),
+            "fish" => Ok(Self::Fish),
+            _ => Err(E::UnknownShell(value.to_owned())),
+        }
+    }
+}
+
+/// Argument of task as it's suggested by completions
+struct CompletionArg {
+    name: String,
+    /// Boolean argument can be given as a flag without value
+    flag: bool,
+    /// Allowed values of argument
+    values: Vec<String>,
+}
+
+impl From<&runtime::TaskArgDeclaration> for CompletionArg {
+    fn from(arg: &runtime::TaskArgDeclaration) -> Self {
+        Self {
+            name: arg.ident.clone(),
+            flag: matches!(arg.ty, Ty::Determined(DeterminedTy::Bool)),
+            values: arg
+                .variants
+                .iter()
+                .filter_map(|vl| match vl {
+                    RtValue::Str(vl) => Some(vl.clone()),
+                    RtValue::Num(vl) => Some(vl.to_string()),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Name of task and its arguments
+type TaskCompletion = (String, Vec<CompletionArg>);
+
+/// Name of component and its tasks
+type ComponentCompletion = (String, Vec<TaskCompletion>);
+
+/// Components, their tasks and arguments of tasks
+pub struct Completions {
+    components: Vec<ComponentCompletion>,
+}
+
+impl Completions {
+    pub fn new(tasks: Vec<&TaskEntity>) -> Self {
+        let mut components: Vec<ComponentCompletion> = Vec::new();
+        for task in tasks.into_iter() {
+            let args = task.args.iter().map(CompletionArg::from).collect();
+            if let Some((_, tasks)) = components
+                .iter_mut()
+                .find(|(name, _)| name == &task.master.name)
+            {
+                tasks.push((task.name.clone(), args));
+            } else {
+                components.push((task.master.name.clone(), vec![(task.name.clone(), args)]));
+            }
+        }
+        Self { components }
+    }
+
+    pub fn script(&self, shell: &Shell) -> String {
+        match shell {
+            Shell::Bash => self.bash(),
+            Shell::Zsh => self.zsh(),
+            Shell::Fish => self.fish(),
+        }
+    }
+
+    /// Returns first level words: components and parameters of sibs
+    fn top_words(&self) -> Vec<String> {
+        self.components
+            .iter()
+            .map(|(name, _)| name.clone())
+            .chain(Parameters::as_vec().iter().flat_map(|param| param.key()))
+            .collect()
+    }
+
+    /// Returns words, which could be given as arguments of task
+    fn arg_words(args: &[CompletionArg]) -> Vec<String> {
+        args.iter()
+            .flat_map(|arg| {
+                let mut words = Vec::new();
+                if arg.flag {
+                    words.push(format!("--{}", arg.name));
+                } else if arg.values.is_empty() {
+                    words.push(format!("--{}=", arg.name));
+                } else {
+                    words.extend(arg.values.iter().map(|vl| format!("--{}={vl}", arg.name)));
+                }
+                words
+            })
+            .collect()
+    }
+
+    fn bash(&self) -> String {
+        let mut tasks = Vec::new();
+        let mut args = Vec::new();
+        for (component, comp_tasks) in self.components.iter() {
+            tasks.push(format!(
+                "            {component}) words=\"{}\" ;;",
+                comp_tasks
+                    .iter()
+                    .map(|(task, _)| task.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            ));
+            for (task, task_args) in comp_tasks.iter() {
+                args.push(format!(
+                    "            {component}:{task}) words=\"{}\" ;;",
+                    Self::arg_words(task_args).join(" ")
+                ));
+            }
+        }
+        format!(
+            r#"_sibs() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}"
+    local words=""
+    if [ "$COMP_CWORD" -eq 1 ]; then
+        words="{}"
+    elif [ "$COMP_CWORD" -eq 2 ]; then
+        case "${{COMP_WORDS[1]}}" in
+{}
+        esac
+    else
+        case "${{COMP_WORDS[1]}}:${{COMP_WORDS[2]}}" in
+{}
+        esac
+    fi
+    COMPREPLY=($(compgen -W "$words" -- "$cur"))
+}}
+complete -o nospace -F _sibs sibs
+"#,
+            self.top_words().join(" "),
+            tasks.join("\n"),
+            args.join("\n")
+        )
+    }
+
+    fn zsh(&self) -> String {
+        let mut tasks = Vec::new();
+        let mut args = Vec::new();
+        for (component, comp_tasks) in self.components.iter() {
+            tasks.push(format!(
+                "                {component}) candidates=({}) ;;",
+                comp_tasks
+                    .iter()
+                    .map(|(task, _)| task.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            ));
+            for (task, task_args) in comp_tasks.iter() {
+                args.push(format!(
+                    "                {component}:{task}) candidates=({}) ;;",
+                    Self::arg_words(task_args)
+                        .iter()
+                        .map(|word| format!("'{word}'"))
+                        .collect::<Vec<String>>()
+                        .join(" ")
+                ));
+            }
+        }
+        format!(
+            r#"#compdef sibs
+
+_sibs() {{
+    local -a candidates
+    case $CURRENT in
+        2)
+            candidates=({})
+            ;;
+        3)
+            case $words[2] in
+{}
+            esac
+            ;;
+        *)
+            case "$words[2]:$words[3]" in
+{}
+            esac
+            ;;
+    esac
+    compadd -S '' -- $candidates
+}}
+
+compdef _sibs sibs
+"#,
+            self.top_words().join(" "),
+            tasks.join("\n"),
+            args.join("\n")
+        )
+    }
+
+    fn fish(&self) -> String {
+        let mut lines = vec![
+            String::from("complete -c sibs -f"),
+            format!(
+                "complete -c sibs -n \"test (count (commandline -opc)) -eq 1\" -a \"{}\"",
+                self.top_words().join(" ")
+            ),
+        ];
+        for (component, comp_tasks) in self.components.iter() {
+            lines.push(format!(
+                "complete -c sibs -n \"test (count (commandline -opc)) -eq 2; and test (commandline -opc)[2] = {component}\" -a \"{}\"",
+                comp_tasks
+                    .iter()
+                    .map(|(task, _)| task.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(" ")
+            ));
+            for (task, task_args) in comp_tasks.iter() {
+                let condition = format!(
+                    "test (count (commandline -opc)) -ge 3; and test (commandline -opc)[2] = {component}; and test (commandline -opc)[3] = {task}"
+                );
+                for arg in task_args.iter() {
+                    lines.push(if arg.flag {
+                        format!("complete -c sibs -n \"{condition}\" -l {}", arg.name)
+                    } else if arg.values.is_empty() {
+                        format!("complete -c sibs -n \"{condition}\" -l {} -r", arg.name)
+                    } else {
+                        format!(
+                            "complete -c sibs -n \"{condition}\" -l {} -x -a \"{}\"",
+                            arg.name,
+                            arg.values.join(" ")
+                        )
+                    });
+                }
+            }
+        }
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn completions() -> Completions {
+        let script = script::tests::script(script::tests::CONTENT, None, None);
+        Completions::new(script.tasks())
+    }
+
+    #[test]
+    fn top_words() {
+        let words = completions().top_words();
+        assert_eq!(words[..2], ["app", "docs"]);
+        assert!(words.contains(&String::from("--help")));
+        assert!(words.contains(&String::from("--scenario")));
+    }
+
+    #[test]
+    fn scripts() {
+        let completions = completions();
+        let deploy = "test (count (commandline -opc)) -ge 3; and test (commandline -opc)[2] = app; and test (commandline -opc)[3] = deploy";
+        for (shell, lines) in [
+            (
+                Shell::Bash,
+                vec![
+                    String::from("        words=\"app docs --help -h "),
+                    String::from("            app) words=\"build deploy\" ;;"),
+                    String::from("            docs) words=\"serve\" ;;"),
+                    String::from("            app:build) words=\"\" ;;"),
+                    String::from(
+                        "            app:deploy) words=\"--env=dev --env=prod --replicas= --dry\" ;;",
+                    ),
+                    String::from("            docs:serve) words=\"--port=8080 --port=8081\" ;;"),
+                    String::from("complete -o nospace -F _sibs sibs"),
+                ],
+            ),
+            (
+                Shell::Zsh,
+                vec![
+                    String::from("#compdef sibs"),
+                    String::from("            candidates=(app docs --help -h "),
+                    String::from("                app) candidates=(build deploy) ;;"),
+                    String::from("                app:build) candidates=() ;;"),
+                    String::from(
+                        "                app:deploy) candidates=('--env=dev' '--env=prod' '--replicas=' '--dry') ;;",
+                    ),
+                    String::from(
+                        "                docs:serve) candidates=('--port=8080' '--port=8081') ;;",
+                    ),
+                    String::from("compdef _sibs sibs"),
+                ],
+            ),
+            (
+                Shell::Fish,
+                vec![
+                    String::from("complete -c sibs -f\n"),
+                    String::from(
+                        "complete -c sibs -n \"test (count (commandline -opc)) -eq 1\" -a \"app docs --help -h ",
+                    ),
+                    String::from(
+                        "complete -c sibs -n \"test (count (commandline -opc)) -eq 2; and test (commandline -opc)[2] = app\" -a \"build deploy\"\n",
+                    ),
+                    format!("complete -c sibs -n \"{deploy}\" -l env -x -a \"dev prod\"\n"),
+                    format!("complete -c sibs -n \"{deploy}\" -l replicas -r\n"),
+                    format!("complete -c sibs -n \"{deploy}\" -l dry\n"),
+                    String::from("[3] = serve\" -l port -x -a \"8080 8081\"\n"),
+                ],
+            ),
+        ] {
+            let script = completions.script(&shell);
+            for line in lines.iter() {
+                assert!(script.contains(line), "{shell:?}: {line}\n{script}");
+            }
+        }
+    }
+}
@@ -11,6 +11,20 @@ pub enum E {
     IO(String),
     #[error("--scenario requires a path to .sibs file")]
     MissedPathWithScenario,
+    #[error("{0} requires a number; use 0 to disable the limit")]
+    MissedLimitValue(String),
+    #[error("{0} requires a number; use 0 to disable the limit. Invalid value: \"{1}\"")]
+    InvalidLimitValue(String, String),
+    #[error("--progress requires a mode: bars, plain, quiet or github")]
+    MissedProgressMode,
+    #[error("Invalid progress mode \"{0}\"; expected bars, plain, quiet or github")]
+    InvalidProgressMode(String),
+    #[error("--trace requires a path to output file")]
+    MissedTracePath,
+    #[error("--completions requires a shell: bash, zsh or fish")]
+    MissedShell,
+    #[error("Unknown shell \"{0}\"; supported shells: bash, zsh, fish")]
+    UnknownShell(String),
     #[error("No arguments to get task's name")]
     FailToGetTaskName,
     #[error("No arguments to get component's name")]
@@ -25,6 +39,8 @@ pub enum E {
     FailToGetCwd(String),
     #[error("Component \"{0}\" doesn't exists")]
     ComponentNotFound(String),
+    #[error("Component \"{0}\" doesn't have task \"{1}\"")]
+    TaskNotFound(String, String),
     #[error("LTS should be run without addition arguments")]
     SelfishLts,
 
@@ -32,6 +48,8 @@ pub enum E {
     FailExtractAnchorNodeFrom(String),
     #[error("Script has been executed already")]
     ScriptAlreadyExecuted,
+    #[error("Execution has been cancelled")]
+    Cancelled,
     #[error("Parser error: {0}")]
     Parser(parser::ParserError),
     #[error("Semantic error: {0}")]
@@ -46,6 +64,11 @@ pub enum E {
 
     #[error("No sessions found")]
     NoSessions,
+
+    #[error("File \"{0}\" isn't formatted")]
+    NotFormatted(String),
+    #[error("Driver error: {0}")]
+    Driver(driver::DriverError),
 }
 
 impl From<std::io::Error> for E {
@@ -54,6 +77,12 @@ impl From<std::io::Error> for E {
     }
 }
 
+impl From<driver::DriverError> for E {
+    fn from(err: driver::DriverError) -> Self {
+        E::Driver(err)
+    }
+}
+
 impl From<scenario::ScenarioError> for E {
     fn from(err: scenario::ScenarioError) -> Self {
         E::Scenario(err)
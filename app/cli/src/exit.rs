@@ -0,0 +1,115 @@
+use crate::*;
+use runtime::RtError;
+pub(crate) use runtime::{
+    EXIT_CANCELLED, EXIT_FAILURE, EXIT_RUNTIME_ERROR, EXIT_SUCCESS, EXIT_TIMEOUT,
+};
+
+/// Invalid parameters of CLI or unknown component/task
+pub const EXIT_USAGE: i32 = 2;
+/// Scenario cannot be parsed
+pub const EXIT_PARSER_ERROR: i32 = 3;
+/// Scenario has semantic errors (types, unknown functions, etc.)
+pub const EXIT_SEMANTIC_ERROR: i32 = 4;
+/// Any other error (IO, reading of scenario, etc.)
+pub const EXIT_INTERNAL_ERROR: i32 = 6;
+
+/// Exit codes of sibs with descriptions; used in help
+pub const EXIT_CODES: [(i32, &str); 9] = [
+    (EXIT_SUCCESS, "task is done"),
+    (
+        EXIT_FAILURE,
+        "task returned false or a command failed without exit code; a failed command returned by task gives its own exit code",
+    ),
+    (EXIT_USAGE, "invalid parameters, unknown component or task"),
+    (EXIT_PARSER_ERROR, "scenario cannot be parsed"),
+    (EXIT_SEMANTIC_ERROR, "scenario has semantic errors"),
+    (EXIT_RUNTIME_ERROR, "runtime error"),
+    (EXIT_INTERNAL_ERROR, "other errors (IO, reading of scenario, etc.)"),
+    (EXIT_TIMEOUT, "time limit (--timeout) has been exceeded"),
+    (EXIT_CANCELLED, "command has been cancelled"),
+];
+
+impl E {
+    /// Returns exit code, which represents the error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::MissedPathWithScenario
+            | Self::MissedLimitValue(..)
+            | Self::InvalidLimitValue(..)
+            | Self::MissedProgressMode
+            | Self::InvalidProgressMode(..)
+            | Self::MissedTracePath
+            | Self::MissedShell
+            | Self::UnknownShell(..)
+            | Self::FailToGetTaskName
+            | Self::FailToGetComponentName
+            | Self::StandaloneParameter(..)
+            | Self::NoComponentParameter
+            | Self::ComponentNotFound(..)
+            | Self::TaskNotFound(..)
+            | Self::SelfishLts
+            | Self::Runtime(RtError::CompNotFound(..) | RtError::TaskNotFound(..)) => EXIT_USAGE,
+            Self::Parser(..) => EXIT_PARSER_ERROR,
+            Self::Semantic(..) => EXIT_SEMANTIC_ERROR,
+            Self::Runtime(err) => err.exit_code(),
+            Self::NotFormatted(..) => EXIT_FAILURE,
+            Self::Cancelled => EXIT_CANCELLED,
+            _ => EXIT_INTERNAL_ERROR,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::*;
+    use runtime::RtError;
+
+    #[test]
+    fn exit_codes() {
+        for (err, code) in [
+            (E::NoComponentParameter, EXIT_USAGE),
+            (E::ComponentNotFound(String::from("a")), EXIT_USAGE),
+            (
+                E::Runtime(RtError::TaskNotFound(String::from("a"), String::from("b"))),
+                EXIT_USAGE,
+            ),
+            (
+                E::Parser(parser::ParserError::MissedComma),
+                EXIT_PARSER_ERROR,
+            ),
+            (
+                E::Semantic(semantic::SemanticError::NoVariantsAreDefined),
+                EXIT_SEMANTIC_ERROR,
+            ),
+            (
+                E::Runtime(RtError::Other(String::new())),
+                EXIT_RUNTIME_ERROR,
+            ),
+            (
+                E::Runtime(RtError::TimeLimit(Duration::from_secs(1))),
+                EXIT_TIMEOUT,
+            ),
+            (E::Cancelled, EXIT_CANCELLED),
+            (E::NotFormatted(String::from("a")), EXIT_FAILURE),
+            (E::IO(String::new()), EXIT_INTERNAL_ERROR),
+            (E::ScriptAlreadyExecuted, EXIT_INTERNAL_ERROR),
+        ] {
+            assert_eq!(err.exit_code(), code, "{err:?}");
+        }
+    }
+
+    #[test]
+    fn codes_are_listed() {
+        for code in [
+            EXIT_USAGE,
+            EXIT_PARSER_ERROR,
+            EXIT_SEMANTIC_ERROR,
+            EXIT_INTERNAL_ERROR,
+            EXIT_CANCELLED,
+        ] {
+            assert!(EXIT_CODES.iter().any(|(listed, _)| *listed == code));
+        }
+    }
+}
@@ -1,18 +1,41 @@
 mod actions;
+mod completions;
 mod error;
+mod exit;
 mod logs;
 mod params;
 mod script;
 
 pub(crate) use actions::*;
+pub(crate) use completions::*;
 pub(crate) use error::*;
+pub(crate) use exit::*;
 pub(crate) use params::*;
 pub(crate) use scenario::*;
 pub(crate) use script::*;
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 4)]
-async fn main() -> Result<(), E> {
-    let guard = logs::init()?;
+async fn main() {
+    let code = match logs::init() {
+        Ok(guard) => {
+            let code = run().await.unwrap_or_else(|err| {
+                eprintln!("Error: {err:?}");
+                err.exit_code()
+            });
+            drop(guard);
+            code
+        }
+        Err(err) => {
+            let err = E::from(err);
+            eprintln!("Error: {err:?}");
+            err.exit_code()
+        }
+    };
+    std::process::exit(code);
+}
+
+/// Runs actions and returns the exit code defined by the result of task
+async fn run() -> Result<i32, E> {
     // Get all actions from parameters
     let actions = Parameters::actions()?;
     // Validate actions
@@ -45,10 +68,11 @@ async fn main() -> Result<(), E> {
         }
     }
     // Run post actions, if exists
+    let mut code = EXIT_SUCCESS;
     for artifact in post_actions.into_iter() {
         match artifact {
             RunArtifact::Script(mut script) => {
-                let _ = script.run().await?;
+                code = script.run().await?.exit_code();
             }
             RunArtifact::Lsp => {
                 lsp::run().await;
@@ -56,6 +80,5 @@ async fn main() -> Result<(), E> {
             RunArtifact::Void => {}
         }
     }
-    drop(guard);
-    Ok(())
+    Ok(code)
 }
@@ -0,0 +1,29 @@
+use crate::*;
+
+const ARGS: [&str; 1] = ["--completions"];
+
+pub struct CompletionsParameter {}
+
+impl Parameter for CompletionsParameter {
+    fn keys() -> Vec<String> {
+        ARGS.iter().map(|s| s.to_string()).collect()
+    }
+    fn desc() -> String {
+        "Prints completions script (bash, zsh or fish) for components and tasks of scenario. Can be combined with --scenario".to_owned()
+    }
+    fn valued() -> bool {
+        true
+    }
+    fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
+        let pos = args.iter().position(|arg| ARGS.contains(&arg.as_str()))?;
+        if args.len() <= pos + 1 {
+            return Some(Err(E::MissedShell));
+        }
+        let shell = args.remove(pos + 1);
+        args.remove(pos);
+        Some(
+            Shell::try_from(shell.as_str())
+                .map(|shell| Action::Completions(CompletionsAction { shell })),
+        )
+    }
+}
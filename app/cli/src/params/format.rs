@@ -0,0 +1,36 @@
+use crate::*;
+
+const ARGS: [&str; 1] = ["--fmt"];
+const CHECK_ARGS: [&str; 1] = ["--fmt-check"];
+
+pub struct FmtParameter {}
+
+impl Parameter for FmtParameter {
+    fn keys() -> Vec<String> {
+        ARGS.iter().map(|s| s.to_string()).collect()
+    }
+    fn desc() -> String {
+        "Formats scenario file (*.sibs). Can be combined with --scenario".to_owned()
+    }
+    fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
+        let pos = args.iter().position(|arg| ARGS.contains(&arg.as_str()))?;
+        args.remove(pos);
+        Some(Ok(Action::Fmt(FmtAction { check: false })))
+    }
+}
+
+pub struct FmtCheckParameter {}
+
+impl Parameter for FmtCheckParameter {
+    fn keys() -> Vec<String> {
+        CHECK_ARGS.iter().map(|s| s.to_string()).collect()
+    }
+    fn desc() -> String {
+        "Checks formatting of scenario file (*.sibs) without changing it. Fails if file isn't formatted".to_owned()
+    }
+    fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
+        let pos = args.iter().position(|arg| CHECK_ARGS.contains(&arg.as_str()))?;
+        args.remove(pos);
+        Some(Ok(Action::Fmt(FmtAction { check: true })))
+    }
+}
@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use crate::*;
+use runtime::DEFAULT_DEPTH_LIMIT;
+
+const ITERATIONS_ARGS: [&str; 1] = ["--max-iterations"];
+const DEPTH_ARGS: [&str; 1] = ["--max-depth"];
+const TIMEOUT_ARGS: [&str; 1] = ["--timeout"];
+const JOBS_ARGS: [&str; 1] = ["--max-jobs"];
+const JOBS_ENV: &str = "SIBS_MAX_JOBS";
+
+/// Extracts the value of limit. `0` means no limit.
+fn value(args: &mut Vec<String>, keys: &[&str]) -> Option<Result<Option<u64>, E>> {
+    let pos = args.iter().position(|arg| keys.contains(&arg.as_str()))?;
+    if args.len() <= pos + 1 {
+        return Some(Err(E::MissedLimitValue(keys.join(", "))));
+    }
+    let vl = args.remove(pos + 1);
+    args.remove(pos);
+    Some(parse(vl, &keys.join(", ")))
+}
+
+/// Parses limit from a string. `0` means no limit.
+fn parse(vl: String, key: &str) -> Result<Option<u64>, E> {
+    vl.parse::<u64>()
+        .map(|vl| if vl == 0 { None } else { Some(vl) })
+        .map_err(|_| E::InvalidLimitValue(key.to_owned(), vl))
+}
+
+pub struct MaxIterationsParameter {}
+
+impl Parameter for MaxIterationsParameter {
+    fn keys() -> Vec<String> {
+        ITERATIONS_ARGS.iter().map(|s| s.to_string()).collect()
+    }
+    fn desc() -> String {
+        "Max number of iterations of a single loop (loop, while, for); 0 - no limit. Default: no limit"
+            .to_owned()
+    }
+    fn valued() -> bool {
+        true
+    }
+    fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
+        Some(value(args, &ITERATIONS_ARGS)?.map(|vl| {
+            Action::Limit(LimitAction {
+                limit: Limit::Iterations(vl.map(|vl| vl as usize)),
+            })
+        }))
+    }
+}
+
+pub struct MaxDepthParameter {}
+
+impl Parameter for MaxDepthParameter {
+    fn keys() -> Vec<String> {
+        DEPTH_ARGS.iter().map(|s| s.to_string()).collect()
+    }
+    fn desc() -> String {
+        format!(
+            "Max depth of calls of functions and closures; 0 - no limit. Default: {DEFAULT_DEPTH_LIMIT}"
+        )
+    }
+    fn valued() -> bool {
+        true
+    }
+    fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
+        Some(value(args, &DEPTH_ARGS)?.map(|vl| {
+            Action::Limit(LimitAction {
+                limit: Limit::Depth(vl.map(|vl| vl as usize)),
+            })
+        }))
+    }
+}
+
+pub struct TimeoutParameter {}
+
+impl Parameter for TimeoutParameter {
+    fn keys() -> Vec<String> {
+        TIMEOUT_ARGS.iter().map(|s| s.to_string()).collect()
+    }
+    fn desc() -> String {
+        "Max duration of execution in seconds; 0 - no limit. Default: no limit".to_owned()
+    }
+    fn valued() -> bool {
+        true
+    }
+    fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
+        Some(value(args, &TIMEOUT_ARGS)?.map(|vl| {
+            Action::Limit(LimitAction {
+                limit: Limit::Timeout(vl.map(Duration::from_secs)),
+            })
+        }))
+    }
+}
+
+pub struct MaxJobsParameter {}
+
+impl Parameter for MaxJobsParameter {
+    fn keys() -> Vec<String> {
+        JOBS_ARGS.iter().map(|s| s.to_string()).collect()
+    }
+    fn desc() -> String {
+        format!(
+            "Max number of commands running at the same time; 0 - no limit. Can be set with {JOBS_ENV} env variable. Default: number of CPUs"
+        )
+    }
+    fn valued() -> bool {
+        true
+    }
+    fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
+        // CLI argument has priority over env variable
+        let vl = value(args, &JOBS_ARGS).or_else(|| {
+            std::env::var(JOBS_ENV)
+                .ok()
+                .map(|vl| parse(vl.trim().to_owned(), JOBS_ENV))
+        })?;
+        Some(vl.map(|vl| {
+            Action::Limit(LimitAction {
+                limit: Limit::Jobs(vl.map(|vl| vl as usize)),
+            })
+        }))
+    }
+}
@@ -1,13 +1,23 @@
+mod completions;
+mod format;
 mod help;
+mod limits;
 mod lsp;
+mod progress;
+mod report;
 mod scenario;
 mod sessions;
 mod version;
 
 use crate::*;
 
+pub(crate) use completions::*;
+pub(crate) use format::*;
 pub(crate) use help::*;
+pub(crate) use limits::*;
 pub(crate) use lsp::*;
+pub(crate) use progress::*;
+pub(crate) use report::*;
 pub(crate) use scenario::*;
 pub(crate) use sessions::*;
 pub(crate) use version::*;
@@ -15,6 +25,10 @@ pub(crate) use version::*;
 pub trait Parameter {
     fn keys() -> Vec<String>;
     fn desc() -> String;
+    /// Returns `true` if the key is followed by a value
+    fn valued() -> bool {
+        false
+    }
     fn action(args: &mut Vec<String>) -> Option<Result<Action, E>>;
 }
 
@@ -25,6 +39,16 @@ pub enum Parameters {
     Version,
     Sessions,
     Lsp,
+    Fmt,
+    FmtCheck,
+    MaxIterations,
+    MaxDepth,
+    Timeout,
+    MaxJobs,
+    Progress,
+    Timings,
+    Trace,
+    Completions,
 }
 
 impl Parameters {
@@ -35,6 +59,35 @@ impl Parameters {
             Self::Version => VersionParameter::keys(),
             Self::Sessions => SessionsParameter::keys(),
             Self::Lsp => LspParameter::keys(),
+            Self::Fmt => FmtParameter::keys(),
+            Self::FmtCheck => FmtCheckParameter::keys(),
+            Self::MaxIterations => MaxIterationsParameter::keys(),
+            Self::MaxDepth => MaxDepthParameter::keys(),
+            Self::Timeout => TimeoutParameter::keys(),
+            Self::MaxJobs => MaxJobsParameter::keys(),
+            Self::Progress => ProgressParameter::keys(),
+            Self::Timings => TimingsParameter::keys(),
+            Self::Trace => TraceParameter::keys(),
+            Self::Completions => CompletionsParameter::keys(),
+        }
+    }
+    pub fn valued(&self) -> bool {
+        match self {
+            Self::Help => HelpParameter::valued(),
+            Self::Scenario => ScenarioParameter::valued(),
+            Self::Version => VersionParameter::valued(),
+            Self::Sessions => SessionsParameter::valued(),
+            Self::Lsp => LspParameter::valued(),
+            Self::Fmt => FmtParameter::valued(),
+            Self::FmtCheck => FmtCheckParameter::valued(),
+            Self::MaxIterations => MaxIterationsParameter::valued(),
+            Self::MaxDepth => MaxDepthParameter::valued(),
+            Self::Timeout => TimeoutParameter::valued(),
+            Self::MaxJobs => MaxJobsParameter::valued(),
+            Self::Progress => ProgressParameter::valued(),
+            Self::Timings => TimingsParameter::valued(),
+            Self::Trace => TraceParameter::valued(),
+            Self::Completions => CompletionsParameter::valued(),
         }
     }
     pub fn desc(&self) -> String {
@@ -44,6 +97,16 @@ impl Parameters {
             Self::Version => VersionParameter::desc(),
             Self::Sessions => SessionsParameter::desc(),
             Self::Lsp => LspParameter::desc(),
+            Self::Fmt => FmtParameter::desc(),
+            Self::FmtCheck => FmtCheckParameter::desc(),
+            Self::MaxIterations => MaxIterationsParameter::desc(),
+            Self::MaxDepth => MaxDepthParameter::desc(),
+            Self::Timeout => TimeoutParameter::desc(),
+            Self::MaxJobs => MaxJobsParameter::desc(),
+            Self::Progress => ProgressParameter::desc(),
+            Self::Timings => TimingsParameter::desc(),
+            Self::Trace => TraceParameter::desc(),
+            Self::Completions => CompletionsParameter::desc(),
         }
     }
     pub fn actions() -> Result<Vec<Action>, E> {
@@ -51,6 +114,10 @@ impl Parameters {
         if !args.is_empty() {
             let _ = args.remove(0);
         }
+        // Parameters are expected before the name of component. Everything after it belongs
+        // to the component and the task, including arguments of task, which look like
+        // parameters (for example "--fmt")
+        let mut rest = Parameters::split_off_component(&mut args);
         let mut actions = Vec::new();
         for param in Parameters::as_vec() {
             if let Some(action) = match param {
@@ -59,10 +126,21 @@ impl Parameters {
                 Parameters::Version => VersionParameter::action(&mut args),
                 Parameters::Sessions => SessionsParameter::action(&mut args),
                 Parameters::Lsp => LspParameter::action(&mut args),
+                Parameters::Fmt => FmtParameter::action(&mut args),
+                Parameters::FmtCheck => FmtCheckParameter::action(&mut args),
+                Parameters::MaxIterations => MaxIterationsParameter::action(&mut args),
+                Parameters::MaxDepth => MaxDepthParameter::action(&mut args),
+                Parameters::Timeout => TimeoutParameter::action(&mut args),
+                Parameters::MaxJobs => MaxJobsParameter::action(&mut args),
+                Parameters::Progress => ProgressParameter::action(&mut args),
+                Parameters::Timings => TimingsParameter::action(&mut args),
+                Parameters::Trace => TraceParameter::action(&mut args),
+                Parameters::Completions => CompletionsParameter::action(&mut args),
             } {
                 actions.push(action?);
             }
         }
+        args.append(&mut rest);
         if !args.is_empty() {
             actions.push(Action::Component(ComponentAction::new(&mut args)?));
         }
@@ -71,4 +149,100 @@ impl Parameters {
         }
         Ok(actions)
     }
+
+    /// Returns position of the first argument, which is neither a key of parameter nor
+    /// a value of parameter
+    fn position_of_component(args: &[String]) -> usize {
+        let params = Parameters::as_vec();
+        let mut pos = 0;
+        while let Some(arg) = args.get(pos) {
+            if !arg.starts_with('-') {
+                break;
+            }
+            let valued = params
+                .iter()
+                .any(|param| param.valued() && param.key().contains(arg));
+            pos += if valued { 2 } else { 1 };
+        }
+        pos.min(args.len())
+    }
+
+    /// Splits off arguments of component and task. A help key directly after the name of
+    /// component or task stays with parameters to show help for them.
+    fn split_off_component(args: &mut Vec<String>) -> Vec<String> {
+        let mut rest = args.split_off(Parameters::position_of_component(args));
+        let help = HelpParameter::keys();
+        if let Some(pos) = rest
+            .iter()
+            .take(3)
+            .skip(1)
+            .position(|arg| help.contains(arg))
+        {
+            args.push(rest.remove(pos + 1));
+        }
+        rest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn position_of_component() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        for (args, pos) in [
+            (args(&["comp", "task", "--help"]), 0),
+            (args(&["comp", "--help"]), 0),
+            (args(&["--progress", "plain", "app", "build", "--fmt"]), 2),
+            (
+                args(&["--timings", "--timeout", "5", "app", "--timeout", "1"]),
+                3,
+            ),
+            (args(&["--help", "--"]), 2),
+            (args(&["--scenario"]), 1),
+        ] {
+            assert_eq!(Parameters::position_of_component(&args), pos, "{args:?}");
+        }
+    }
+
+    #[test]
+    fn split_off_component() {
+        let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        for (input, params, rest) in [
+            (
+                args(&["comp", "task", "--help"]),
+                args(&["--help"]),
+                args(&["comp", "task"]),
+            ),
+            (
+                args(&["comp", "--help"]),
+                args(&["--help"]),
+                args(&["comp"]),
+            ),
+            (
+                args(&["-s", "h.sibs", "comp", "task", "-h"]),
+                args(&["-s", "h.sibs", "-h"]),
+                args(&["comp", "task"]),
+            ),
+            (
+                args(&["comp", "task", "--name", "--help"]),
+                args(&[]),
+                args(&["comp", "task", "--name", "--help"]),
+            ),
+            (
+                args(&["--fmt", "comp", "task", "--fmt"]),
+                args(&["--fmt"]),
+                args(&["comp", "task", "--fmt"]),
+            ),
+        ] {
+            let mut args = input.clone();
+            assert_eq!(
+                Parameters::split_off_component(&mut args),
+                rest,
+                "{input:?}"
+            );
+            assert_eq!(args, params, "{input:?}");
+        }
+    }
 }
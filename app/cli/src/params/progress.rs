@@ -0,0 +1,39 @@
+use crate::*;
+use runtime::ProgressMode;
+
+const ARGS: [&str; 1] = ["--progress"];
+const ENV: &str = "SIBS_PROGRESS";
+
+pub struct ProgressParameter {}
+
+impl Parameter for ProgressParameter {
+    fn keys() -> Vec<String> {
+        ARGS.iter().map(|s| s.to_string()).collect()
+    }
+    fn desc() -> String {
+        format!(
+            "Mode of progress: bars, plain (line by line), quiet (failures only) or github (with GitHub Actions groups). Can be set with {ENV} env variable. Default: github on GitHub Actions; plain on CI or if output isn't a terminal; bars in other cases"
+        )
+    }
+    fn valued() -> bool {
+        true
+    }
+    fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
+        // CLI argument has priority over env variable
+        let mode = if let Some(pos) = args.iter().position(|arg| ARGS.contains(&arg.as_str())) {
+            if args.len() <= pos + 1 {
+                return Some(Err(E::MissedProgressMode));
+            }
+            let mode = args.remove(pos + 1);
+            args.remove(pos);
+            mode
+        } else {
+            std::env::var(ENV).ok()?
+        };
+        Some(
+            ProgressMode::try_from(mode.as_str())
+                .map(|mode| Action::Progress(ProgressAction { mode }))
+                .map_err(|_| E::InvalidProgressMode(mode)),
+        )
+    }
+}
@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+
+use crate::*;
+
+const TIMINGS_ARGS: [&str; 1] = ["--timings"];
+const TRACE_ARGS: [&str; 1] = ["--trace"];
+
+pub struct TimingsParameter {}
+
+impl Parameter for TimingsParameter {
+    fn keys() -> Vec<String> {
+        TIMINGS_ARGS.iter().map(|s| s.to_string()).collect()
+    }
+    fn desc() -> String {
+        "Prints wall time and status of components, tasks and commands with the critical path after the run".to_owned()
+    }
+    fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
+        let pos = args
+            .iter()
+            .position(|arg| TIMINGS_ARGS.contains(&arg.as_str()))?;
+        args.remove(pos);
+        Some(Ok(Action::Report(ReportAction {
+            report: Report::Timings,
+        })))
+    }
+}
+
+pub struct TraceParameter {}
+
+impl Parameter for TraceParameter {
+    fn keys() -> Vec<String> {
+        TRACE_ARGS.iter().map(|s| s.to_string()).collect()
+    }
+    fn desc() -> String {
+        "Writes the tree of jobs into the file as Chrome trace events (JSON) after the run; the file can be opened in chrome://tracing or Perfetto".to_owned()
+    }
+    fn valued() -> bool {
+        true
+    }
+    fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
+        let pos = args
+            .iter()
+            .position(|arg| TRACE_ARGS.contains(&arg.as_str()))?;
+        if args.len() <= pos + 1 {
+            return Some(Err(E::MissedTracePath));
+        }
+        let path = args.remove(pos + 1);
+        args.remove(pos);
+        Some(Ok(Action::Report(ReportAction {
+            report: Report::Trace(PathBuf::from(path)),
+        })))
+    }
+}
@@ -11,6 +11,9 @@ impl Parameter for ScenarioParameter {
     fn desc() -> String {
         "Path to file - uses to define specific scenario file (*.sibs)".to_owned()
     }
+    fn valued() -> bool {
+        true
+    }
     fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
         let pos = args.iter().position(|arg| ARGS.contains(&arg.as_str()))?;
         if args.len() <= pos + 1 {
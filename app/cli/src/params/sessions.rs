@@ -11,6 +11,9 @@ impl Parameter for SessionsParameter {
     fn desc() -> String {
         "Shows sessions of sibs".to_owned()
     }
+    fn valued() -> bool {
+        true
+    }
     fn action(args: &mut Vec<String>) -> Option<Result<Action, E>> {
         let pos = args.iter().position(|arg| ARGS.contains(&arg.as_str()))?;
         args.remove(pos);
@@ -14,6 +14,9 @@ pub struct Script {
     component: Option<String>,
     task: Option<String>,
     args: Option<Vec<String>>,
+    limits: RtLimits,
+    progress: Option<ProgressMode>,
+    reports: Vec<Report>,
     scenario: Scenario,
     anchor: Anchor,
     scx: Option<SemanticCx>,
@@ -26,6 +29,7 @@ impl Script {
         component: Option<String>,
         task: Option<String>,
         args: Option<Vec<String>>,
+        limits: RtLimits,
     ) -> Result<Self, E> {
         let mut parser = Parser::new(&scenario.filepath, false)?;
         let anchor = Anchor::read(&mut parser);
@@ -57,22 +61,68 @@ impl Script {
             component,
             task,
             args,
+            limits,
+            progress: None,
+            reports: Vec::new(),
         })
     }
 
+    /// Sets the mode of progress rendering; without it the mode is detected by environment
+    pub fn with_progress(mut self, progress: Option<ProgressMode>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Sets reports, which are produced after the run
+    pub fn with_reports(mut self, reports: Vec<Report>) -> Self {
+        self.reports = reports;
+        self
+    }
+
     pub async fn run(&mut self) -> Result<RtValue, E> {
         let component = self.component.take().ok_or(E::ScriptAlreadyExecuted)?;
         let task = self.task.take().ok_or(E::ScriptAlreadyExecuted)?;
         let scx = self.scx.take().ok_or(E::ScriptAlreadyExecuted)?;
         let args = self.args.take().ok_or(E::ScriptAlreadyExecuted)?;
-        let params = RtParameters::new(component.clone(), task.clone(), args, self.scenario.cwd()?);
+        let params = RtParameters::new(component.clone(), task.clone(), args, self.scenario.cwd()?)
+            .with_limits(self.limits)
+            .with_progress(self.progress);
         let rt = interpreter::runtime(params, scx)?;
         let cx = rt
             .create_cx(Uuid::new_v4(), format!("{component}:{task}"), None)
             .await?;
-        let vl = self.anchor.interpret(rt.clone(), cx).await;
+        // Interruption by user (Ctrl-C) cancels all jobs, so running processes are killed
+        let interruption = {
+            let rt = rt.clone();
+            tokio::spawn(async move {
+                if tokio::signal::ctrl_c().await.is_ok() {
+                    rt.cancel();
+                }
+            })
+        };
+        let vl = self.anchor.interpret(rt.clone(), cx.clone()).await;
+        interruption.abort();
+        let cancelled = rt.is_cancelled();
+        cx.job.finish(if vl.is_ok() {
+            JobStatus::Success
+        } else {
+            JobStatus::Failed
+        });
+        // Timings are taken before destroying of runtime, but reported after it to
+        // avoid mixing with the progress
+        let timings = if self.reports.is_empty() {
+            None
+        } else {
+            Some(rt.timings().await?)
+        };
         let _ = rt.destroy().await;
+        if let Some(timings) = timings {
+            self.report(&timings)?;
+        }
+        // The time limit cancels jobs as well, but it's reported by own error
+        let timeout = matches!(&vl, Err(err) if matches!(err.e, RtError::TimeLimit(..)));
         match vl {
+            _ if cancelled && !timeout => Err(E::Cancelled),
             Ok(vl) => Ok(vl),
             Err(err) => {
                 eprintln!(
@@ -85,14 +135,58 @@ impl Script {
             }
         }
     }
+    fn report(&self, timings: &Timings) -> Result<(), E> {
+        for report in self.reports.iter() {
+            match report {
+                Report::Timings => eprintln!("{}", timings.summary()),
+                Report::Trace(path) => std::fs::write(path, timings.trace())?,
+            }
+        }
+        Ok(())
+    }
+
     pub fn print(&self) -> Result<(), E> {
-        if self.component.is_some() {
+        if self.task.is_some() {
+            self.print_task()
+        } else if self.component.is_some() {
             self.print_tasks()
         } else {
             self.print_components()
         }
     }
 
+    /// Returns declared tasks of components
+    pub fn tasks(&self) -> Vec<&TaskEntity> {
+        let Some(scx) = self.scx.as_ref() else {
+            return Vec::new();
+        };
+        let mut tasks = scx.tasks.table.values().collect::<Vec<&TaskEntity>>();
+        tasks.sort_by_key(|task| task.fullname());
+        tasks
+    }
+
+    fn get_task(&self, component: &str, task: &str) -> Option<&TaskEntity> {
+        self.tasks()
+            .into_iter()
+            .find(|en| en.master.name == component && en.name == task)
+    }
+
+    /// Returns declared arguments of task as they are written in the declaration
+    fn task_args(&self, component: &str, task: &str) -> String {
+        format!(
+            "({})",
+            self.get_task(component, task)
+                .map(|en| {
+                    en.args
+                        .iter()
+                        .map(|arg| arg.signature())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .unwrap_or_default()
+        )
+    }
+
     fn print_components(&self) -> Result<(), E> {
         let mut lines = Vec::new();
         self.anchor
@@ -102,7 +196,10 @@ impl Script {
                 lines.push(format!("- [b]{component}[/b][>>]"));
                 lines.extend(md.lines().into_iter().map(|ln| format!("  {ln}")));
                 tasks.iter().for_each(|(task, meta)| {
-                    lines.push(format!("[>>]- [b]{task}[/b]"));
+                    lines.push(format!(
+                        "[>>]- [b]{task}[/b]{}",
+                        self.task_args(component, task)
+                    ));
                     lines.extend(meta.lines().into_iter().map(|ln| format!("[>>]{ln}")));
                 });
             });
@@ -115,17 +212,157 @@ impl Script {
             return Err(E::NoComponentParameter);
         };
         let mut lines = vec![format!("[b]{component}[/b]")];
-        let Some(component) = self.anchor.get_component(&component) else {
+        let Some(node) = self.anchor.get_component(&component) else {
             return Err(E::ComponentNotFound(component));
         };
-        lines.extend(component.get_md().lines());
-        if let Node::Root(Root::Component(component)) = component.get_node() {
-            component.get_tasks_md().iter().for_each(|(task, meta)| {
-                lines.push(format!(" - [b]{task}[/b][>>]"));
+        lines.extend(node.get_md().lines());
+        if let Node::Root(Root::Component(node)) = node.get_node() {
+            node.get_tasks_md().iter().for_each(|(task, meta)| {
+                lines.push(format!(
+                    " - [b]{task}[/b] [>>]{}",
+                    self.task_args(&component, task)
+                ));
                 lines.extend(meta.lines().into_iter().map(|ln| format!("[>>]{ln}")));
             });
         }
         term::print(lines.join("\n"));
         Ok(())
     }
+
+    fn print_task(&self) -> Result<(), E> {
+        term::print(self.task_help()?);
+        Ok(())
+    }
+
+    /// Returns help of task: signature, description and usage with arguments
+    fn task_help(&self) -> Result<String, E> {
+        let (Some(component), Some(task)) = (self.component.as_ref(), self.task.as_ref()) else {
+            return Err(E::NoComponentParameter);
+        };
+        let Some(node) = self.anchor.get_component(component) else {
+            return Err(E::ComponentNotFound(component.to_owned()));
+        };
+        let Some(entity) = self.get_task(component, task) else {
+            return Err(E::TaskNotFound(component.to_owned(), task.to_owned()));
+        };
+        let mut lines = vec![format!("[b]{component}:{}[/b]", entity.signature())];
+        if let Node::Root(Root::Component(node)) = node.get_node() {
+            if let Some((_, meta)) = node.get_tasks_md().iter().find(|(name, _)| name == task) {
+                lines.extend(meta.lines());
+            }
+        }
+        lines.push(String::new());
+        lines.push(format!(
+            "Usage: sibs {component} {task}{}",
+            entity
+                .args
+                .iter()
+                .map(|arg| if arg.default.is_some() {
+                    format!(" [{}]", arg.ident)
+                } else {
+                    format!(" <{}>", arg.ident)
+                })
+                .collect::<String>()
+        ));
+        if !entity.args.is_empty() {
+            lines.push(String::from(
+                "Arguments can be given by position or by name: --name=value",
+            ));
+            entity.args.iter().for_each(|arg| {
+                lines.push(format!(
+                    " - [b]{}[/b] [>>]{}{}",
+                    arg.ident,
+                    arg.expected(),
+                    arg.default_value()
+                        .map(|default| format!(" (default: {default})"))
+                        .unwrap_or_default()
+                ));
+            });
+        }
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::*;
+
+    pub(crate) const CONTENT: &str = r#"
+    component app() {
+        task deploy(env: "dev" | "prod", replicas: num = 2, dry: bool) {
+            true;
+        }
+        task build() {
+            true;
+        }
+    };
+    component docs() {
+        task serve(port: 8080 | 8081) {
+            true;
+        }
+    };
+    "#;
+
+    /// Creates script from the content; the file of scenario is removed after reading
+    pub(crate) fn script(content: &str, component: Option<&str>, task: Option<&str>) -> Script {
+        let filepath = std::env::temp_dir().join(format!("{}.sibs", uuid::Uuid::new_v4()));
+        std::fs::write(&filepath, content).unwrap();
+        let script = Script::new(
+            Scenario::from(&filepath).unwrap(),
+            component.map(|s| s.to_owned()),
+            task.map(|s| s.to_owned()),
+            None,
+            runtime::RtLimits::default(),
+        );
+        std::fs::remove_file(&filepath).unwrap();
+        script.unwrap()
+    }
+
+    #[test]
+    fn task_help() {
+        for (component, task, help) in [
+            (
+                "app",
+                "deploy",
+                [
+                    r#"[b]app:deploy(env: "dev" | "prod", replicas: num = 2, dry: bool)[/b]"#,
+                    "",
+                    "Usage: sibs app deploy <env> [replicas] <dry>",
+                    "Arguments can be given by position or by name: --name=value",
+                    r#" - [b]env[/b] [>>]"dev" | "prod""#,
+                    " - [b]replicas[/b] [>>]num (default: 2)",
+                    " - [b]dry[/b] [>>]bool",
+                ]
+                .join("\n"),
+            ),
+            (
+                "docs",
+                "serve",
+                [
+                    "[b]docs:serve(port: 8080 | 8081)[/b]",
+                    "",
+                    "Usage: sibs docs serve <port>",
+                    "Arguments can be given by position or by name: --name=value",
+                    " - [b]port[/b] [>>]8080 | 8081",
+                ]
+                .join("\n"),
+            ),
+            (
+                "app",
+                "build",
+                ["[b]app:build()[/b]", "", "Usage: sibs app build"].join("\n"),
+            ),
+        ] {
+            assert_eq!(
+                script(CONTENT, Some(component), Some(task))
+                    .task_help()
+                    .unwrap(),
+                help
+            );
+        }
+        assert!(matches!(
+            script(CONTENT, Some("app"), Some("test")).task_help(),
+            Err(E::TaskNotFound(..))
+        ));
+    }
 }
@@ -1,18 +1,153 @@
 mod semantic;
 
 use diagnostics::ErrorCode;
-use driver::{CompletionMatch, Driver};
+use driver::{
+    is_valid_name, CompletionMatch, Driver, Fix, Formatted, OutlineItem, OutlineKind, ParseCache,
+    SharedParseCache, Symbol, SymbolLocation,
+};
 use std::collections::HashMap;
 use std::fs;
+use std::path::PathBuf;
 use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
 use tower_lsp::lsp_types::*;
 use tower_lsp::{jsonrpc, Client, LanguageServer, LspService, Server};
-use tracing::{debug, error};
+use tracing::{debug, error, warn};
+
+fn to_range(position: &lexer::Position) -> Range {
+    Range {
+        start: Position {
+            line: position.from.ln as u32,
+            character: position.from.col as u32,
+        },
+        end: Position {
+            line: position.to.ln as u32,
+            character: position.to.col as u32,
+        },
+    }
+}
+
+fn to_code_action(uri: &Url, fix: Fix, preferred: bool) -> CodeActionOrCommand {
+    let edits = fix
+        .edits
+        .into_iter()
+        .map(|edit| TextEdit {
+            range: to_range(&lexer::Position::new(edit.position.from, edit.position.to)),
+            new_text: edit.text,
+        })
+        .collect();
+    CodeActionOrCommand::CodeAction(CodeAction {
+        title: fix.title,
+        kind: Some(CodeActionKind::QUICKFIX),
+        edit: Some(WorkspaceEdit {
+            changes: Some(HashMap::from([(uri.clone(), edits)])),
+            ..Default::default()
+        }),
+        is_preferred: Some(preferred),
+        ..Default::default()
+    })
+}
+
+fn to_symbol_kind(kind: OutlineKind) -> SymbolKind {
+    match kind {
+        OutlineKind::Component => SymbolKind::CLASS,
+        OutlineKind::Task => SymbolKind::METHOD,
+        OutlineKind::Module => SymbolKind::NAMESPACE,
+        OutlineKind::Function => SymbolKind::FUNCTION,
+    }
+}
+
+#[allow(deprecated)]
+fn to_document_symbol(item: &OutlineItem) -> DocumentSymbol {
+    DocumentSymbol {
+        name: item.name.to_owned(),
+        detail: item.detail.to_owned(),
+        kind: to_symbol_kind(item.kind),
+        tags: None,
+        deprecated: None,
+        range: to_range(&item.position),
+        selection_range: to_range(&item.selection),
+        children: Some(item.children.iter().map(to_document_symbol).collect()),
+    }
+}
+
+/// Symbol with locations of its declaration and usages
+struct ResolvedSymbol {
+    symbol: Symbol,
+    declaration: Option<Location>,
+    usages: Vec<Location>,
+}
+
+/// Converts LSP position (UTF-16 based) into byte offset in the content
+fn to_offset(content: &str, pos: Position) -> usize {
+    let mut offset = 0;
+    for (n, line) in content.split_inclusive('\n').enumerate() {
+        if n == pos.line as usize {
+            let mut units = 0;
+            for (idx, ch) in line.char_indices() {
+                if units >= pos.character as usize || ch == '\n' || ch == '\r' {
+                    return offset + idx;
+                }
+                units += ch.len_utf16();
+            }
+            return offset + line.len();
+        }
+        offset += line.len();
+    }
+    content.len()
+}
+
+fn apply_change(content: &mut String, change: TextDocumentContentChangeEvent) {
+    let Some(range) = change.range else {
+        *content = change.text;
+        return;
+    };
+    let from = to_offset(content, range.start);
+    let to = to_offset(content, range.end).max(from);
+    content.replace_range(from..to, &change.text);
+}
+
+fn get_diagnostics(driver: &Driver) -> Vec<Diagnostic> {
+    let Some(errors) = driver.errors() else {
+        return Vec::new();
+    };
+    let root = driver.get_root_src();
+    errors
+        // Errors of included sources cannot be shown in the current document
+        .filter(|err| Some(err.err.link().src) == root)
+        .map(|err| {
+            let link = err.err.link();
+            Diagnostic {
+                range: Range {
+                    start: Position {
+                        line: link.from.ln as u32,
+                        character: link.from.col as u32,
+                    },
+                    end: Position {
+                        line: link.to.ln as u32,
+                        character: link.to.col as u32,
+                    },
+                },
+                code: Some(NumberOrString::String(err.err.formattable())),
+                severity: Some(DiagnosticSeverity::ERROR),
+                message: err.err.to_string(),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
 
 #[derive(Debug)]
 struct Backend {
     client: Client,
     docs: RwLock<HashMap<Url, String>>,
+    /// Parsed attached files (`mod from`, `include from`) shared between all documents
+    cache: SharedParseCache,
+    /// Attached files of each analyzed document
+    deps: RwLock<HashMap<Url, Vec<PathBuf>>>,
+    /// Running analyses. Analysis is cancelled as soon as a new one for the same
+    /// document is started.
+    analyses: RwLock<HashMap<Url, CancellationToken>>,
 }
 
 impl Backend {
@@ -20,6 +155,99 @@ impl Backend {
         Self {
             client,
             docs: RwLock::new(HashMap::new()),
+            cache: ParseCache::shared(),
+            deps: RwLock::new(HashMap::new()),
+            analyses: RwLock::new(HashMap::new()),
+        }
+    }
+    fn set_cached_content(&self, uri: &Url, content: Option<&str>) {
+        let Ok(filepath) = uri.to_file_path() else {
+            return;
+        };
+        let Ok(mut cache) = self.cache.lock() else {
+            warn!("fail to access parsing cache");
+            return;
+        };
+        if let Some(content) = content {
+            cache.set_content(filepath, content);
+        } else {
+            cache.remove_content(filepath);
+        }
+    }
+    /// Returns opened documents, which have the given document as attached file
+    async fn get_dependents(&self, uri: &Url) -> Vec<Url> {
+        let Ok(filepath) = uri.to_file_path() else {
+            return Vec::new();
+        };
+        let filepath = fs::canonicalize(&filepath).unwrap_or(filepath);
+        self.deps
+            .read()
+            .await
+            .iter()
+            .filter(|(doc, files)| *doc != uri && files.contains(&filepath))
+            .map(|(doc, _)| doc.clone())
+            .collect()
+    }
+    /// Analyzes the document and publishes diagnostics. Previous analysis of the same
+    /// document (if it's still running) is cancelled.
+    async fn analyze(&self, uri: Url) {
+        let Some(content) = self.docs.read().await.get(&uri).cloned() else {
+            return;
+        };
+        let cancel = CancellationToken::new();
+        if let Some(previous) = self
+            .analyses
+            .write()
+            .await
+            .insert(uri.clone(), cancel.clone())
+        {
+            previous.cancel();
+        }
+        let filepath = uri.to_file_path().ok();
+        let cache = self.cache.clone();
+        let token = cancel.clone();
+        let analysis = tokio::task::spawn_blocking(move || {
+            let mut driver = if let Some(filepath) = filepath {
+                Driver::buffered(filepath, content, true)
+            } else {
+                Driver::unbound(content, true)
+            };
+            driver.set_cache(cache);
+            driver.set_cancellation(token);
+            driver
+                .read()
+                .map(|_| (get_diagnostics(&driver), driver.get_attached_files()))
+                .map_err(|err| err.to_string())
+        })
+        .await;
+        if cancel.is_cancelled() {
+            debug!("analysis of {} has been cancelled", uri.path());
+            return;
+        }
+        self.analyses.write().await.remove(&uri);
+        match analysis {
+            Ok(Ok((diagnostics, attached))) => {
+                self.deps.write().await.insert(uri.clone(), attached);
+                self.client
+                    .publish_diagnostics(uri, diagnostics, None)
+                    .await;
+            }
+            Ok(Err(err)) => {
+                error!("fail to get diagnostics: {err}");
+            }
+            Err(err) => {
+                error!("fail to analyze {}: {err}", uri.path());
+            }
+        }
+    }
+    /// Analyzes the changed document and all opened documents, which depend on it. Only
+    /// parsing of attached files is cached; each analyzed document passes semantic
+    /// analysis in full.
+    async fn analyze_with_dependents(&self, uri: &Url) {
+        let mut uris = vec![uri.clone()];
+        uris.extend(self.get_dependents(uri).await);
+        for uri in uris.into_iter() {
+            self.analyze(uri).await;
         }
     }
     pub async fn get_driver_by_url(&self, uri: &Url) -> jsonrpc::Result<Driver> {
@@ -30,7 +258,13 @@ impl Backend {
                     format!("document ({}) found in cache", uri.path()),
                 )
                 .await;
-            Ok(Driver::unbound(content, true))
+            let mut driver = if let Ok(filepath) = uri.to_file_path() {
+                Driver::buffered(filepath, content, true)
+            } else {
+                Driver::unbound(content, true)
+            };
+            driver.set_cache(self.cache.clone());
+            Ok(driver)
         } else {
             let filepath = uri.to_file_path().map_err(|_| jsonrpc::Error {
                 message: "Fail to get filepath".into(),
@@ -43,30 +277,14 @@ impl Backend {
                     format!("document ({filepath:?}) will be read from disk"),
                 )
                 .await;
-            Ok(Driver::new(filepath, true))
+            let mut driver = Driver::new(filepath, true);
+            driver.set_cache(self.cache.clone());
+            Ok(driver)
         }
     }
     pub async fn get_abs_pos(&self, uri: &Url, pos: Position) -> jsonrpc::Result<usize> {
-        fn get_pos(content: &str, pos: Position) -> usize {
-            let mut abs = 0;
-            let mut lines = content.lines();
-
-            for _ in 0..pos.line {
-                if let Some(line) = lines.next() {
-                    abs += line.len() + 1;
-                } else {
-                    return content.len();
-                }
-            }
-
-            if let Some(line) = lines.next() {
-                abs + (pos.character as usize).min(line.len())
-            } else {
-                content.len()
-            }
-        }
         if let Some(content) = self.docs.read().await.get(uri) {
-            Ok(get_pos(content, pos))
+            Ok(to_offset(content, pos))
         } else {
             let filepath = uri.to_file_path().map_err(|_| jsonrpc::Error {
                 message: "Fail to get filepath".into(),
@@ -87,40 +305,92 @@ impl Backend {
                 message: format!("Cannot access to {}", uri.path()).into(),
                 data: None,
             })?;
-            Ok(get_pos(content, pos))
+            Ok(to_offset(content, pos))
+        }
+    }
+    async fn get_formatted(&self, uri: &Url) -> jsonrpc::Result<Option<Formatted>> {
+        let formatted = {
+            let mut driver = self.get_driver_by_url(uri).await?;
+            driver.read().map_err(|err| jsonrpc::Error {
+                message: format!("Fail to parse source code: {err}").into(),
+                code: jsonrpc::ErrorCode::ParseError,
+                data: None,
+            })?;
+            driver.format().map_err(|err| err.to_string())
+        };
+        match formatted {
+            Ok(formatted) => Ok(Some(formatted)),
+            Err(err) => {
+                self.client
+                    .log_message(
+                        MessageType::WARNING,
+                        format!("document ({}) cannot be formatted: {err}", uri.path()),
+                    )
+                    .await;
+                Ok(None)
+            }
         }
     }
-    async fn get_diagnostics<S: ToString>(&self, content: S) -> jsonrpc::Result<Vec<Diagnostic>> {
-        let mut driver = Driver::unbound(content, true);
+    /// Resolves symbol in the given position and converts all its occurrences into locations.
+    async fn get_symbol(
+        &self,
+        uri: &Url,
+        position: Position,
+    ) -> jsonrpc::Result<Option<ResolvedSymbol>> {
+        let pos = self.get_abs_pos(uri, position).await?;
+        debug!("will resolve symbol for position {pos}");
+        let mut driver = self.get_driver_by_url(uri).await?;
         driver.read().map_err(|err| jsonrpc::Error {
             message: format!("Fail to parse source code: {err}").into(),
             code: jsonrpc::ErrorCode::ParseError,
             data: None,
         })?;
-        let Some(errors) = driver.errors() else {
-            return Ok(Vec::new());
+        let Some(symbol) = driver.symbol(pos, None) else {
+            debug!("no symbol has been found for pos: {pos}");
+            return Ok(None);
         };
-        Ok(errors
-            .map(|err| {
-                let link = err.err.link();
-                Diagnostic {
-                    range: Range {
-                        start: Position {
-                            line: link.from.ln as u32,
-                            character: link.from.col as u32,
-                        },
-                        end: Position {
-                            line: link.to.ln as u32,
-                            character: link.to.col as u32,
-                        },
-                    },
-                    code: Some(NumberOrString::String(err.err.formattable())),
-                    severity: Some(DiagnosticSeverity::ERROR),
-                    message: err.err.to_string(),
-                    ..Default::default()
-                }
+        let root = driver.get_root_src();
+        let to_location = |location: &SymbolLocation| {
+            let uri = if Some(location.src) == root {
+                uri.clone()
+            } else {
+                Url::from_file_path(driver.get_src_filename(&location.src)?).ok()?
+            };
+            Some(Location {
+                uri,
+                range: to_range(&location.position),
             })
-            .collect())
+        };
+        Ok(Some(ResolvedSymbol {
+            declaration: symbol.declaration.as_ref().and_then(to_location),
+            usages: symbol.usages.iter().filter_map(to_location).collect(),
+            symbol,
+        }))
+    }
+    /// Looks for declarations of the file, which isn't a scenario by itself, but is attached
+    /// to one of opened scenarios with `mod from` or `include from`.
+    async fn get_attached_outline(&self, uri: &Url) -> jsonrpc::Result<Vec<OutlineItem>> {
+        let Ok(filepath) = uri.to_file_path() else {
+            return Ok(Vec::new());
+        };
+        let uris = self
+            .docs
+            .read()
+            .await
+            .keys()
+            .filter(|doc| *doc != uri)
+            .cloned()
+            .collect::<Vec<Url>>();
+        for doc in uris.iter() {
+            let mut driver = self.get_driver_by_url(doc).await?;
+            if driver.read().is_err() {
+                continue;
+            }
+            if let Some(src) = driver.get_src_by_filename(&filepath) {
+                return Ok(driver.outline(Some(src)));
+            }
+        }
+        Ok(Vec::new())
     }
 }
 
@@ -130,6 +400,23 @@ impl LanguageServer for Backend {
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
+                document_formatting_provider: Some(OneOf::Left(true)),
+                document_range_formatting_provider: Some(OneOf::Left(true)),
+                definition_provider: Some(OneOf::Left(true)),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                references_provider: Some(OneOf::Left(true)),
+                document_highlight_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Options(
+                    CodeActionOptions {
+                        code_action_kinds: Some(vec![CodeActionKind::QUICKFIX]),
+                        ..Default::default()
+                    },
+                )),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
                 signature_help_provider: Some(SignatureHelpOptions {
                     trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
                     retrigger_characters: None,
@@ -154,7 +441,7 @@ impl LanguageServer for Backend {
                 text_document_sync: Some(TextDocumentSyncCapability::Options(
                     TextDocumentSyncOptions {
                         open_close: Some(true),
-                        change: Some(TextDocumentSyncKind::FULL),
+                        change: Some(TextDocumentSyncKind::INCREMENTAL),
                         will_save: None,
                         will_save_wait_until: None,
                         save: None,
@@ -200,53 +487,46 @@ impl LanguageServer for Backend {
                 format!("document ({}) has been opened", uri.path()),
             )
             .await;
-        let diagnostics = self.get_diagnostics(&text).await;
+        self.set_cached_content(&uri, Some(&text));
         self.docs.write().await.insert(uri.clone(), text);
-        match diagnostics {
-            Ok(diagnostics) => {
-                self.client
-                    .publish_diagnostics(uri, diagnostics, None)
-                    .await;
-            }
-            Err(err) => {
-                error!("fail to get diagnostics: {err}");
-            }
-        }
+        self.analyze_with_dependents(&uri).await;
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
         let uri = params.text_document.uri;
-        let changes = &params.content_changes;
-        if let Some(change) = changes.last() {
-            let diagnostics = self.get_diagnostics(&change.text).await;
-            self.docs
-                .write()
-                .await
-                .insert(uri.clone(), change.text.clone());
-            match diagnostics {
-                Ok(diagnostics) => {
-                    self.client
-                        .publish_diagnostics(uri, diagnostics, None)
-                        .await;
-                }
-                Err(err) => {
-                    error!("fail to get diagnostics: {err}");
-                }
+        let content = {
+            let mut docs = self.docs.write().await;
+            let Some(content) = docs.get_mut(&uri) else {
+                warn!("changes of not opened document ({}) are ignored", uri.path());
+                return;
+            };
+            for change in params.content_changes.into_iter() {
+                apply_change(content, change);
             }
-        }
+            content.clone()
+        };
+        self.set_cached_content(&uri, Some(&content));
+        self.analyze_with_dependents(&uri).await;
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
+        let uri = params.text_document.uri;
         self.client
             .log_message(
                 MessageType::INFO,
-                format!(
-                    "document ({}) closed and will be removed",
-                    params.text_document.uri.path()
-                ),
+                format!("document ({}) closed and will be removed", uri.path()),
             )
             .await;
-        self.docs.write().await.remove(&params.text_document.uri);
+        self.docs.write().await.remove(&uri);
+        self.deps.write().await.remove(&uri);
+        if let Some(cancel) = self.analyses.write().await.remove(&uri) {
+            cancel.cancel();
+        }
+        self.set_cached_content(&uri, None);
+        // Dependents should be analyzed with the content of file on disk
+        for dependent in self.get_dependents(&uri).await.into_iter() {
+            self.analyze(dependent).await;
+        }
     }
 
     async fn semantic_tokens_full(
@@ -268,11 +548,54 @@ impl LanguageServer for Backend {
     }
 
     async fn initialized(&self, _: InitializedParams) {
+        // Changes of files on disk (not opened documents) should invalidate parsing cache
+        let options = DidChangeWatchedFilesRegistrationOptions {
+            watchers: vec![FileSystemWatcher {
+                glob_pattern: GlobPattern::String("**/*.sibs".to_owned()),
+                kind: None,
+            }],
+        };
+        match serde_json::to_value(options) {
+            Ok(options) => {
+                if let Err(err) = self
+                    .client
+                    .register_capability(vec![Registration {
+                        id: "sibs-files-watcher".to_owned(),
+                        method: "workspace/didChangeWatchedFiles".to_owned(),
+                        register_options: Some(options),
+                    }])
+                    .await
+                {
+                    warn!("fail to register files watcher: {err}");
+                }
+            }
+            Err(err) => {
+                error!("fail to serialize options of files watcher: {err}");
+            }
+        }
         self.client
             .log_message(MessageType::INFO, "SIBS language server initialized")
             .await;
     }
 
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        for change in params.changes.into_iter() {
+            let Ok(filepath) = change.uri.to_file_path() else {
+                continue;
+            };
+            match self.cache.lock() {
+                Ok(mut cache) => cache.invalidate(filepath),
+                Err(_) => {
+                    warn!("fail to access parsing cache");
+                    continue;
+                }
+            }
+            for dependent in self.get_dependents(&change.uri).await.into_iter() {
+                self.analyze(dependent).await;
+            }
+        }
+    }
+
     async fn signature_help(
         &self,
         params: SignatureHelpParams,
@@ -446,6 +769,316 @@ impl LanguageServer for Backend {
         }))
     }
 
+    async fn formatting(
+        &self,
+        params: DocumentFormattingParams,
+    ) -> jsonrpc::Result<Option<Vec<TextEdit>>> {
+        let uri = &params.text_document.uri;
+        debug!("will format document {}", uri.path());
+        let Some(formatted) = self.get_formatted(uri).await? else {
+            return Ok(None);
+        };
+        let end = self
+            .docs
+            .read()
+            .await
+            .get(uri)
+            .map(|content| {
+                let lines = content.split('\n').collect::<Vec<&str>>();
+                Position {
+                    line: lines.len().saturating_sub(1) as u32,
+                    character: lines
+                        .last()
+                        .map(|ln| ln.encode_utf16().count())
+                        .unwrap_or_default() as u32,
+                }
+            })
+            .unwrap_or(Position {
+                line: u32::MAX,
+                character: 0,
+            });
+        Ok(Some(vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: 0,
+                    character: 0,
+                },
+                end,
+            },
+            new_text: formatted.text(),
+        }]))
+    }
+
+    async fn range_formatting(
+        &self,
+        params: DocumentRangeFormattingParams,
+    ) -> jsonrpc::Result<Option<Vec<TextEdit>>> {
+        let uri = &params.text_document.uri;
+        debug!(
+            "will format lines {}-{} of document {}",
+            params.range.start.line,
+            params.range.end.line,
+            uri.path()
+        );
+        let Some(formatted) = self.get_formatted(uri).await? else {
+            return Ok(None);
+        };
+        let Some((from, to, text)) = formatted.range(
+            params.range.start.line as usize,
+            params.range.end.line as usize,
+        ) else {
+            return Ok(None);
+        };
+        let end = self
+            .docs
+            .read()
+            .await
+            .get(uri)
+            .and_then(|content| {
+                content
+                    .lines()
+                    .nth(to)
+                    .map(|ln| ln.encode_utf16().count() as u32)
+            })
+            .unwrap_or_default();
+        Ok(Some(vec![TextEdit {
+            range: Range {
+                start: Position {
+                    line: from as u32,
+                    character: 0,
+                },
+                end: Position {
+                    line: to as u32,
+                    character: end,
+                },
+            },
+            new_text: text,
+        }]))
+    }
+
+    async fn goto_definition(
+        &self,
+        params: GotoDefinitionParams,
+    ) -> jsonrpc::Result<Option<GotoDefinitionResponse>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let Some(resolved) = self
+            .get_symbol(uri, params.text_document_position_params.position)
+            .await?
+        else {
+            return Ok(None);
+        };
+        Ok(resolved.declaration.map(GotoDefinitionResponse::Scalar))
+    }
+
+    async fn references(&self, params: ReferenceParams) -> jsonrpc::Result<Option<Vec<Location>>> {
+        let uri = &params.text_document_position.text_document.uri;
+        let Some(resolved) = self
+            .get_symbol(uri, params.text_document_position.position)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let mut locations = Vec::new();
+        if params.context.include_declaration {
+            locations.extend(resolved.declaration);
+        }
+        locations.extend(resolved.usages);
+        Ok(Some(locations))
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> jsonrpc::Result<Option<Vec<DocumentHighlight>>> {
+        let uri = &params.text_document_position_params.text_document.uri;
+        let Some(resolved) = self
+            .get_symbol(uri, params.text_document_position_params.position)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let declaration = resolved.declaration.map(|location| (location, true));
+        let usages = resolved.usages.into_iter().map(|location| (location, false));
+        Ok(Some(
+            declaration
+                .into_iter()
+                .chain(usages)
+                .filter(|(location, _)| &location.uri == uri)
+                .map(|(location, declaration)| DocumentHighlight {
+                    range: location.range,
+                    kind: Some(if declaration {
+                        DocumentHighlightKind::WRITE
+                    } else {
+                        DocumentHighlightKind::READ
+                    }),
+                })
+                .collect(),
+        ))
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> jsonrpc::Result<Option<PrepareRenameResponse>> {
+        let Some(resolved) = self
+            .get_symbol(&params.text_document.uri, params.position)
+            .await?
+        else {
+            return Ok(None);
+        };
+        if !resolved.symbol.is_renamable() {
+            return Err(jsonrpc::Error::invalid_params(format!(
+                "\"{}\" isn't declared in the source code and cannot be renamed",
+                resolved.symbol.name
+            )));
+        }
+        Ok(resolved
+            .declaration
+            .iter()
+            .chain(resolved.usages.iter())
+            .find(|location| {
+                location.uri == params.text_document.uri
+                    && location.range.start <= params.position
+                    && params.position <= location.range.end
+            })
+            .map(|location| PrepareRenameResponse::Range(location.range)))
+    }
+
+    async fn rename(&self, params: RenameParams) -> jsonrpc::Result<Option<WorkspaceEdit>> {
+        let uri = &params.text_document_position.text_document.uri;
+        if !is_valid_name(&params.new_name) {
+            return Err(jsonrpc::Error::invalid_params(format!(
+                "\"{}\" isn't a valid name",
+                params.new_name
+            )));
+        }
+        let Some(resolved) = self
+            .get_symbol(uri, params.text_document_position.position)
+            .await?
+        else {
+            return Ok(None);
+        };
+        if !resolved.symbol.is_renamable() {
+            return Err(jsonrpc::Error::invalid_params(format!(
+                "\"{}\" isn't declared in the source code and cannot be renamed",
+                resolved.symbol.name
+            )));
+        }
+        let mut changes: HashMap<Url, Vec<TextEdit>> = HashMap::new();
+        for location in resolved.declaration.into_iter().chain(resolved.usages) {
+            changes.entry(location.uri).or_default().push(TextEdit {
+                range: location.range,
+                new_text: params.new_name.clone(),
+            });
+        }
+        Ok(Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }))
+    }
+
+    async fn code_action(
+        &self,
+        params: CodeActionParams,
+    ) -> jsonrpc::Result<Option<CodeActionResponse>> {
+        let uri = &params.text_document.uri;
+        let from = self.get_abs_pos(uri, params.range.start).await?;
+        let to = self.get_abs_pos(uri, params.range.end).await?;
+        debug!("will collect fixes for range {from}..{to}");
+        let fixes = {
+            let mut driver = self.get_driver_by_url(uri).await?;
+            driver.read().map_err(|err| jsonrpc::Error {
+                message: format!("Fail to parse source code: {err}").into(),
+                code: jsonrpc::ErrorCode::ParseError,
+                data: None,
+            })?;
+            driver.fixes(from, to, None)
+        };
+        let preferred = fixes.len() == 1;
+        Ok(Some(
+            fixes
+                .into_iter()
+                .map(|fix| to_code_action(uri, fix, preferred))
+                .collect(),
+        ))
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> jsonrpc::Result<Option<DocumentSymbolResponse>> {
+        let uri = &params.text_document.uri;
+        debug!("will collect symbols of document {}", uri.path());
+        let items = {
+            let mut driver = self.get_driver_by_url(uri).await?;
+            driver.read().map_err(|err| jsonrpc::Error {
+                message: format!("Fail to parse source code: {err}").into(),
+                code: jsonrpc::ErrorCode::ParseError,
+                data: None,
+            })?;
+            driver.outline(None)
+        };
+        let items = if items.is_empty() {
+            self.get_attached_outline(uri).await?
+        } else {
+            items
+        };
+        Ok(Some(DocumentSymbolResponse::Nested(
+            items.iter().map(to_document_symbol).collect(),
+        )))
+    }
+
+    #[allow(deprecated)]
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> jsonrpc::Result<Option<Vec<SymbolInformation>>> {
+        let query = params.query.to_lowercase();
+        debug!("will look for symbols by query \"{query}\"");
+        let uris = self.docs.read().await.keys().cloned().collect::<Vec<Url>>();
+        let mut symbols: Vec<SymbolInformation> = Vec::new();
+        for uri in uris.iter() {
+            let mut driver = self.get_driver_by_url(uri).await?;
+            if driver.read().is_err() {
+                continue;
+            }
+            let root = driver.get_root_src();
+            let items = driver.workspace_outline();
+            for (item, parent) in OutlineItem::flatten(&items, None) {
+                if !item.name.to_lowercase().contains(&query) {
+                    continue;
+                }
+                let location = if Some(item.src) == root {
+                    uri.clone()
+                } else if let Some(url) = driver
+                    .get_src_filename(&item.src)
+                    .and_then(|filename| Url::from_file_path(filename).ok())
+                {
+                    url
+                } else {
+                    continue;
+                };
+                let location = Location {
+                    uri: location,
+                    range: to_range(&item.selection),
+                };
+                // The same file can be attached to multiple opened scenarios
+                if symbols.iter().any(|symbol| symbol.location == location) {
+                    continue;
+                }
+                symbols.push(SymbolInformation {
+                    name: item.name.to_owned(),
+                    kind: to_symbol_kind(item.kind),
+                    tags: None,
+                    deprecated: None,
+                    location,
+                    container_name: parent.map(|name| name.to_owned()),
+                });
+            }
+        }
+        Ok(Some(symbols))
+    }
+
     async fn shutdown(&self) -> jsonrpc::Result<()> {
         Ok(())
     }
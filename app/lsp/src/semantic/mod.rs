@@ -21,6 +21,7 @@ fn map_token_type(token: &semantic::SemanticToken) -> u32 {
         semantic::SemanticToken::Task => 1,
         semantic::SemanticToken::Component => 1,
         semantic::SemanticToken::Module => 1,
+        semantic::SemanticToken::Label => 4,
     }
 }
 
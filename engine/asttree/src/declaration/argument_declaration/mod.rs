@@ -8,21 +8,37 @@ use std::fmt;
 pub struct ArgumentDeclaration {
     pub variable: Box<LinkedNode>,
     pub r#type: Box<LinkedNode>,
+    /// Default value of argument (`= value`). Argument with default value can be
+    /// omitted by caller.
+    pub default: Option<Box<LinkedNode>>,
     pub uuid: Uuid,
 }
 
 impl Diagnostic for ArgumentDeclaration {
     fn located(&self, src: &Uuid, pos: usize) -> bool {
-        self.variable.located(src, pos) || self.r#type.located(src, pos)
+        self.variable.located(src, pos)
+            || self.r#type.located(src, pos)
+            || self
+                .default
+                .as_ref()
+                .map(|n| n.located(src, pos))
+                .unwrap_or(false)
     }
     fn get_position(&self) -> Position {
         Position::new(
             self.variable.get_position().from,
-            self.r#type.get_position().to,
+            self.default
+                .as_ref()
+                .map(|n| n.get_position().to)
+                .unwrap_or(self.r#type.get_position().to),
         )
     }
     fn childs(&self) -> Vec<&LinkedNode> {
-        vec![&*self.variable, &*self.r#type]
+        let mut childs = vec![&*self.variable, &*self.r#type];
+        if let Some(n) = self.default.as_ref() {
+            childs.push(n);
+        }
+        childs
     }
 }
 
@@ -42,6 +58,7 @@ impl<'a> Lookup<'a> for ArgumentDeclaration {
             .lookup_inner(self.uuid, trgs)
             .into_iter()
             .chain(self.r#type.lookup_inner(self.uuid, trgs))
+            .chain(self.default.as_ref().lookup_inner(self.uuid, trgs))
             .collect()
     }
 }
@@ -51,12 +68,16 @@ impl FindMutByUuid for ArgumentDeclaration {
         self.variable
             .find_mut_by_uuid(uuid)
             .or_else(|| self.r#type.find_mut_by_uuid(uuid))
+            .or_else(|| self.default.find_mut_by_uuid(uuid))
     }
 }
 
 impl SrcLinking for ArgumentDeclaration {
     fn link(&self) -> SrcLink {
-        src_from::nodes(&self.variable, &self.r#type)
+        src_from::nodes(
+            &self.variable,
+            self.default.as_deref().unwrap_or(&self.r#type),
+        )
     }
     fn slink(&self) -> SrcLink {
         self.link()
@@ -65,7 +86,11 @@ impl SrcLinking for ArgumentDeclaration {
 
 impl fmt::Display for ArgumentDeclaration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.variable, self.r#type)
+        write!(f, "{} {}", self.variable, self.r#type)?;
+        if let Some(n) = self.default.as_ref() {
+            write!(f, " {n}")?;
+        }
+        Ok(())
     }
 }
 
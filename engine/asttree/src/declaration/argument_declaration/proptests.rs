@@ -47,10 +47,18 @@ impl Arbitrary for ArgumentDeclaration {
                 .prop_flat_map(LinkedNode::arbitrary_with)
                 .boxed()
             },
+            prop::option::of(
+                ArgumentAssignedValue::arbitrary_with(deep + 1)
+                    .prop_map(Statement::ArgumentAssignedValue)
+                    .prop_map(Node::Statement)
+                    .prop_map(move |n| (n, deep + 1))
+                    .prop_flat_map(LinkedNode::arbitrary_with),
+            ),
         )
-            .prop_map(|(variable, ty)| ArgumentDeclaration {
+            .prop_map(|(variable, ty, default)| ArgumentDeclaration {
                 variable: Box::new(variable),
                 r#type: Box::new(ty),
+                default: default.map(Box::new),
                 uuid: Uuid::new_v4(),
             })
             .boxed()
@@ -55,6 +55,9 @@ impl Arbitrary for FunctionCall {
                         Array::arbitrary_with(deep + 1)
                             .prop_map(|v| Node::Value(Value::Array(v)))
                             .boxed(),
+                        NamedArgument::arbitrary_with(deep + 1)
+                            .prop_map(|v| Node::Expression(Expression::NamedArgument(v)))
+                            .boxed(),
                     ])
                     .prop_map(move |n| (n, deep + 1))
                     .prop_flat_map(LinkedNode::arbitrary_with),
@@ -13,6 +13,7 @@ mod compound_assignments;
 mod compound_assignments_op;
 mod function_call;
 mod logical_op;
+mod named_argument;
 mod range;
 mod task_call;
 mod variable;
@@ -32,6 +33,7 @@ pub use compound_assignments::*;
 pub use compound_assignments_op::*;
 pub use function_call::*;
 pub use logical_op::*;
+pub use named_argument::*;
 pub use range::*;
 pub use task_call::*;
 pub use variable::*;
@@ -77,6 +79,8 @@ pub enum Expression {
     Command(Command),
     /// :comp:task(args)
     TaskCall(TaskCall),
+    /// release = true, name = "app", etc, named argument of call
+    NamedArgument(NamedArgument),
 }
 
 impl Diagnostic for Expression {
@@ -97,6 +101,7 @@ impl Diagnostic for Expression {
             Self::CompoundAssignmentsOp(n) => n.located(src, pos),
             Self::FunctionCall(n) => n.located(src, pos),
             Self::LogicalOp(n) => n.located(src, pos),
+            Self::NamedArgument(n) => n.located(src, pos),
             Self::Range(n) => n.located(src, pos),
             Self::TaskCall(n) => n.located(src, pos),
             Self::Variable(n) => n.located(src, pos),
@@ -119,6 +124,7 @@ impl Diagnostic for Expression {
             Self::CompoundAssignmentsOp(n) => n.get_position(),
             Self::FunctionCall(n) => n.get_position(),
             Self::LogicalOp(n) => n.get_position(),
+            Self::NamedArgument(n) => n.get_position(),
             Self::Range(n) => n.get_position(),
             Self::TaskCall(n) => n.get_position(),
             Self::Variable(n) => n.get_position(),
@@ -141,6 +147,7 @@ impl Diagnostic for Expression {
             Self::CompoundAssignmentsOp(n) => n.childs(),
             Self::FunctionCall(n) => n.childs(),
             Self::LogicalOp(n) => n.childs(),
+            Self::NamedArgument(n) => n.childs(),
             Self::Range(n) => n.childs(),
             Self::TaskCall(n) => n.childs(),
             Self::Variable(n) => n.childs(),
@@ -166,6 +173,7 @@ impl Identification for Expression {
             Self::CompoundAssignmentsOp(n) => &n.uuid,
             Self::FunctionCall(n) => &n.uuid,
             Self::LogicalOp(n) => &n.uuid,
+            Self::NamedArgument(n) => &n.uuid,
             Self::Range(n) => &n.uuid,
             Self::TaskCall(n) => &n.uuid,
             Self::Variable(n) => &n.uuid,
@@ -188,6 +196,7 @@ impl Identification for Expression {
             Self::CompoundAssignmentsOp(..) => ExpressionId::CompoundAssignmentsOp.to_string(),
             Self::FunctionCall(..) => ExpressionId::FunctionCall.to_string(),
             Self::LogicalOp(..) => ExpressionId::LogicalOp.to_string(),
+            Self::NamedArgument(..) => ExpressionId::NamedArgument.to_string(),
             Self::Range(..) => ExpressionId::Range.to_string(),
             Self::TaskCall(..) => ExpressionId::TaskCall.to_string(),
             Self::Variable(..) => ExpressionId::Variable.to_string(),
@@ -213,6 +222,7 @@ impl<'a> Lookup<'a> for Expression {
             Self::CompoundAssignmentsOp(n) => n.lookup(trgs),
             Self::FunctionCall(n) => n.lookup(trgs),
             Self::LogicalOp(n) => n.lookup(trgs),
+            Self::NamedArgument(n) => n.lookup(trgs),
             Self::Range(n) => n.lookup(trgs),
             Self::TaskCall(n) => n.lookup(trgs),
             Self::Variable(n) => n.lookup(trgs),
@@ -238,6 +248,7 @@ impl FindMutByUuid for Expression {
             Self::CompoundAssignmentsOp(n) => n.find_mut_by_uuid(uuid),
             Self::FunctionCall(n) => n.find_mut_by_uuid(uuid),
             Self::LogicalOp(n) => n.find_mut_by_uuid(uuid),
+            Self::NamedArgument(n) => n.find_mut_by_uuid(uuid),
             Self::Range(n) => n.find_mut_by_uuid(uuid),
             Self::TaskCall(n) => n.find_mut_by_uuid(uuid),
             Self::Variable(n) => n.find_mut_by_uuid(uuid),
@@ -263,6 +274,7 @@ impl SrcLinking for Expression {
             Self::CompoundAssignmentsOp(n) => n.link(),
             Self::FunctionCall(n) => n.link(),
             Self::LogicalOp(n) => n.link(),
+            Self::NamedArgument(n) => n.link(),
             Self::Range(n) => n.link(),
             Self::TaskCall(n) => n.link(),
             Self::Variable(n) => n.link(),
@@ -0,0 +1,65 @@
+#[cfg(feature = "proptests")]
+mod proptests;
+
+use crate::*;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct NamedArgument {
+    /// Name of argument
+    pub ident: String,
+    pub token: Token,
+    /// Token of `=`
+    pub equals: Token,
+    pub value: Box<LinkedNode>,
+    pub uuid: Uuid,
+}
+
+impl Diagnostic for NamedArgument {
+    fn located(&self, src: &Uuid, pos: usize) -> bool {
+        if !self.token.belongs(src) {
+            false
+        } else {
+            self.get_position().is_in(pos)
+        }
+    }
+    fn get_position(&self) -> Position {
+        Position::new(self.token.pos.from, self.value.md.link.to())
+    }
+    fn childs(&self) -> Vec<&LinkedNode> {
+        vec![&*self.value]
+    }
+}
+
+impl<'a> Lookup<'a> for NamedArgument {
+    fn lookup(&'a self, trgs: &[NodeTarget]) -> Vec<FoundNode<'a>> {
+        self.value.lookup_inner(self.uuid, trgs)
+    }
+}
+
+impl FindMutByUuid for NamedArgument {
+    fn find_mut_by_uuid(&mut self, uuid: &Uuid) -> Option<&mut LinkedNode> {
+        self.value.find_mut_by_uuid(uuid)
+    }
+}
+
+impl SrcLinking for NamedArgument {
+    fn link(&self) -> SrcLink {
+        src_from::tk_and_node(&self.token, &self.value)
+    }
+    fn slink(&self) -> SrcLink {
+        self.link()
+    }
+}
+
+impl fmt::Display for NamedArgument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.token, self.equals, self.value)
+    }
+}
+
+impl From<NamedArgument> for Node {
+    fn from(val: NamedArgument) -> Self {
+        Node::Expression(Expression::NamedArgument(val))
+    }
+}
@@ -0,0 +1,44 @@
+use crate::*;
+use proptest::prelude::*;
+
+impl Arbitrary for NamedArgument {
+    type Parameters = u8;
+
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(deep: Self::Parameters) -> Self::Strategy {
+        (
+            gens::kind(KindId::Identifier),
+            prop::strategy::Union::new(vec![
+                Number::arbitrary()
+                    .prop_map(|v| Node::Value(Value::Number(v)))
+                    .boxed(),
+                Boolean::arbitrary()
+                    .prop_map(|v| Node::Value(Value::Boolean(v)))
+                    .boxed(),
+                PrimitiveString::arbitrary()
+                    .prop_map(|v| Node::Value(Value::PrimitiveString(v)))
+                    .boxed(),
+                Variable::arbitrary()
+                    .prop_map(|v| Node::Expression(Expression::Variable(v)))
+                    .boxed(),
+            ])
+            .prop_map(move |n| (n, deep + 1))
+            .prop_flat_map(LinkedNode::arbitrary_with),
+        )
+            .prop_filter_map("Expected: Kind::Identifier", |(knd, value)| {
+                if let Kind::Identifier(ident) = knd {
+                    Some(NamedArgument {
+                        ident: ident.to_owned(),
+                        token: Token::for_test(Kind::Identifier(ident.to_owned())),
+                        equals: Token::for_test(Kind::Equals),
+                        value: Box::new(value),
+                        uuid: Uuid::new_v4(),
+                    })
+                } else {
+                    None
+                }
+            })
+            .boxed()
+    }
+}
@@ -48,6 +48,9 @@ impl Arbitrary for TaskCall {
                         Array::arbitrary_with(deep + 1)
                             .prop_map(|v| Node::Value(Value::Array(v)))
                             .boxed(),
+                        NamedArgument::arbitrary_with(deep + 1)
+                            .prop_map(|v| Node::Expression(Expression::NamedArgument(v)))
+                            .boxed(),
                     ])
                     .prop_map(move |n| (n, deep + 1))
                     .prop_flat_map(LinkedNode::arbitrary_with),
@@ -46,6 +46,9 @@ impl Arbitrary for Block {
                     Break::arbitrary()
                         .prop_map(|v| Node::Statement(Statement::Break(v)))
                         .boxed(),
+                    Continue::arbitrary()
+                        .prop_map(|v| Node::Statement(Statement::Continue(v)))
+                        .boxed(),
                     VariableDeclaration::arbitrary_with(deep + 1)
                         .prop_map(|v| Node::Declaration(Declaration::VariableDeclaration(v)))
                         .boxed(),
@@ -100,6 +103,9 @@ impl Arbitrary for Block {
                     Break::arbitrary()
                         .prop_map(|v| Node::Statement(Statement::Break(v)))
                         .boxed(),
+                    Continue::arbitrary()
+                        .prop_map(|v| Node::Statement(Statement::Continue(v)))
+                        .boxed(),
                     Return::arbitrary_with(deep + 1)
                         .prop_map(|v| Node::Statement(Statement::Return(v)))
                         .boxed(),
@@ -7,15 +7,27 @@ use std::fmt;
 #[derive(Debug, Clone)]
 pub struct Break {
     pub token: Token,
+    /// Label of target loop (`break 'outer;`)
+    pub label: Option<Token>,
+    /// Value, which is returned by loop (`break value;`)
+    pub value: Option<Box<LinkedNode>>,
     pub target: Option<Uuid>,
     pub uuid: Uuid,
 }
 
 impl Break {
-    pub fn set_target(&mut self, uuid: &Uuid) {
-        if self.target.is_none() {
-            self.target = Some(*uuid)
+    /// Sets target loop if target isn't set yet. Labeled break can be targeted only
+    /// to the loop with the same label.
+    pub fn set_target(&mut self, uuid: &Uuid, label: Option<&Token>) {
+        if self.target.is_some() {
+            return;
         }
+        if let Some(own) = self.label.as_ref() {
+            if label.map(|label| label.kind != own.kind).unwrap_or(true) {
+                return;
+            }
+        }
+        self.target = Some(*uuid)
     }
     pub fn is_target(&self, uuid: &Uuid) -> bool {
         self.target
@@ -37,28 +49,40 @@ impl Diagnostic for Break {
         }
     }
     fn get_position(&self) -> Position {
-        self.token.pos.clone()
+        if let Some(value) = self.value.as_ref() {
+            Position::new(self.token.pos.from, value.md.link.to())
+        } else if let Some(label) = self.label.as_ref() {
+            Position::new(self.token.pos.from, label.pos.to)
+        } else {
+            self.token.pos.clone()
+        }
     }
     fn childs(&self) -> Vec<&LinkedNode> {
-        Vec::new()
+        self.value.as_ref().map(|n| vec![&**n]).unwrap_or_default()
     }
 }
 
 impl<'a> Lookup<'a> for Break {
-    fn lookup(&'a self, _trgs: &[NodeTarget]) -> Vec<FoundNode<'a>> {
-        vec![]
+    fn lookup(&'a self, trgs: &[NodeTarget]) -> Vec<FoundNode<'a>> {
+        self.value.as_ref().lookup_inner(self.uuid, trgs)
     }
 }
 
 impl FindMutByUuid for Break {
-    fn find_mut_by_uuid(&mut self, _uuid: &Uuid) -> Option<&mut LinkedNode> {
-        None
+    fn find_mut_by_uuid(&mut self, uuid: &Uuid) -> Option<&mut LinkedNode> {
+        self.value.find_mut_by_uuid(uuid)
     }
 }
 
 impl SrcLinking for Break {
     fn link(&self) -> SrcLink {
-        src_from::tk(&self.token)
+        if let Some(value) = self.value.as_ref() {
+            src_from::tk_and_node(&self.token, value)
+        } else if let Some(label) = self.label.as_ref() {
+            src_from::tks(&self.token, label)
+        } else {
+            src_from::tk(&self.token)
+        }
     }
     fn slink(&self) -> SrcLink {
         self.link()
@@ -67,7 +91,19 @@ impl SrcLinking for Break {
 
 impl fmt::Display for Break {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.token)
+        write!(
+            f,
+            "{}{}{}",
+            self.token,
+            self.label
+                .as_ref()
+                .map(|label| format!(" {label}"))
+                .unwrap_or_default(),
+            self.value
+                .as_ref()
+                .map(|value| format!(" {value}"))
+                .unwrap_or_default()
+        )
     }
 }
 
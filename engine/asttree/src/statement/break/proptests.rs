@@ -10,6 +10,8 @@ impl Arbitrary for Break {
         gens::keyword(KeywordId::Break)
             .boxed()
             .prop_map(move |knd| Break {
+                label: None,
+                value: None,
                 token: Token::for_test(Kind::Keyword(knd)),
                 target: None,
                 uuid: Uuid::new_v4(),
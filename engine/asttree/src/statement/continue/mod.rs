@@ -0,0 +1,100 @@
+#[cfg(feature = "proptests")]
+mod proptests;
+
+use crate::*;
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub struct Continue {
+    pub token: Token,
+    /// Label of target loop (`continue 'outer;`)
+    pub label: Option<Token>,
+    pub target: Option<Uuid>,
+    pub uuid: Uuid,
+}
+
+impl Continue {
+    /// Sets target loop if target isn't set yet. Labeled continue can be targeted only
+    /// to the loop with the same label.
+    pub fn set_target(&mut self, uuid: &Uuid, label: Option<&Token>) {
+        if self.target.is_some() {
+            return;
+        }
+        if let Some(own) = self.label.as_ref() {
+            if label.map(|label| label.kind != own.kind).unwrap_or(true) {
+                return;
+            }
+        }
+        self.target = Some(*uuid)
+    }
+    pub fn is_target(&self, uuid: &Uuid) -> bool {
+        self.target
+            .as_ref()
+            .map(|target| target == uuid)
+            .unwrap_or(false)
+    }
+    pub fn is_assigned(&self) -> bool {
+        self.target.is_some()
+    }
+}
+
+impl Diagnostic for Continue {
+    fn located(&self, src: &Uuid, pos: usize) -> bool {
+        if !self.token.belongs(src) {
+            false
+        } else {
+            self.get_position().is_in(pos)
+        }
+    }
+    fn get_position(&self) -> Position {
+        if let Some(label) = self.label.as_ref() {
+            Position::new(self.token.pos.from, label.pos.to)
+        } else {
+            self.token.pos.clone()
+        }
+    }
+    fn childs(&self) -> Vec<&LinkedNode> {
+        Vec::new()
+    }
+}
+
+impl<'a> Lookup<'a> for Continue {
+    fn lookup(&'a self, _trgs: &[NodeTarget]) -> Vec<FoundNode<'a>> {
+        vec![]
+    }
+}
+
+impl FindMutByUuid for Continue {
+    fn find_mut_by_uuid(&mut self, _uuid: &Uuid) -> Option<&mut LinkedNode> {
+        None
+    }
+}
+
+impl SrcLinking for Continue {
+    fn link(&self) -> SrcLink {
+        if let Some(label) = self.label.as_ref() {
+            src_from::tks(&self.token, label)
+        } else {
+            src_from::tk(&self.token)
+        }
+    }
+    fn slink(&self) -> SrcLink {
+        self.link()
+    }
+}
+
+impl fmt::Display for Continue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(label) = self.label.as_ref() {
+            write!(f, "{} {label}", self.token)
+        } else {
+            write!(f, "{}", self.token)
+        }
+    }
+}
+
+impl From<Continue> for Node {
+    fn from(val: Continue) -> Self {
+        Node::Statement(Statement::Continue(val))
+    }
+}
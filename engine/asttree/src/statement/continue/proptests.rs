@@ -0,0 +1,20 @@
+use crate::*;
+use proptest::prelude::*;
+
+impl Arbitrary for Continue {
+    type Parameters = ();
+
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        gens::keyword(KeywordId::Continue)
+            .boxed()
+            .prop_map(move |knd| Continue {
+                label: None,
+                token: Token::for_test(Kind::Keyword(knd)),
+                target: None,
+                uuid: Uuid::new_v4(),
+            })
+            .boxed()
+    }
+}
@@ -6,6 +6,8 @@ use std::fmt;
 
 #[derive(Debug, Clone)]
 pub struct For {
+    /// Label of loop (`'outer: for el in elements { ... }`)
+    pub label: Option<Token>,
     pub token_for: Token,
     pub token_in: Token,
     pub element: Box<LinkedNode>,
@@ -24,7 +26,10 @@ impl Diagnostic for For {
         }
     }
     fn get_position(&self) -> Position {
-        Position::new(self.token_for.pos.from, self.block.md.link.to())
+        Position::new(
+            self.label.as_ref().unwrap_or(&self.token_for).pos.from,
+            self.block.md.link.to(),
+        )
     }
     fn childs(&self) -> Vec<&LinkedNode> {
         let mut nodes = vec![&*self.element, &*self.elements, &*self.block];
@@ -57,7 +62,7 @@ impl FindMutByUuid for For {
 
 impl SrcLinking for For {
     fn link(&self) -> SrcLink {
-        src_from::tk_and_node(&self.token_for, &self.block)
+        src_from::tk_and_node(self.label.as_ref().unwrap_or(&self.token_for), &self.block)
     }
     fn slink(&self) -> SrcLink {
         src_from::tk_and_node(&self.token_for, &self.elements)
@@ -66,6 +71,9 @@ impl SrcLinking for For {
 
 impl fmt::Display for For {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(label) = self.label.as_ref() {
+            write!(f, "{label}{} ", Kind::Colon)?;
+        }
         if let Some(index) = self.index.as_ref() {
             write!(
                 f,
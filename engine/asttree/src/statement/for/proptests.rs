@@ -54,6 +54,7 @@ impl Arbitrary for For {
                 .boxed(),
         )
             .prop_map(move |(element, index, elements, block)| For {
+                label: None,
                 element: Box::new(element),
                 index: index.map(Box::new),
                 elements: Box::new(elements),
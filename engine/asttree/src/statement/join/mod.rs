@@ -6,6 +6,9 @@ use std::fmt;
 
 #[derive(Debug, Clone)]
 pub struct Join {
+    /// Options of join: `join[limit, policy](...)`. A number is the max number of
+    /// branches running at the same time; a string is the policy of failures.
+    pub options: Vec<LinkedNode>,
     pub commands: Vec<LinkedNode>,
     pub token: Token,
     pub open: Token,
@@ -25,14 +28,15 @@ impl Diagnostic for Join {
         Position::tokens(&self.token, &self.close)
     }
     fn childs(&self) -> Vec<&LinkedNode> {
-        self.commands.iter().collect()
+        self.options.iter().chain(self.commands.iter()).collect()
     }
 }
 
 impl<'a> Lookup<'a> for Join {
     fn lookup(&'a self, trgs: &[NodeTarget]) -> Vec<FoundNode<'a>> {
-        self.commands
+        self.options
             .iter()
+            .chain(self.commands.iter())
             .collect::<Vec<&LinkedNode>>()
             .lookup_inner(self.uuid, trgs)
     }
@@ -40,7 +44,9 @@ impl<'a> Lookup<'a> for Join {
 
 impl FindMutByUuid for Join {
     fn find_mut_by_uuid(&mut self, uuid: &Uuid) -> Option<&mut LinkedNode> {
-        self.commands.find_mut_by_uuid(uuid)
+        self.options
+            .find_mut_by_uuid(uuid)
+            .or_else(|| self.commands.find_mut_by_uuid(uuid))
     }
 }
 
@@ -57,8 +63,22 @@ impl fmt::Display for Join {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "{} {} {} {}",
+            "{}{} {} {} {}",
             self.token,
+            if self.options.is_empty() {
+                String::new()
+            } else {
+                format!(
+                    "{}{}{}",
+                    Kind::LeftBracket,
+                    self.options
+                        .iter()
+                        .map(|n| n.to_string())
+                        .collect::<Vec<String>>()
+                        .join(&Kind::Comma.to_string()),
+                    Kind::RightBracket
+                )
+            },
             self.open,
             self.commands
                 .iter()
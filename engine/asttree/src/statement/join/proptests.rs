@@ -7,7 +7,23 @@ impl Arbitrary for Join {
     type Strategy = BoxedStrategy<Self>;
 
     fn arbitrary_with(deep: Self::Parameters) -> Self::Strategy {
-        prop::collection::vec(
+        let options = prop::collection::vec(
+            prop::strategy::Union::new(vec![
+                Number::arbitrary()
+                    .prop_map(|v| Node::Value(Value::Number(v)))
+                    .boxed(),
+                PrimitiveString::arbitrary()
+                    .prop_map(|v| Node::Value(Value::PrimitiveString(v)))
+                    .boxed(),
+                Variable::arbitrary()
+                    .prop_map(|v| Node::Expression(Expression::Variable(v)))
+                    .boxed(),
+            ])
+            .prop_map(move |n| (n, deep + 1))
+            .prop_flat_map(LinkedNode::arbitrary_with),
+            0..3,
+        );
+        let commands = prop::collection::vec(
             prop::strategy::Union::new(vec![
                 Command::arbitrary_with(deep + 1)
                     .prop_map(|v| Node::Expression(Expression::Command(v)))
@@ -20,14 +36,16 @@ impl Arbitrary for Join {
             ])
             .prop_flat_map(LinkedNode::arbitrary_with),
             1..5,
-        )
-        .prop_map(move |commands| Join {
-            open: Token::for_test(Kind::LeftParen),
-            close: Token::for_test(Kind::RightParen),
-            commands,
-            token: Token::for_test(Kind::Keyword(Keyword::Join)),
-            uuid: Uuid::new_v4(),
-        })
-        .boxed()
+        );
+        (options, commands)
+            .prop_map(move |(options, commands)| Join {
+                options,
+                open: Token::for_test(Kind::LeftParen),
+                close: Token::for_test(Kind::RightParen),
+                commands,
+                token: Token::for_test(Kind::Keyword(Keyword::Join)),
+                uuid: Uuid::new_v4(),
+            })
+            .boxed()
     }
 }
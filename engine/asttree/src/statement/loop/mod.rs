@@ -6,6 +6,8 @@ use std::fmt;
 
 #[derive(Debug, Clone)]
 pub struct Loop {
+    /// Label of loop (`'outer: loop { ... }`)
+    pub label: Option<Token>,
     pub token: Token,
     pub block: Box<LinkedNode>,
     pub uuid: Uuid,
@@ -20,7 +22,10 @@ impl Diagnostic for Loop {
         }
     }
     fn get_position(&self) -> Position {
-        Position::new(self.token.pos.from, self.block.md.link.to())
+        Position::new(
+            self.label.as_ref().unwrap_or(&self.token).pos.from,
+            self.block.md.link.to(),
+        )
     }
     fn childs(&self) -> Vec<&LinkedNode> {
         vec![&*self.block]
@@ -41,7 +46,7 @@ impl FindMutByUuid for Loop {
 
 impl SrcLinking for Loop {
     fn link(&self) -> SrcLink {
-        src_from::tk_and_node(&self.token, &self.block)
+        src_from::tk_and_node(self.label.as_ref().unwrap_or(&self.token), &self.block)
     }
     fn slink(&self) -> SrcLink {
         src_from::tk(&self.token)
@@ -50,7 +55,11 @@ impl SrcLinking for Loop {
 
 impl fmt::Display for Loop {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.token, self.block)
+        if let Some(label) = self.label.as_ref() {
+            write!(f, "{label}{} {} {}", Kind::Colon, self.token, self.block)
+        } else {
+            write!(f, "{} {}", self.token, self.block)
+        }
     }
 }
 
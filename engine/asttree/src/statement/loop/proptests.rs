@@ -13,6 +13,7 @@ impl Arbitrary for Loop {
             .prop_flat_map(LinkedNode::arbitrary_with)
             .boxed()
             .prop_map(move |block| Loop {
+                label: None,
                 block: Box::new(block),
                 token: Token::for_test(Kind::Keyword(Keyword::Loop)),
                 uuid: Uuid::new_v4(),
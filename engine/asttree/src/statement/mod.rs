@@ -4,6 +4,7 @@ mod assignation;
 mod assigned_value;
 mod block;
 mod r#break;
+mod r#continue;
 mod r#for;
 mod r#if;
 mod join;
@@ -22,6 +23,7 @@ pub use join::*;
 pub use oneof::*;
 pub use optional::*;
 pub use r#break::*;
+pub use r#continue::*;
 pub use r#for::*;
 pub use r#if::*;
 pub use r#loop::*;
@@ -37,6 +39,8 @@ pub enum Statement {
     Block(Block),
     /// break;
     Break(Break),
+    /// continue;
+    Continue(Continue),
     /// return;
     Return(Return),
     /// a > 5 => func();
@@ -94,6 +98,7 @@ impl Identification for Statement {
             Self::ArgumentAssignedValue(n) => &n.uuid,
             Self::Block(n) => &n.uuid,
             Self::Break(n) => &n.uuid,
+            Self::Continue(n) => &n.uuid,
             Self::For(n) => &n.uuid,
             Self::If(n) => &n.uuid,
             Self::Join(n) => &n.uuid,
@@ -112,6 +117,7 @@ impl Identification for Statement {
             Self::ArgumentAssignedValue(..) => StatementId::ArgumentAssignedValue.to_string(),
             Self::Block(..) => StatementId::Block.to_string(),
             Self::Break(..) => StatementId::Break.to_string(),
+            Self::Continue(..) => StatementId::Continue.to_string(),
             Self::For(..) => StatementId::For.to_string(),
             Self::If(..) => StatementId::If.to_string(),
             Self::Join(..) => StatementId::Join.to_string(),
@@ -133,6 +139,7 @@ impl Diagnostic for Statement {
             Self::ArgumentAssignedValue(n) => n.located(src, pos),
             Self::Block(n) => n.located(src, pos),
             Self::Break(n) => n.located(src, pos),
+            Self::Continue(n) => n.located(src, pos),
             Self::For(n) => n.located(src, pos),
             Self::If(n) => n.located(src, pos),
             Self::Join(n) => n.located(src, pos),
@@ -151,6 +158,7 @@ impl Diagnostic for Statement {
             Self::ArgumentAssignedValue(n) => n.get_position(),
             Self::Block(n) => n.get_position(),
             Self::Break(n) => n.get_position(),
+            Self::Continue(n) => n.get_position(),
             Self::For(n) => n.get_position(),
             Self::If(n) => n.get_position(),
             Self::Join(n) => n.get_position(),
@@ -170,6 +178,7 @@ impl Diagnostic for Statement {
             Self::ArgumentAssignedValue(n) => n.childs(),
             Self::Block(n) => n.childs(),
             Self::Break(n) => n.childs(),
+            Self::Continue(n) => n.childs(),
             Self::For(n) => n.childs(),
             Self::If(n) => n.childs(),
             Self::Join(n) => n.childs(),
@@ -191,6 +200,7 @@ impl<'a> Lookup<'a> for Statement {
             Self::ArgumentAssignedValue(n) => n.lookup(trgs),
             Self::Block(n) => n.lookup(trgs),
             Self::Break(n) => n.lookup(trgs),
+            Self::Continue(n) => n.lookup(trgs),
             Self::For(n) => n.lookup(trgs),
             Self::If(n) => n.lookup(trgs),
             Self::Join(n) => n.lookup(trgs),
@@ -212,6 +222,7 @@ impl FindMutByUuid for Statement {
             Self::ArgumentAssignedValue(n) => n.find_mut_by_uuid(uuid),
             Self::Block(n) => n.find_mut_by_uuid(uuid),
             Self::Break(n) => n.find_mut_by_uuid(uuid),
+            Self::Continue(n) => n.find_mut_by_uuid(uuid),
             Self::For(n) => n.find_mut_by_uuid(uuid),
             Self::If(n) => n.find_mut_by_uuid(uuid),
             Self::Join(n) => n.find_mut_by_uuid(uuid),
@@ -233,6 +244,7 @@ impl SrcLinking for Statement {
             Self::ArgumentAssignedValue(n) => n.link(),
             Self::Block(n) => n.link(),
             Self::Break(n) => n.link(),
+            Self::Continue(n) => n.link(),
             Self::For(n) => n.link(),
             Self::If(n) => n.link(),
             Self::Join(n) => n.link(),
@@ -18,6 +18,9 @@ impl Arbitrary for Optional {
                     Break::arbitrary()
                         .prop_map(|v| Node::Statement(Statement::Break(v)))
                         .boxed(),
+                    Continue::arbitrary()
+                        .prop_map(|v| Node::Statement(Statement::Continue(v)))
+                        .boxed(),
                     Return::arbitrary_with(deep + 1)
                         .prop_map(|v| Node::Statement(Statement::Return(v)))
                         .boxed(),
@@ -36,6 +39,9 @@ impl Arbitrary for Optional {
                     Break::arbitrary()
                         .prop_map(|v| Node::Statement(Statement::Break(v)))
                         .boxed(),
+                    Continue::arbitrary()
+                        .prop_map(|v| Node::Statement(Statement::Continue(v)))
+                        .boxed(),
                     Return::arbitrary_with(deep + 1)
                         .prop_map(|v| Node::Statement(Statement::Return(v)))
                         .boxed(),
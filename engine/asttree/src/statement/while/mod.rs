@@ -6,6 +6,8 @@ use std::fmt;
 
 #[derive(Debug, Clone)]
 pub struct While {
+    /// Label of loop (`'outer: while condition { ... }`)
+    pub label: Option<Token>,
     pub token: Token,
     pub comparison: Box<LinkedNode>,
     pub block: Box<LinkedNode>,
@@ -21,7 +23,10 @@ impl Diagnostic for While {
         }
     }
     fn get_position(&self) -> Position {
-        Position::new(self.token.pos.from, self.block.md.link.to())
+        Position::new(
+            self.label.as_ref().unwrap_or(&self.token).pos.from,
+            self.block.md.link.to(),
+        )
     }
     fn childs(&self) -> Vec<&LinkedNode> {
         vec![&*self.block, &*self.comparison]
@@ -48,7 +53,7 @@ impl FindMutByUuid for While {
 
 impl SrcLinking for While {
     fn link(&self) -> SrcLink {
-        src_from::tk_and_node(&self.token, &self.block)
+        src_from::tk_and_node(self.label.as_ref().unwrap_or(&self.token), &self.block)
     }
     fn slink(&self) -> SrcLink {
         src_from::tk_and_node(&self.token, &self.comparison)
@@ -57,6 +62,9 @@ impl SrcLinking for While {
 
 impl fmt::Display for While {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(label) = self.label.as_ref() {
+            write!(f, "{label}{} ", Kind::Colon)?;
+        }
         write!(f, "{} {} {}", self.token, self.comparison, self.block)
     }
 }
@@ -32,6 +32,7 @@ impl Arbitrary for While {
                 .boxed(),
         )
             .prop_map(move |(comparison, block)| While {
+                label: None,
                 comparison: Box::new(comparison),
                 block: Box::new(block),
                 token: Token::for_test(Kind::Keyword(Keyword::While)),
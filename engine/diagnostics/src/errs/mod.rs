@@ -22,6 +22,10 @@ impl<E: Display + ErrorCode> Errors<E> {
             .and_then(|stamp| self.errors.remove(stamp))
     }
     #[must_use]
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+    #[must_use]
     pub fn is_empty(&self) -> bool {
         self.errors.is_empty()
     }
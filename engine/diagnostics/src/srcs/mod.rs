@@ -55,6 +55,18 @@ impl CodeSources {
         };
         Ok(Some(source.content()?))
     }
+    pub fn get_filename(&self, src: &Uuid) -> Option<PathBuf> {
+        match self.sources.get(src)? {
+            CodeSource::File(filename) => Some(filename.to_owned()),
+            CodeSource::Inline(..) => None,
+        }
+    }
+    pub fn get_src_by_filename<P: AsRef<Path>>(&self, filename: P) -> Option<Uuid> {
+        self.sources.iter().find_map(|(uuid, cs)| match cs {
+            CodeSource::File(path) if path == filename.as_ref() => Some(*uuid),
+            _ => None,
+        })
+    }
     pub fn add_file_src<P: AsRef<Path>>(
         &mut self,
         filename: P,
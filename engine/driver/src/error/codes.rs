@@ -11,6 +11,8 @@ impl ErrorCode for E {
             Self::NestedTasks(..) => "00005",
             Self::Parser(err) => err.code(),
             Self::Lexer(..) => "00006",
+            Self::FormattingOfInvalidSrc(..) => "00007",
+            Self::Cancelled => "00008",
             Self::Semantic(err) => err.code(),
             Self::Runtime(err) => err.code(),
         }
@@ -22,6 +24,8 @@ impl ErrorCode for E {
             | Self::ScriptAlreadyExecuted
             | Self::TaskInsideFuncDeclaration(..)
             | Self::NestedTasks(..)
+            | Self::FormattingOfInvalidSrc(..)
+            | Self::Cancelled
             | Self::Lexer(..) => ErrorSource::Driver,
             Self::Parser(err) => err.src(),
             Self::Semantic(err) => err.src(),
@@ -43,6 +47,8 @@ mod test {
                 EId::ScriptAlreadyExecuted => E::ScriptAlreadyExecuted,
                 EId::TaskInsideFuncDeclaration => E::TaskInsideFuncDeclaration(Uuid::new_v4()),
                 EId::NestedTasks => E::NestedTasks(Uuid::new_v4()),
+                EId::FormattingOfInvalidSrc => E::FormattingOfInvalidSrc(0),
+                EId::Cancelled => E::Cancelled,
                 EId::Parser => E::Parser(ParserError::KeywordUsing),
                 EId::Lexer => E::Lexer(LexerError::InvalidNumber),
                 EId::Semantic => E::Semantic(SemanticError::EmptyTypeDeclaration),
@@ -19,6 +19,10 @@ pub enum E {
     TaskInsideFuncDeclaration(Uuid),
     #[error("Detected nested tasks (nested task's uuid {0})")]
     NestedTasks(Uuid),
+    #[error("Source code has {0} error(s) and cannot be formatted")]
+    FormattingOfInvalidSrc(usize),
+    #[error("Analysis has been cancelled")]
+    Cancelled,
     #[error("Parser error: {0}")]
     Parser(parser::ParserError),
     #[error("Lexer error: {0}")]
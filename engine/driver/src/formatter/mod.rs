@@ -0,0 +1,532 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::*;
+
+const INDENT: &str = "    ";
+
+/// Frame of nesting, which is tracked during formatting
+#[derive(Debug, Clone)]
+enum Frame {
+    /// Code block `{ ... }`
+    /// * `usize` - indentation of line, where block has been opened
+    Block(usize),
+    /// Parentheses or brackets
+    /// * `usize` - indentation of line, where group has been opened
+    Group(usize),
+    /// String or command. Tokens inside are taken as they are
+    /// * `KindId` - opening (and closing) quote
+    Str(KindId),
+    /// Interpolation inside string or command `'text { expr } text'`
+    Interpolation,
+}
+
+/// Single line of formatted content
+#[derive(Debug, Clone)]
+pub struct FormattedLine {
+    /// Content of line without indentation and line break
+    pub text: String,
+    /// Level of indentation
+    pub indent: usize,
+    /// Line number (original content) of the first token on line
+    pub from: usize,
+    /// Line number (original content) of the last token on line
+    pub to: usize,
+}
+
+impl fmt::Display for FormattedLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.text.is_empty() {
+            Ok(())
+        } else {
+            write!(f, "{}{}", INDENT.repeat(self.indent), self.text)
+        }
+    }
+}
+
+/// Result of formatting
+#[derive(Debug, Clone)]
+pub struct Formatted {
+    pub lines: Vec<FormattedLine>,
+    /// Line break, which is used in original content
+    pub eol: &'static str,
+}
+
+impl Formatted {
+    /// Returns formatted content as text. Content always ends with line break.
+    pub fn text(&self) -> String {
+        let mut text = self
+            .lines
+            .iter()
+            .map(|ln| ln.to_string())
+            .collect::<Vec<String>>()
+            .join(self.eol);
+        text.push_str(self.eol);
+        text
+    }
+
+    /// Returns formatted fragment for given range of lines of original content.
+    ///
+    /// Range can be extended if borders of range are in the middle of formatted line
+    /// (for example multiline command or string).
+    ///
+    /// Returns `(from, to, text)`, where `from` and `to` are numbers of lines in original
+    /// content (both inclusive) and `text` is a replacement for these lines (without
+    /// line break at the end).
+    pub fn range(&self, from: usize, to: usize) -> Option<(usize, usize, String)> {
+        let (mut from, mut to) = (from.min(to), to.max(from));
+        for ln in self.lines.iter().filter(|ln| !ln.text.is_empty()) {
+            if ln.from < from && ln.to >= from {
+                from = ln.from;
+            }
+            if ln.from <= to && ln.to > to {
+                to = ln.to;
+            }
+        }
+        let lines = self
+            .lines
+            .iter()
+            .filter(|ln| ln.from >= from && ln.from <= to)
+            .map(|ln| ln.to_string())
+            .collect::<Vec<String>>();
+        if lines.is_empty() {
+            None
+        } else {
+            Some((from, to, lines.join(self.eol)))
+        }
+    }
+}
+
+/// Formatter of `sibs` sources.
+///
+/// Formatting is done over lexer's tokens, which gives a way to keep comments and meta
+/// as it is. AST is used to get structural hints:
+/// - closing braces of components, modules and functions in modules, which should be
+///   followed by `;`;
+/// - component's working path `component name(path)`, which is taken as it is;
+/// - colons of type annotations and comparison operators, which are spaced unlike
+///   paths (`mod::fn`) and types (`Vec<str>`).
+///
+/// Formatter keeps line breaks of original content and normalizes:
+/// - indentation (4 spaces per nesting level);
+/// - spacing between tokens, including operators (`a + b`) and type annotations
+///   (`name: type`);
+/// - spacing inside interpolations `{ expr }`;
+/// - empty lines (not more than one in a row; no empty lines at the beginning and at the
+///   end of blocks).
+pub struct Formatter<'a> {
+    content: &'a str,
+    tokens: &'a [Token],
+    /// Positions (`to.abs`) of closing braces, which should be followed by `;`
+    terminated: HashSet<usize>,
+    /// Regions of content, which should be taken as it is
+    /// * `usize` (key) - absolute position of region's beginning
+    /// * `usize` - absolute position of region's end
+    verbatim: HashMap<usize, usize>,
+    /// Positions (`from.abs`) of colons of type annotations `name: type`
+    annotations: HashSet<usize>,
+    /// Positions (`from.abs`) of comparison operators. Tokens `<` and `>` are used by types
+    /// as well (`Vec<num>`), that's why comparisons are taken from AST
+    comparisons: HashSet<usize>,
+}
+
+impl<'a> Formatter<'a> {
+    pub fn new(anchor: &Anchor, tokens: &'a [Token], content: &'a str) -> Self {
+        fn collect(nodes: Vec<&LinkedNode>, formatter: &mut Formatter) {
+            for node in nodes.into_iter() {
+                match node.get_node() {
+                    Node::Root(Root::Component(component)) => {
+                        formatter.terminated.insert(component.close_bl.pos.to.abs);
+                        formatter
+                            .verbatim
+                            .insert(component.name.pos.to.abs, component.open_bl.pos.from.abs);
+                    }
+                    Node::Root(Root::Module(module)) => {
+                        formatter.terminated.insert(module.close.pos.to.abs);
+                        for node in module.nodes.iter() {
+                            let Node::Declaration(Declaration::FunctionDeclaration(func)) =
+                                node.get_node()
+                            else {
+                                continue;
+                            };
+                            if let Node::Statement(Statement::Block(block)) = func.block.get_node()
+                            {
+                                formatter.terminated.insert(block.close.pos.to.abs);
+                            }
+                        }
+                    }
+                    Node::Declaration(Declaration::VariableTypeDeclaration(ty)) => {
+                        formatter.annotations.insert(ty.token.pos.from.abs);
+                    }
+                    Node::Declaration(Declaration::VariableVariants(variants)) => {
+                        formatter.annotations.insert(variants.token.pos.from.abs);
+                    }
+                    Node::Expression(Expression::ComparisonOp(op)) => {
+                        formatter.comparisons.insert(op.token.pos.from.abs);
+                    }
+                    _ => {}
+                }
+                collect(node.childs(), formatter);
+            }
+        }
+        let mut formatter = Self {
+            content,
+            tokens,
+            terminated: HashSet::new(),
+            verbatim: HashMap::new(),
+            annotations: HashSet::new(),
+            comparisons: HashSet::new(),
+        };
+        collect(anchor.childs(), &mut formatter);
+        formatter
+    }
+
+    pub fn format(&self) -> Formatted {
+        let mut writer = Writer::default();
+        let mut frames: Vec<Frame> = Vec::new();
+        let mut skip_until: Option<usize> = None;
+        for (idx, token) in self.tokens.iter().enumerate() {
+            if let Some(end) = skip_until {
+                if token.pos.from.abs < end {
+                    continue;
+                }
+                skip_until = None;
+            }
+            let id = token.id();
+            if let Some(Frame::Str(quote)) = frames.last() {
+                // Inside of string or command everything is taken as it is
+                if &id == quote {
+                    frames.pop();
+                } else if id == KindId::LeftBrace {
+                    frames.push(Frame::Interpolation);
+                }
+                writer.verbatim(self.text(token), id, token.pos.to.ln);
+                continue;
+            }
+            let interpolation = frames.iter().any(|f| matches!(f, Frame::Interpolation));
+            match id {
+                KindId::BOF | KindId::EOF => continue,
+                KindId::Whitespace => {
+                    writer.gap = true;
+                    continue;
+                }
+                KindId::LF | KindId::CR | KindId::CRLF => {
+                    if writer.eol.is_none() {
+                        writer.eol = Some(match id {
+                            KindId::CR => "\r",
+                            KindId::CRLF => "\r\n",
+                            _ => "\n",
+                        });
+                    }
+                    if interpolation {
+                        writer.gap = true;
+                    } else {
+                        writer.break_line();
+                    }
+                    continue;
+                }
+                _ => {}
+            }
+            if writer.is_line_start() {
+                let indent = match (frames.last(), &id) {
+                    (
+                        Some(Frame::Block(indent)) | Some(Frame::Group(indent)),
+                        KindId::RightBrace | KindId::RightParen | KindId::RightBracket,
+                    ) => *indent,
+                    _ => frames
+                        .iter()
+                        .rev()
+                        .find_map(|f| match f {
+                            Frame::Block(indent) | Frame::Group(indent) => Some(indent + 1),
+                            _ => None,
+                        })
+                        .unwrap_or_default(),
+                };
+                writer.open_line(indent, token.pos.from.ln, is_closing(&id));
+            }
+            if let Some((from, to)) = self
+                .verbatim
+                .iter()
+                .find(|(from, to)| token.pos.from.abs >= **from && token.pos.from.abs < **to)
+            {
+                let text = self.content.get(*from..*to).unwrap_or_default().trim();
+                writer.push(text, KindId::RightParen, token.pos.to.ln, Spacing::Auto);
+                skip_until = Some(*to);
+                continue;
+            }
+            let indent = writer.indent();
+            match id {
+                KindId::LeftBrace => frames.push(Frame::Block(indent)),
+                KindId::LeftParen | KindId::LeftBracket => frames.push(Frame::Group(indent)),
+                KindId::RightBrace | KindId::RightParen | KindId::RightBracket => {
+                    frames.pop();
+                }
+                KindId::SingleQuote | KindId::Backtick => frames.push(Frame::Str(id.clone())),
+                _ => {}
+            }
+            let text = match &token.kind {
+                Kind::Comment(..) | Kind::Meta(..) => self.text(token).trim_end(),
+                _ => self.text(token),
+            };
+            let spacing = match &token.kind {
+                Kind::Keyword(keyword) if is_spaced_keyword(keyword) => Spacing::After,
+                Kind::Colon
+                    if self.annotations.contains(&token.pos.from.abs)
+                        || writer.last == Some(KindId::Label) =>
+                {
+                    Spacing::Attached
+                }
+                Kind::Less | Kind::Greater if self.comparisons.contains(&token.pos.from.abs) => {
+                    Spacing::Around
+                }
+                _ => Spacing::Auto,
+            };
+            writer.push(text, id.clone(), token.pos.to.ln, spacing);
+            if id == KindId::RightBrace
+                && self.terminated.contains(&token.pos.to.abs)
+                && !self.is_followed_by_semicolon(idx)
+            {
+                writer.push(";", KindId::Semicolon, token.pos.to.ln, Spacing::Auto);
+            }
+        }
+        writer.break_line();
+        Formatted {
+            lines: writer.lines,
+            eol: writer.eol.unwrap_or("\n"),
+        }
+    }
+
+    fn text(&self, token: &Token) -> &'a str {
+        self.content
+            .get(token.pos.from.abs..token.pos.to.abs)
+            .unwrap_or_default()
+    }
+
+    fn is_followed_by_semicolon(&self, idx: usize) -> bool {
+        self.tokens
+            .iter()
+            .skip(idx + 1)
+            .find(|tk| {
+                !matches!(
+                    tk.id(),
+                    KindId::Whitespace
+                        | KindId::LF
+                        | KindId::CR
+                        | KindId::CRLF
+                        | KindId::Comment
+                        | KindId::Meta
+                )
+            })
+            .map(|tk| tk.id() == KindId::Semicolon)
+            .unwrap_or_default()
+    }
+}
+
+fn is_closing(id: &KindId) -> bool {
+    matches!(
+        id,
+        KindId::RightBrace | KindId::RightParen | KindId::RightBracket
+    )
+}
+
+/// Returns `true` if tokens should be separated with space
+/// * `prev` - previous token on line
+/// * `next` - next token on line
+/// * `gap` - `true` if tokens were separated with whitespace in original content
+fn is_spaced(prev: &KindId, next: &KindId, gap: bool) -> bool {
+    use KindId::*;
+    if matches!(next, Comment | Meta) {
+        return true;
+    }
+    if matches!(next, Comma | Semicolon | RightParen | RightBracket | Dot) {
+        return false;
+    }
+    if matches!(prev, LeftParen | LeftBracket | Dot | Bang) {
+        return false;
+    }
+    if *next == LeftParen && *prev == Identifier {
+        return false;
+    }
+    if *prev == LeftBrace {
+        return *next != RightBrace;
+    }
+    if matches!(next, LeftBrace | RightBrace) || matches!(prev, Comma | Semicolon) {
+        return true;
+    }
+    let operators = |id: &KindId| {
+        matches!(
+            id,
+            Equals
+                | EqualEqual
+                | BangEqual
+                | LessEqual
+                | GreaterEqual
+                | And
+                | Or
+                | PlusEqual
+                | MinusEqual
+                | StarEqual
+                | SlashEqual
+                | Arrow
+                | DoubleArrow
+                | Plus
+                | Minus
+                | Star
+                | Slash
+        )
+    };
+    if operators(prev) || operators(next) {
+        return true;
+    }
+    if *prev == RightBrace && *next == Keyword {
+        return true;
+    }
+    gap
+}
+
+/// Returns `true` if the keyword always should be followed by space
+fn is_spaced_keyword(keyword: &Keyword) -> bool {
+    matches!(
+        keyword,
+        Keyword::Let
+            | Keyword::If
+            | Keyword::Else
+            | Keyword::While
+            | Keyword::For
+            | Keyword::Each
+            | Keyword::In
+            | Keyword::Fn
+            | Keyword::Mod
+            | Keyword::Include
+            | Keyword::Task
+            | Keyword::Component
+            | Keyword::Private
+            | Keyword::Return
+            | Keyword::Break
+            | Keyword::Continue
+    )
+}
+
+/// Spacing of token, which overrides rules of [`is_spaced`]
+enum Spacing {
+    /// Spacing is defined by neighbouring tokens
+    Auto,
+    /// Token is followed by space (keywords like `let`, `if`)
+    After,
+    /// Token is attached to previous one and followed by space (`name: type`, `'label: loop`)
+    Attached,
+    /// Token is surrounded by spaces
+    Around,
+}
+
+#[derive(Default)]
+struct Writer {
+    lines: Vec<FormattedLine>,
+    current: Option<FormattedLine>,
+    /// Recent token on current line
+    last: Option<KindId>,
+    /// Keyword, which requires space after
+    spaced: bool,
+    /// Whitespace between tokens has been found in original content
+    gap: bool,
+    /// Empty line has been found in original content
+    blank: bool,
+    eol: Option<&'static str>,
+}
+
+impl Writer {
+    fn is_line_start(&self) -> bool {
+        self.current.is_none()
+    }
+
+    fn indent(&self) -> usize {
+        self.current
+            .as_ref()
+            .map(|ln| ln.indent)
+            .unwrap_or_default()
+    }
+
+    fn open_line(&mut self, indent: usize, ln: usize, closing: bool) {
+        let after_opening = self
+            .lines
+            .last()
+            .map(|prev| {
+                prev.text
+                    .chars()
+                    .last()
+                    .map(|ch| matches!(ch, '{' | '(' | '['))
+                    .unwrap_or_default()
+            })
+            .unwrap_or(true);
+        if self.blank && !closing && !after_opening {
+            self.lines.push(FormattedLine {
+                text: String::new(),
+                indent: 0,
+                from: ln,
+                to: ln,
+            });
+        }
+        self.blank = false;
+        self.gap = false;
+        self.last = None;
+        self.spaced = false;
+        self.current = Some(FormattedLine {
+            text: String::new(),
+            indent,
+            from: ln,
+            to: ln,
+        });
+    }
+
+    fn break_line(&mut self) {
+        if let Some(ln) = self.current.take() {
+            self.lines.push(ln);
+        } else if !self.lines.is_empty() {
+            self.blank = true;
+        }
+        self.gap = false;
+        self.last = None;
+    }
+
+    /// Adds token to current line
+    fn push(&mut self, text: &str, id: KindId, ln: usize, spacing: Spacing) {
+        let Some(current) = self.current.as_mut() else {
+            return;
+        };
+        if let Some(prev) = self.last.as_ref() {
+            let space = if matches!(spacing, Spacing::Attached) {
+                false
+            } else if matches!(spacing, Spacing::Around) {
+                true
+            } else if self.spaced {
+                !matches!(
+                    id,
+                    KindId::Semicolon | KindId::RightParen | KindId::Comma | KindId::Dot
+                )
+            } else {
+                is_spaced(prev, &id, self.gap)
+            };
+            if space {
+                current.text.push(' ');
+            }
+        }
+        current.text.push_str(text);
+        current.to = ln;
+        self.spaced = !matches!(spacing, Spacing::Auto);
+        self.last = Some(id);
+        self.gap = false;
+    }
+
+    fn verbatim(&mut self, text: &str, id: KindId, ln: usize) {
+        let Some(current) = self.current.as_mut() else {
+            return;
+        };
+        current.text.push_str(text);
+        current.to = ln;
+        self.spaced = false;
+        self.last = Some(id);
+        self.gap = false;
+    }
+}
@@ -0,0 +1,25 @@
+mod tests;
+
+#[macro_export]
+macro_rules! formatting {
+    ($test_name:ident, $content:literal, $expected:literal ) => {
+        paste::item! {
+            #[test]
+            fn [< formatting_ $test_name >]() {
+                use $crate::*;
+
+                let mut driver = Driver::unbound($content, true);
+                driver.read().unwrap_or_else(|err| panic!("{err}"));
+                let formatted = driver.format().unwrap_or_else(|err| panic!("{err}")).text();
+                assert_eq!(formatted, $expected);
+                // Formatting should be idempotent
+                let mut driver = Driver::unbound(&formatted, true);
+                driver.read().unwrap_or_else(|err| panic!("{err}"));
+                assert_eq!(
+                    driver.format().unwrap_or_else(|err| panic!("{err}")).text(),
+                    formatted
+                );
+            }
+        }
+    };
+}
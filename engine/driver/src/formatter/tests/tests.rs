@@ -0,0 +1,226 @@
+use crate::*;
+
+formatting!(
+    indentation,
+    r#"component a() {
+task b() {
+        let a = 5;
+      if a == 5 {
+  return true;
+          }
+    false;
+}
+};
+"#,
+    r#"component a() {
+    task b() {
+        let a = 5;
+        if a == 5 {
+            return true;
+        }
+        false;
+    }
+};
+"#
+);
+
+formatting!(
+    semicolons,
+    r#"mod a {
+    fn sum(a: num, b: num) {
+        a + b;
+    }
+}
+
+component b(./some/path) {
+    task c() {
+        a::sum(1, 2);
+    }
+}"#,
+    r#"mod a {
+    fn sum(a: num, b: num) {
+        a + b;
+    };
+};
+
+component b(./some/path) {
+    task c() {
+        a::sum(1, 2);
+    }
+};
+"#
+);
+
+formatting!(
+    spacing,
+    r#"component a() {
+    task b(  a:num,b :   str ) {
+        let c=a+1;
+        let d :bool=c==2&&b!="a";
+        if !d{return false;}else{ strs::repeat( b ,c );}
+        let e = fs::is_file( "a" ).is_success();
+        let f :Vec<str> ="a,b".split( "," );
+        let g=c>1&&c<a;
+        let h=a*2-c/2;
+        'h:loop{ break 'h; }
+    }
+};
+"#,
+    r#"component a() {
+    task b(a: num, b: str) {
+        let c = a + 1;
+        let d: bool = c == 2 && b != "a";
+        if !d { return false; } else { strs::repeat(b, c); }
+        let e = fs::is_file("a").is_success();
+        let f: Vec<str> = "a,b".split(",");
+        let g = c > 1 && c < a;
+        let h = a * 2 - c / 2;
+        'h: loop { break 'h; }
+    }
+};
+"#
+);
+
+formatting!(
+    interpolation,
+    r#"component a() {
+    task b() {
+        let a = 'one {a}two{  if a == 1 {"b";} else {"c";}  }';
+        let b = `some {    a   } command  with   spaces`;
+        let c = "no {a} interpolation";
+    }
+};
+"#,
+    r#"component a() {
+    task b() {
+        let a = 'one { a }two{ if a == 1 { "b"; } else { "c"; } }';
+        let b = `some { a } command  with   spaces`;
+        let c = "no {a} interpolation";
+    }
+};
+"#
+);
+
+formatting!(
+    comments_and_meta,
+    r#"/// Component description
+component a() {
+
+
+// comment
+    ///   Task description
+        task b() {
+                // inner comment   
+        true;
+
+    }
+
+
+    task c() {
+
+        true;
+    }
+};
+"#,
+    r#"/// Component description
+component a() {
+    // comment
+    ///   Task description
+    task b() {
+        // inner comment
+        true;
+    }
+
+    task c() {
+        true;
+    }
+};
+"#
+);
+
+formatting!(
+    multiline_groups,
+    r#"component a() {
+    task b() {
+        let a = [
+        1,
+                2,
+    3
+        ];
+        print(
+      a,
+         "b"
+        );
+    }
+};
+"#,
+    r#"component a() {
+    task b() {
+        let a = [
+            1,
+            2,
+            3
+        ];
+        print(
+            a,
+            "b"
+        );
+    }
+};
+"#
+);
+
+#[test]
+fn formatting_playground() {
+    use std::env::current_dir;
+    let mut driver = Driver::new(
+        current_dir().unwrap().join("../tests/playground/test.sibs"),
+        true,
+    );
+    driver.read().unwrap_or_else(|err| panic!("{err}"));
+    let formatted = driver.format().unwrap_or_else(|err| panic!("{err}")).text();
+    let mut driver = Driver::unbound(&formatted, true);
+    driver.read().unwrap_or_else(|err| panic!("{err}"));
+    assert_eq!(
+        driver.format().unwrap_or_else(|err| panic!("{err}")).text(),
+        formatted
+    );
+}
+
+#[test]
+fn formatting_range() {
+    let mut driver = Driver::unbound(
+        r#"component a() {
+    task b() {
+    let a = 5;
+          let b=6;
+        let c = 7;
+    }
+};
+"#,
+        true,
+    );
+    driver.read().unwrap_or_else(|err| panic!("{err}"));
+    let formatted = driver.format().unwrap_or_else(|err| panic!("{err}"));
+    let (from, to, text) = formatted
+        .range(2, 3)
+        .unwrap_or_else(|| panic!("Fail to get range"));
+    assert_eq!(from, 2);
+    assert_eq!(to, 3);
+    assert_eq!(text, "        let a = 5;\n        let b = 6;");
+}
+
+#[test]
+fn formatting_invalid() {
+    let mut driver = Driver::unbound(
+        r#"component a() {
+    task b() {
+        let a = ;
+    }
+};
+"#,
+        true,
+    );
+    driver.read().unwrap_or_else(|err| panic!("{err}"));
+    assert!(driver.format().is_err());
+}
@@ -1,12 +1,22 @@
 mod completion;
 mod error;
 mod errors;
+mod formatter;
 mod location;
 mod locator;
 mod map;
+mod outline;
+mod references;
 mod signature;
+#[cfg(test)]
+mod tests;
 
-use std::{cell::Ref, fmt, io, path::PathBuf};
+use std::{
+    cell::Ref,
+    fmt, io,
+    path::{Path, PathBuf},
+};
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, warn};
 use uuid::Uuid;
 
@@ -20,13 +30,20 @@ pub(crate) use runtime::{Fns, Ty, TyScope};
 pub(crate) use semantic::*;
 
 pub use completion::*;
+pub use formatter::*;
+pub use outline::{OutlineItem, OutlineKind};
+pub use references::{is_valid_name, Symbol, SymbolKind, SymbolLocation};
 pub use signature::*;
 
 pub(crate) use error::*;
 pub(crate) use errors::*;
 pub(crate) use locator::*;
+pub(crate) use outline::*;
+pub(crate) use references::*;
 
 pub use error::E as DriverError;
+pub use parser::{ParseCache, SharedParseCache};
+pub use semantic::{Fix, FixEdit, FixKind};
 
 fn find_node<'a>(
     nodes: Vec<&'a LinkedNode>,
@@ -80,6 +97,8 @@ fn get_ownership_tree<'a>(
 pub enum CodeSrc {
     Path(PathBuf),
     Text(String),
+    /// In-memory content of the file (for example, unsaved changes of a document)
+    Buffer(PathBuf, String),
 }
 
 impl fmt::Display for CodeSrc {
@@ -88,7 +107,7 @@ impl fmt::Display for CodeSrc {
             f,
             "{}",
             match self {
-                Self::Path(path) => path.to_string_lossy().to_string(),
+                Self::Path(path) | Self::Buffer(path, ..) => path.to_string_lossy().to_string(),
                 Self::Text(..) => String::from("text codebase"),
             }
         )
@@ -101,6 +120,8 @@ pub struct Driver {
     errors: Vec<DrivingError>,
     src: CodeSrc,
     resilience: bool,
+    cache: Option<SharedParseCache>,
+    cancel: Option<CancellationToken>,
 }
 
 impl Driver {
@@ -112,6 +133,8 @@ impl Driver {
             errors: Vec::new(),
             src: CodeSrc::Path(path.into()),
             resilience,
+            cache: None,
+            cancel: None,
         }
     }
     pub fn unbound<S: ToString>(content: S, resilience: bool) -> Self {
@@ -122,17 +145,65 @@ impl Driver {
             errors: Vec::new(),
             src: CodeSrc::Text(content.to_string()),
             resilience,
+            cache: None,
+            cancel: None,
+        }
+    }
+    /// Creates driver for in-memory content of the file. Relative paths (`mod from`,
+    /// `include from`) are resolved from the location of the file.
+    pub fn buffered<P: Into<PathBuf>, S: ToString>(path: P, content: S, resilience: bool) -> Self {
+        Self {
+            parser: None,
+            scx: None,
+            anchor: None,
+            errors: Vec::new(),
+            src: CodeSrc::Buffer(path.into(), content.to_string()),
+            resilience,
+            cache: None,
+            cancel: None,
+        }
+    }
+
+    /// Sets cache of attached files (`mod from`, `include from`), which can be shared
+    /// between drivers.
+    pub fn set_cache(&mut self, cache: SharedParseCache) {
+        self.cache = Some(cache);
+    }
+
+    /// Sets token to cancel reading. Cancelled reading returns `E::Cancelled`.
+    pub fn set_cancellation(&mut self, cancel: CancellationToken) {
+        self.cancel = Some(cancel);
+    }
+
+    fn check_cancellation(&self) -> Result<(), E> {
+        if self
+            .cancel
+            .as_ref()
+            .map(|cancel| cancel.is_cancelled())
+            .unwrap_or_default()
+        {
+            Err(E::Cancelled)
+        } else {
+            Ok(())
         }
     }
 
     pub fn read(&mut self) -> Result<(), E> {
+        self.check_cancellation()?;
         let mut parser = match &self.src {
             CodeSrc::Path(path) => Parser::new(&path, self.resilience)?,
             CodeSrc::Text(content) => {
                 let mut lx = lexer::Lexer::new(&content, 0);
                 Parser::unbound(lx.read()?.tokens, &lx.uuid, &content, self.resilience)
             }
+            CodeSrc::Buffer(path, content) => {
+                let mut lx = lexer::Lexer::new(&content, 0);
+                Parser::buffered(lx.read()?.tokens, &lx.uuid, &content, path, self.resilience)
+            }
         };
+        if let Some(cache) = self.cache.as_ref() {
+            parser.set_cache(cache.clone());
+        }
         let anchor = match Anchor::read(&mut parser) {
             Ok(Some(anchor)) => anchor,
             Ok(None) => {
@@ -163,6 +234,7 @@ impl Driver {
         );
         parser.bind(anchor.nodes())?;
         self.parser = Some(parser);
+        self.check_cancellation()?;
         let mut scx = SemanticCx::new(self.resilience);
         functions::register(&mut scx.fns.efns)?;
         if let Err(err) = anchor.initialize(&mut scx) {
@@ -171,12 +243,14 @@ impl Driver {
             }
             self.errors.push(DrivingError::Semantic(err));
         }
+        self.check_cancellation()?;
         if let Err(err) = anchor.infer_type(&mut scx) {
             if !self.resilience {
                 return Err(err.into());
             }
             self.errors.push(DrivingError::Semantic(err));
         }
+        self.check_cancellation()?;
         if let Err(err) = anchor.finalize(&mut scx) {
             if !self.resilience {
                 return Err(err.into());
@@ -202,6 +276,30 @@ impl Driver {
         parser.get_src_content(src)
     }
 
+    /// Returns uuid of root source
+    pub fn get_root_src(&self) -> Option<Uuid> {
+        self.anchor.as_ref().map(|anchor| anchor.uuid)
+    }
+
+    /// Returns the path of the file which is the source with the given uuid
+    pub fn get_src_filename(&self, src: &Uuid) -> Option<PathBuf> {
+        self.parser.as_ref()?.get_src_filename(src)
+    }
+
+    /// Returns paths of all files attached to the root source (`mod from`, `include from`),
+    /// including files attached by attached files.
+    pub fn get_attached_files(&self) -> Vec<PathBuf> {
+        self.parser
+            .as_ref()
+            .map(|parser| parser.get_attached_files())
+            .unwrap_or_default()
+    }
+
+    /// Returns uuid of the source attached from the given file
+    pub fn get_src_by_filename<P: AsRef<Path>>(&self, filename: P) -> Option<Uuid> {
+        self.parser.as_ref()?.get_src_by_filename(filename)
+    }
+
     pub fn get_semantic_tokens(&self) -> Vec<LinkedSemanticToken> {
         self.anchor
             .as_ref()
@@ -249,6 +347,86 @@ impl Driver {
         ))
     }
 
+    /// Resolves the symbol (variable, function, task or component) in the given position
+    /// and collects its declaration and all usages across all sources.
+    pub fn symbol(&self, pos: usize, src: Option<Uuid>) -> Option<Symbol> {
+        let anchor = self.anchor.as_ref()?;
+        References::new(anchor, self.scx.as_ref()).symbol(&src.unwrap_or(anchor.uuid), pos)
+    }
+
+    /// Returns the tree of declarations (components, tasks, modules and functions) of the
+    /// given source. If src is `None` will return declarations of root source.
+    pub fn outline(&self, src: Option<Uuid>) -> Vec<OutlineItem> {
+        let Some(anchor) = self.anchor.as_ref() else {
+            return Vec::new();
+        };
+        Outline::new(anchor).src(&src.unwrap_or(anchor.uuid))
+    }
+
+    /// Returns the tree of declarations across all sources, including sources attached with
+    /// `include from` and `mod from`.
+    pub fn workspace_outline(&self) -> Vec<OutlineItem> {
+        let Some(anchor) = self.anchor.as_ref() else {
+            return Vec::new();
+        };
+        Outline::new(anchor).all()
+    }
+
+    /// Returns suggested fixes of errors located in the given range (`from..=to`) of the
+    /// source. If src is `None` will return fixes for root source. Attaching of a module is
+    /// suggested only if the file of module exists next to the source.
+    pub fn fixes(&self, from: usize, to: usize, src: Option<Uuid>) -> Vec<Fix> {
+        let Some(src) = src.or_else(|| self.get_root_src()) else {
+            return Vec::new();
+        };
+        let dir = self
+            .get_src_filename(&src)
+            .and_then(|filename| filename.parent().map(|dir| dir.to_path_buf()));
+        let mut fixes: Vec<Fix> = Vec::new();
+        for err in self.errors.iter() {
+            let DrivingError::Semantic(err) = err else {
+                continue;
+            };
+            if err.link.src != src || err.link.from.abs > to || err.link.to.abs < from {
+                continue;
+            }
+            for fix in err.e.fixes() {
+                if let FixKind::Import(module) = &fix.kind {
+                    if !dir
+                        .as_ref()
+                        .is_some_and(|dir| dir.join(format!("{module}.sibs")).is_file())
+                    {
+                        continue;
+                    }
+                }
+                if !fixes.contains(fix) {
+                    fixes.push(fix.clone());
+                }
+            }
+        }
+        fixes
+    }
+
+    /// Formats root source. Formatting is available only for sources without parsing errors.
+    pub fn format(&self) -> Result<Formatted, E> {
+        let (Some(anchor), Some(parser)) = (self.anchor.as_ref(), self.parser.as_ref()) else {
+            return Err(E::FailExtractAnchorNodeFrom(self.src.to_string()));
+        };
+        let errors = self
+            .errors
+            .iter()
+            .filter(|err| matches!(err, DrivingError::Parsing(..)))
+            .count();
+        if errors > 0 {
+            return Err(E::FormattingOfInvalidSrc(errors));
+        }
+        let content = parser
+            .get_src_content(None)?
+            .ok_or(E::FailExtractAnchorNodeFrom(self.src.to_string()))?;
+        let tokens = parser.tokens.borrow();
+        Ok(Formatter::new(anchor, &tokens, &content).format())
+    }
+
     pub fn errors(&self) -> Option<ErrorsIterator<'_>> {
         let (Some(anchor), Some(parser)) = (self.anchor.as_ref(), self.parser.as_ref()) else {
             return None;
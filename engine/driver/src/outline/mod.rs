@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod tests;
+
+use crate::*;
+
+/// Kind of outline's item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutlineKind {
+    Component,
+    Task,
+    Module,
+    Function,
+}
+
+/// Declaration (component, task, module or function) in the source code
+#[derive(Debug, Clone)]
+pub struct OutlineItem {
+    pub kind: OutlineKind,
+    pub name: String,
+    /// Meta information (`///` comments) of declaration
+    pub detail: Option<String>,
+    /// Uuid of source (file), which includes declaration
+    pub src: Uuid,
+    /// Position of whole declaration
+    pub position: Position,
+    /// Position of the name of declaration
+    pub selection: Position,
+    pub children: Vec<OutlineItem>,
+}
+
+impl OutlineItem {
+    fn new(
+        kind: OutlineKind,
+        name: String,
+        detail: Option<String>,
+        node: &LinkedNode,
+        token: &Token,
+        children: Vec<OutlineItem>,
+    ) -> Self {
+        Self {
+            kind,
+            name,
+            detail,
+            src: token.src,
+            position: node.get_position(),
+            selection: token.pos.clone(),
+            children,
+        }
+    }
+
+    /// Returns all items as a flat list. Each item is paired with the name of its parent.
+    pub fn flatten<'a>(
+        items: &'a [OutlineItem],
+        parent: Option<&'a str>,
+    ) -> Vec<(&'a OutlineItem, Option<&'a str>)> {
+        let mut flatten = Vec::new();
+        for item in items.iter() {
+            flatten.push((item, parent));
+            flatten.extend(Self::flatten(&item.children, Some(&item.name)));
+        }
+        flatten
+    }
+}
+
+/// Collects the tree of declarations, including declarations of files attached with
+/// `include from` and `mod from`.
+pub(crate) struct Outline<'a> {
+    md: AnchorMetadata<'a>,
+    anchor: &'a Anchor,
+}
+
+impl<'a> Outline<'a> {
+    pub fn new(anchor: &'a Anchor) -> Self {
+        Self {
+            md: anchor.get_components_md(),
+            anchor,
+        }
+    }
+
+    /// Returns the full tree of declarations across all sources
+    pub fn all(&self) -> Vec<OutlineItem> {
+        self.collect(self.anchor.childs(), None)
+    }
+
+    /// Returns the tree of declarations from the given source only
+    pub fn src(&self, src: &Uuid) -> Vec<OutlineItem> {
+        fn retain(items: Vec<OutlineItem>, src: &Uuid) -> Vec<OutlineItem> {
+            let mut retained = Vec::new();
+            for mut item in items.into_iter() {
+                if &item.src == src {
+                    item.children = retain_own(item.children, src);
+                    retained.push(item);
+                } else {
+                    retained.extend(retain(item.children, src));
+                }
+            }
+            retained
+        }
+        fn retain_own(items: Vec<OutlineItem>, src: &Uuid) -> Vec<OutlineItem> {
+            items
+                .into_iter()
+                .filter(|item| &item.src == src)
+                .map(|mut item| {
+                    item.children = retain_own(item.children, src);
+                    item
+                })
+                .collect()
+        }
+        retain(self.all(), src)
+    }
+
+    fn detail(lines: Vec<String>) -> Option<String> {
+        if lines.is_empty() {
+            None
+        } else {
+            Some(lines.join(" "))
+        }
+    }
+
+    fn collect(&self, nodes: Vec<&LinkedNode>, component: Option<&str>) -> Vec<OutlineItem> {
+        let mut items = Vec::new();
+        for node in nodes.into_iter() {
+            match node.get_node() {
+                Node::Root(Root::Component(comp)) => {
+                    let name = comp.get_name();
+                    let detail = self
+                        .md
+                        .get(&name)
+                        .and_then(|(md, _)| Self::detail(md.lines()));
+                    let children = self.collect(comp.nodes.iter().collect(), Some(&name));
+                    items.push(OutlineItem::new(
+                        OutlineKind::Component,
+                        name,
+                        detail,
+                        node,
+                        &comp.name,
+                        children,
+                    ));
+                }
+                Node::Root(Root::Task(task)) => {
+                    let name = task.get_name();
+                    let detail = component
+                        .and_then(|component| self.md.get(component))
+                        .and_then(|(_, tasks)| tasks.iter().find(|(task, _)| task == &name))
+                        .map(|(_, md)| md.lines())
+                        .unwrap_or_else(|| node.get_md().lines());
+                    items.push(OutlineItem::new(
+                        OutlineKind::Task,
+                        name,
+                        Self::detail(detail),
+                        node,
+                        &task.name,
+                        Vec::new(),
+                    ));
+                }
+                Node::Root(Root::Module(module)) => {
+                    items.push(OutlineItem::new(
+                        OutlineKind::Module,
+                        module.name.to_string(),
+                        Self::detail(node.get_md().lines()),
+                        node,
+                        &module.name,
+                        self.collect(module.nodes.iter().collect(), None),
+                    ));
+                }
+                Node::Declaration(Declaration::ModuleDeclaration(module)) => {
+                    items.push(OutlineItem::new(
+                        OutlineKind::Module,
+                        module.name.to_owned(),
+                        Self::detail(node.get_md().lines()),
+                        node,
+                        &module.sig,
+                        self.collect(module.nodes.iter().collect(), None),
+                    ));
+                }
+                Node::Declaration(Declaration::FunctionDeclaration(func)) => {
+                    items.push(OutlineItem::new(
+                        OutlineKind::Function,
+                        func.name.to_string(),
+                        Self::detail(node.get_md().lines()),
+                        node,
+                        &func.name,
+                        Vec::new(),
+                    ));
+                }
+                Node::Declaration(Declaration::IncludeDeclaration(include)) => {
+                    items.extend(self.collect(vec![&include.root], None));
+                }
+                Node::Root(Root::Anchor(anchor)) => {
+                    items.extend(self.collect(anchor.childs(), None));
+                }
+                _ => {}
+            }
+        }
+        items
+    }
+}
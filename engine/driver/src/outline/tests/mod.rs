@@ -0,0 +1,35 @@
+mod tests;
+
+#[macro_export]
+macro_rules! outline {
+    ($test_name:ident, $content:literal, $expected:literal ) => {
+        paste::item! {
+            #[test]
+            fn [< outline_ $test_name >]() {
+                use $crate::*;
+
+                fn print(items: &[OutlineItem], level: usize, output: &mut Vec<String>) {
+                    for item in items.iter() {
+                        output.push(format!(
+                            "{}{:?} {}{}",
+                            " ".repeat(level * 4),
+                            item.kind,
+                            item.name,
+                            item.detail
+                                .as_ref()
+                                .map(|detail| format!(" ({detail})"))
+                                .unwrap_or_default()
+                        ));
+                        print(&item.children, level + 1, output);
+                    }
+                }
+
+                let mut driver = Driver::unbound($content, true);
+                driver.read().unwrap_or_else(|err| panic!("{err}"));
+                let mut output = Vec::new();
+                print(&driver.outline(None), 0, &mut output);
+                assert_eq!(output.join("\n"), $expected);
+            }
+        }
+    };
+}
@@ -0,0 +1,96 @@
+use crate::*;
+
+outline!(
+    components,
+    r#"/// Builds everything
+component a() {
+    /// Compiles sources
+    task build() {
+        true;
+    }
+    task test() {
+        true;
+    }
+};
+component b() {
+    task run() {
+        true;
+    }
+};"#,
+    r#"Component a (Builds everything)
+    Task build (Compiles sources)
+    Task test
+Component b
+    Task run"#
+);
+
+outline!(
+    modules,
+    r#"/// Math functions
+mod math {
+    /// Sum of two numbers
+    fn sum(a: num, b: num) {
+        a + b;
+    };
+    mod inner {
+        fn diff(a: num, b: num) {
+            a - b;
+        };
+    };
+};
+component a() {
+    task run() {
+        math::sum(1, 2);
+    }
+};"#,
+    r#"Module math (Math functions)
+    Function sum (Sum of two numbers)
+    Module inner
+        Function diff
+Component a
+    Task run"#
+);
+
+#[test]
+fn outline_across_files() {
+    use std::env::current_dir;
+
+    let path = current_dir().unwrap().join("../tests/mods/main.sibs");
+    let mut driver = Driver::new(&path, true);
+    driver.read().unwrap_or_else(|err| panic!("{err}"));
+    let root = driver.outline(None);
+    assert_eq!(
+        root.iter()
+            .map(|item| (item.kind, item.name.as_str(), item.children.len()))
+            .collect::<Vec<(OutlineKind, &str, usize)>>(),
+        vec![
+            (OutlineKind::Module, "math", 0),
+            (OutlineKind::Module, "inner_mod_a", 1),
+            (OutlineKind::Component, "component_a", 1),
+        ]
+    );
+    let math = driver
+        .get_src_by_filename(path.parent().unwrap().join("mods/math.sibs"))
+        .expect("Source of math.sibs is attached");
+    assert_eq!(
+        driver
+            .outline(Some(math))
+            .iter()
+            .map(|item| (item.kind, item.name.as_str()))
+            .collect::<Vec<(OutlineKind, &str)>>(),
+        vec![
+            (OutlineKind::Module, "nested"),
+            (OutlineKind::Module, "inner"),
+            (OutlineKind::Function, "sum"),
+            (OutlineKind::Function, "diff"),
+        ]
+    );
+    let workspace = driver.workspace_outline();
+    let flatten = OutlineItem::flatten(&workspace, None);
+    assert!(flatten
+        .iter()
+        .any(|(item, parent)| item.name == "task_b" && parent == &Some("component_b")));
+    assert!(flatten
+        .iter()
+        .any(|(item, parent)| item.name == "diff" && parent == &Some("nested")));
+}
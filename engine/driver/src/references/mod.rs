@@ -0,0 +1,324 @@
+#[cfg(test)]
+mod tests;
+
+use std::collections::HashMap;
+
+use crate::*;
+
+/// Kind of symbol, which can be resolved in the source code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolKind {
+    Variable,
+    Function,
+    Task,
+    Component,
+}
+
+/// Location of the symbol's occurrence
+#[derive(Debug, Clone)]
+pub struct SymbolLocation {
+    /// Uuid of source (file), which includes the occurrence
+    pub src: Uuid,
+    /// Position of the identifier
+    pub position: Position,
+}
+
+/// Resolved symbol with its declaration and all usages
+#[derive(Debug)]
+pub struct Symbol {
+    pub kind: SymbolKind,
+    pub name: String,
+    /// Location of declaration. Can be `None` for built-in functions or if the
+    /// declaration isn't available (for example, because of errors)
+    pub declaration: Option<SymbolLocation>,
+    pub usages: Vec<SymbolLocation>,
+}
+
+impl Symbol {
+    /// Returns all occurrences of symbol. Declaration (if it exists) is always first.
+    pub fn locations(&self, include_declaration: bool) -> Vec<&SymbolLocation> {
+        let mut locations = Vec::new();
+        if include_declaration {
+            if let Some(declaration) = self.declaration.as_ref() {
+                locations.push(declaration);
+            }
+        }
+        locations.extend(self.usages.iter());
+        locations
+    }
+
+    /// Symbol can be renamed only if it's declared in the source code
+    pub fn is_renamable(&self) -> bool {
+        self.declaration.is_some()
+    }
+}
+
+/// Checks whether the given string can be used as a name of symbol (it's a valid identifier
+/// and isn't a keyword).
+pub fn is_valid_name<S: AsRef<str>>(name: S) -> bool {
+    let Ok(tokens) = lexer::Lexer::new(name.as_ref(), 0).read() else {
+        return false;
+    };
+    let mut tokens = tokens
+        .tokens
+        .iter()
+        .filter(|tk| !matches!(tk.kind, Kind::BOF | Kind::EOF));
+    matches!(
+        (tokens.next().map(|tk| &tk.kind), tokens.next()),
+        (Some(Kind::Identifier(..)), None)
+    )
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum SymbolKey {
+    /// Uuid of variable's declaration (`VariableName` node)
+    Variable(Uuid),
+    /// Uuid of function's declaration
+    Function(Uuid),
+    /// Fullname of embedded function
+    EmbeddedFunction(String),
+    /// Uuid of task's declaration
+    Task(Uuid),
+    /// Name of component
+    Component(String),
+}
+
+impl SymbolKey {
+    fn kind(&self) -> SymbolKind {
+        match self {
+            Self::Variable(..) => SymbolKind::Variable,
+            Self::Function(..) | Self::EmbeddedFunction(..) => SymbolKind::Function,
+            Self::Task(..) => SymbolKind::Task,
+            Self::Component(..) => SymbolKind::Component,
+        }
+    }
+}
+
+struct Occurrence {
+    key: SymbolKey,
+    token: Token,
+    declaration: bool,
+}
+
+/// Walks through the AST and collects all occurrences of symbols. Variables are resolved
+/// by scopes; functions and tasks are resolved with links collected by semantic analysis.
+pub(crate) struct References<'a> {
+    scx: Option<&'a SemanticCx>,
+    scopes: Vec<HashMap<String, Uuid>>,
+    occurrences: Vec<Occurrence>,
+}
+
+impl<'a> References<'a> {
+    pub fn new(anchor: &Anchor, scx: Option<&'a SemanticCx>) -> Self {
+        let mut refs = Self {
+            scx,
+            scopes: vec![HashMap::new()],
+            occurrences: Vec::new(),
+        };
+        anchor.childs().into_iter().for_each(|node| refs.walk(node));
+        refs
+    }
+
+    /// Looks for the symbol in the given position of the source.
+    pub fn symbol(&self, src: &Uuid, pos: usize) -> Option<Symbol> {
+        let key = &self
+            .occurrences
+            .iter()
+            .find(|oc| {
+                &oc.token.src == src
+                    && oc.token.pos.from.abs <= pos
+                    && pos <= oc.token.pos.to.abs
+            })?
+            .key;
+        let mut symbol = Symbol {
+            kind: key.kind(),
+            name: String::new(),
+            declaration: None,
+            usages: Vec::new(),
+        };
+        for oc in self.occurrences.iter().filter(|oc| &oc.key == key) {
+            let location = SymbolLocation {
+                src: oc.token.src,
+                position: oc.token.pos.clone(),
+            };
+            if oc.declaration && symbol.declaration.is_none() {
+                symbol.name = oc.token.to_string();
+                symbol.declaration = Some(location);
+            } else {
+                if symbol.name.is_empty() {
+                    symbol.name = oc.token.to_string();
+                }
+                symbol.usages.push(location);
+            }
+        }
+        Some(symbol)
+    }
+
+    fn add(&mut self, key: SymbolKey, token: &Token, declaration: bool) {
+        self.occurrences.push(Occurrence {
+            key,
+            token: token.clone(),
+            declaration,
+        });
+    }
+
+    fn declare(&mut self, node: &LinkedNode) {
+        let (ident, token, uuid) = match node.get_node() {
+            Node::Declaration(Declaration::VariableName(variable)) => {
+                (&variable.ident, &variable.token, variable.uuid)
+            }
+            // Elements of `for` are declared as variables
+            Node::Expression(Expression::Variable(variable)) => {
+                (&variable.ident, &variable.token, variable.uuid)
+            }
+            _ => {
+                self.walk(node);
+                return;
+            }
+        };
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.insert(ident.to_owned(), uuid);
+        }
+        self.add(SymbolKey::Variable(uuid), token, true);
+    }
+
+    fn resolve(&mut self, ident: &str, token: &Token) {
+        let Some(uuid) = self
+            .scopes
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(ident).copied())
+        else {
+            return;
+        };
+        self.add(SymbolKey::Variable(uuid), token, false);
+    }
+
+    fn scoped<F: FnOnce(&mut Self)>(&mut self, inner: F) {
+        self.scopes.push(HashMap::new());
+        inner(self);
+        self.scopes.pop();
+    }
+
+    fn walk_all(&mut self, nodes: Vec<&LinkedNode>) {
+        nodes.into_iter().for_each(|node| self.walk(node));
+    }
+
+    fn function_key(&self, caller: &Uuid) -> Option<SymbolKey> {
+        let fns = &self.scx?.fns;
+        if let Some(entity) = fns
+            .ufns
+            .links
+            .get(caller)
+            .and_then(|name| fns.ufns.funcs.get(name))
+        {
+            Some(SymbolKey::Function(entity.uuid))
+        } else {
+            fns.efns
+                .links
+                .get(caller)
+                .map(|name| SymbolKey::EmbeddedFunction(name.to_owned()))
+        }
+    }
+
+    fn task_key(&self, caller: &Uuid) -> Option<SymbolKey> {
+        let tasks = &self.scx?.tasks;
+        tasks
+            .links
+            .get(caller)
+            .and_then(|name| tasks.table.get(name))
+            .map(|entity| SymbolKey::Task(entity.uuid))
+    }
+
+    fn walk(&mut self, node: &LinkedNode) {
+        match node.get_node() {
+            Node::Root(Root::Component(component)) => {
+                self.add(
+                    SymbolKey::Component(component.get_name()),
+                    &component.name,
+                    true,
+                );
+                self.walk_all(node.get_node().childs());
+            }
+            Node::Root(Root::Task(task)) => {
+                self.add(SymbolKey::Task(task.uuid), &task.name, true);
+                self.walk_all(task.gts.iter().collect());
+                self.scoped(|refs| {
+                    task.args.iter().for_each(|arg| refs.walk(arg));
+                    refs.walk(&task.block);
+                });
+            }
+            Node::Declaration(Declaration::FunctionDeclaration(func)) => {
+                self.add(SymbolKey::Function(func.uuid), &func.name, true);
+                self.scoped(|refs| {
+                    func.args.iter().for_each(|arg| refs.walk(arg));
+                    refs.walk(&func.block);
+                });
+            }
+            Node::Value(Value::Closure(closure)) => {
+                self.scoped(|refs| {
+                    closure.args.iter().for_each(|arg| refs.walk(arg));
+                    refs.walk(&closure.block);
+                });
+            }
+            Node::Declaration(Declaration::ArgumentDeclaration(arg)) => {
+                self.walk(&arg.r#type);
+                if let Some(default) = arg.default.as_ref() {
+                    self.walk(default);
+                }
+                self.declare(&arg.variable);
+            }
+            Node::Declaration(Declaration::VariableDeclaration(decl)) => {
+                if let Some(ty) = decl.r#type.as_ref() {
+                    self.walk(ty);
+                }
+                if let Some(assignation) = decl.assignation.as_ref() {
+                    self.walk(assignation);
+                }
+                self.declare(&decl.variable);
+            }
+            Node::Declaration(Declaration::VariableName(variable)) => {
+                self.resolve(&variable.ident, &variable.token);
+            }
+            Node::Expression(Expression::Variable(variable)) => {
+                self.resolve(&variable.ident, &variable.token);
+            }
+            Node::Statement(Statement::For(stm)) => {
+                self.walk(&stm.elements);
+                self.scoped(|refs| {
+                    refs.declare(&stm.element);
+                    if let Some(index) = stm.index.as_ref() {
+                        refs.declare(index);
+                    }
+                    refs.walk(&stm.block);
+                });
+            }
+            Node::Statement(Statement::Block(..)) => {
+                self.scoped(|refs| refs.walk_all(node.get_node().childs()));
+            }
+            Node::Expression(Expression::FunctionCall(call)) => {
+                if let (Some(key), Some((_, token))) =
+                    (self.function_key(&call.uuid), call.reference.last())
+                {
+                    self.add(key, token, false);
+                }
+                self.walk_all(node.get_node().childs());
+            }
+            Node::Expression(Expression::TaskCall(call)) => {
+                if let (Some(key), Some((_, token))) =
+                    (self.task_key(&call.uuid), call.reference.last())
+                {
+                    self.add(key, token, false);
+                }
+                if call.reference.len() > 1 {
+                    let (name, token) = &call.reference[0];
+                    self.add(SymbolKey::Component(name.to_owned()), token, false);
+                }
+                self.walk_all(node.get_node().childs());
+            }
+            _ => self.walk_all(node.get_node().childs()),
+        }
+        // Metadata includes post-processing calls (`a.fn()`)
+        self.walk_all(node.get_md().childs());
+    }
+}
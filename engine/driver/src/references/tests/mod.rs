@@ -0,0 +1,35 @@
+mod tests;
+
+#[macro_export]
+macro_rules! references {
+    ($test_name:ident, $content:literal, $needle:literal, $nth:literal, $kind:expr, $count:literal ) => {
+        paste::item! {
+            #[test]
+            fn [< references_ $test_name >]() {
+                use $crate::*;
+
+                let pos = $content
+                    .match_indices($needle)
+                    .nth($nth)
+                    .map(|(pos, _)| pos)
+                    .unwrap_or_else(|| panic!("Fail to find \"{}\" #{}", $needle, $nth));
+                let mut driver = Driver::unbound($content, true);
+                driver.read().unwrap_or_else(|err| panic!("{err}"));
+                let symbol = driver
+                    .symbol(pos, None)
+                    .unwrap_or_else(|| panic!("Fail to find symbol at {pos}"));
+                assert_eq!(symbol.kind, $kind);
+                assert_eq!(symbol.name, $needle);
+                assert!(symbol.is_renamable());
+                let locations = symbol.locations(true);
+                assert_eq!(locations.len(), $count);
+                for location in locations {
+                    assert_eq!(
+                        &$content[location.position.from.abs..location.position.to.abs],
+                        $needle
+                    );
+                }
+            }
+        }
+    };
+}
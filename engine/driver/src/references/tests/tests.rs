@@ -0,0 +1,227 @@
+use crate::*;
+
+references!(
+    variable,
+    r#"component a() {
+    task b() {
+        let value = 5;
+        let sum = value + 1;
+        if value == 5 {
+            return value;
+        }
+        sum;
+    }
+};"#,
+    "value",
+    2,
+    SymbolKind::Variable,
+    4
+);
+
+references!(
+    variable_shadowing,
+    r#"component a() {
+    task b() {
+        let value = 5;
+        if value == 5 {
+            let value = "str";
+            value;
+        }
+        value;
+    }
+};"#,
+    "value",
+    3,
+    SymbolKind::Variable,
+    2
+);
+
+references!(
+    variable_for,
+    r#"component a() {
+    task b() {
+        let items = [1, 2, 3];
+        for (item, idx) in items {
+            print(item);
+            print(idx);
+        }
+        true;
+    }
+};"#,
+    "item",
+    3,
+    SymbolKind::Variable,
+    2
+);
+
+references!(
+    fn_argument,
+    r#"mod m {
+    fn sum(a: num, b: num) {
+        a + b + a;
+    };
+};
+component c() {
+    task d() {
+        m::sum(1, 2);
+    }
+};"#,
+    "a",
+    1,
+    SymbolKind::Variable,
+    3
+);
+
+references!(
+    task_argument,
+    r#"component a() {
+    task b(target: str) {
+        print(target);
+        target;
+    }
+};"#,
+    "target",
+    0,
+    SymbolKind::Variable,
+    3
+);
+
+references!(
+    function,
+    r#"mod m {
+    fn calc(a: num, b: num) {
+        a + b;
+    };
+};
+component a() {
+    task b() {
+        let c = m::calc(1, 2);
+        m::calc(c, 2);
+    }
+};"#,
+    "calc",
+    2,
+    SymbolKind::Function,
+    3
+);
+
+references!(
+    mod_function,
+    r#"mod math {
+    fn calc(a: num, b: num) {
+        a + b;
+    };
+    fn twice(a: num) {
+        calc(a, a);
+    };
+};
+component a() {
+    task b() {
+        let c = math::calc(1, 2);
+        math::twice(c);
+    }
+};"#,
+    "calc",
+    0,
+    SymbolKind::Function,
+    3
+);
+
+references!(
+    task,
+    r#"component a() {
+    task build() {
+        true;
+    }
+    task all() {
+        :a:build();
+        :a:build();
+    }
+};"#,
+    "build",
+    1,
+    SymbolKind::Task,
+    3
+);
+
+references!(
+    component,
+    r#"component comp() {
+    task build() {
+        true;
+    }
+    task all() {
+        :comp:build();
+    }
+};"#,
+    "comp",
+    1,
+    SymbolKind::Component,
+    2
+);
+
+#[test]
+fn references_across_files() {
+    use std::env::current_dir;
+
+    let path = current_dir().unwrap().join("../tests/mods/main.sibs");
+    let content = std::fs::read_to_string(&path).unwrap();
+    let mut driver = Driver::new(&path, true);
+    driver.read().unwrap_or_else(|err| panic!("{err}"));
+    let pos = content.find("math::inner::sum").unwrap() + "math::inner::".len();
+    let symbol = driver
+        .symbol(pos, None)
+        .unwrap_or_else(|| panic!("Fail to find symbol at {pos}"));
+    assert_eq!(symbol.kind, SymbolKind::Function);
+    assert_eq!(symbol.name, "sum");
+    let declaration = symbol.declaration.as_ref().expect("Declaration is found");
+    let filename = driver
+        .get_src_filename(&declaration.src)
+        .expect("Declaration source is a file");
+    assert!(filename.ends_with("mods/math.sibs"));
+    let src = std::fs::read_to_string(filename).unwrap();
+    assert_eq!(
+        &src[declaration.position.from.abs..declaration.position.to.abs],
+        "sum"
+    );
+    assert_eq!(symbol.usages.len(), 1);
+}
+
+#[test]
+fn references_buffered() {
+    use std::env::current_dir;
+
+    let path = current_dir().unwrap().join("../tests/mods/main.sibs");
+    let content = std::fs::read_to_string(&path)
+        .unwrap()
+        .replace("let b = :component_b:task_b(5);", "let b = :component_b:task_b(5);\n        let c = b;");
+    let mut driver = Driver::buffered(&path, &content, true);
+    driver.read().unwrap_or_else(|err| panic!("{err}"));
+    let pos = content.find("task_b").unwrap();
+    let symbol = driver
+        .symbol(pos, None)
+        .unwrap_or_else(|| panic!("Fail to find symbol at {pos}"));
+    assert_eq!(symbol.kind, SymbolKind::Task);
+    let declaration = symbol.declaration.as_ref().expect("Declaration is found");
+    assert!(driver
+        .get_src_filename(&declaration.src)
+        .expect("Declaration source is a file")
+        .ends_with("components/b.sibs"));
+    let pos = content.find("let c = b").unwrap() + "let c = ".len();
+    let symbol = driver
+        .symbol(pos, None)
+        .unwrap_or_else(|| panic!("Fail to find symbol at {pos}"));
+    assert_eq!(symbol.kind, SymbolKind::Variable);
+    assert_eq!(symbol.locations(true).len(), 3);
+}
+
+#[test]
+fn references_valid_name() {
+    assert!(is_valid_name("value"));
+    assert!(is_valid_name("value_a"));
+    assert!(!is_valid_name(""));
+    assert!(!is_valid_name("let"));
+    assert!(!is_valid_name("1value"));
+    assert!(!is_valid_name("value a"));
+    assert!(!is_valid_name("value-a"));
+}
@@ -0,0 +1,91 @@
+use crate::*;
+use tokio_util::sync::CancellationToken;
+
+#[test]
+fn cached_attached_files() {
+    use std::env::current_dir;
+
+    let path = current_dir().unwrap().join("../tests/mods/main.sibs");
+    let math = current_dir().unwrap().join("../tests/mods/mods/math.sibs");
+    let cache = ParseCache::shared();
+    let read = |cache: &SharedParseCache| {
+        let mut driver = Driver::new(&path, true);
+        driver.set_cache(cache.clone());
+        driver.read().unwrap();
+        assert_eq!(driver.errors.len(), 0);
+        let src = driver.get_src_by_filename(&math).unwrap();
+        let names = driver
+            .outline(Some(src))
+            .iter()
+            .map(|item| item.name.to_owned())
+            .collect::<Vec<String>>();
+        (src, names)
+    };
+    let (src, names) = read(&cache);
+    assert_eq!(names, vec!["nested", "inner", "sum", "diff"]);
+    assert!(!cache.lock().unwrap().is_empty());
+    // Cached nodes are reused
+    let (cached, names) = read(&cache);
+    assert_eq!(src, cached);
+    assert_eq!(names, vec!["nested", "inner", "sum", "diff"]);
+    // Changes of attached file invalidate cache
+    let content = std::fs::read_to_string(&math)
+        .unwrap()
+        .replace("fn diff(", "fn sub(");
+    cache.lock().unwrap().set_content(&math, content);
+    let (changed, names) = read(&cache);
+    assert_ne!(src, changed);
+    assert_eq!(names, vec!["nested", "inner", "sum", "sub"]);
+    cache.lock().unwrap().remove_content(&math);
+    let (restored, names) = read(&cache);
+    assert_eq!(names, vec!["nested", "inner", "sum", "diff"]);
+    // Changes on disk are reported explicitly
+    cache.lock().unwrap().invalidate(&math);
+    let (reread, names) = read(&cache);
+    assert_ne!(restored, reread);
+    assert_eq!(names, vec!["nested", "inner", "sum", "diff"]);
+    assert_eq!(read(&cache).0, reread);
+}
+
+#[test]
+fn unreported_changes() {
+    let dir = std::env::temp_dir().join(format!("sibs_cache_{}", Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("main.sibs");
+    let math = dir.join("math.sibs");
+    std::fs::write(&path, "mod from \"./math.sibs\";").unwrap();
+    std::fs::write(&math, "fn sum(a: num, b: num) { a + b; };").unwrap();
+    let cache = ParseCache::shared();
+    let read = |cache: &SharedParseCache| {
+        let mut driver = Driver::new(&path, true);
+        driver.set_cache(cache.clone());
+        driver.read().unwrap();
+        assert_eq!(driver.errors.len(), 0);
+        let src = driver.get_src_by_filename(&math).unwrap();
+        let names = driver
+            .outline(Some(src))
+            .iter()
+            .map(|item| item.name.to_owned())
+            .collect::<Vec<String>>();
+        (src, names)
+    };
+    let (src, names) = read(&cache);
+    assert_eq!(names, vec!["sum"]);
+    assert_eq!(read(&cache).0, src);
+    // Changes on disk aren't reported, but content hash doesn't match
+    std::fs::write(&math, "fn diff(a: num, b: num) { a - b; };").unwrap();
+    let (changed, names) = read(&cache);
+    assert_ne!(src, changed);
+    assert_eq!(names, vec!["diff"]);
+    assert_eq!(read(&cache).0, changed);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn cancelled_reading() {
+    let mut driver = Driver::unbound("component a() { task b() { true; } };", true);
+    let cancel = CancellationToken::new();
+    driver.set_cancellation(cancel.clone());
+    cancel.cancel();
+    assert!(matches!(driver.read(), Err(E::Cancelled)));
+}
@@ -0,0 +1,73 @@
+use crate::*;
+
+fn apply_fix(content: &str, fix: &Fix) -> String {
+    let mut content = content.to_owned();
+    let mut edits = fix.edits.iter().collect::<Vec<&FixEdit>>();
+    edits.sort_by_key(|edit| std::cmp::Reverse(edit.position.from.abs));
+    for edit in edits {
+        content.replace_range(edit.position.from.abs..edit.position.to.abs, &edit.text);
+    }
+    content
+}
+
+#[test]
+fn fixes() {
+    use std::env::current_dir;
+
+    let fixes = |driver: &Driver, content: &str, needle: &str| {
+        let pos = content.find(needle).expect("Needle is found");
+        driver
+            .fixes(pos, pos + needle.len(), None)
+            .iter()
+            .map(|fix| (fix.kind.clone(), apply_fix(content, fix)))
+            .collect::<Vec<(FixKind, String)>>()
+    };
+    let read = |content: &str| {
+        let mut driver = Driver::unbound(content, true);
+        driver.read().unwrap();
+        driver
+    };
+    // Similar names of variables
+    let content = "component a() { task b() { let value = 5; let c = valeu + 1; } };";
+    let found = fixes(&read(content), content, "valeu");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].0, FixKind::Rename);
+    assert!(found[0].1.contains("let c = value + 1;"));
+    // Qualified names of functions
+    let content = r#"mod math { fn sum(a: num, b: num) { a + b; }; };
+component a() { task b() { let c = summ(1, 2); } };"#;
+    let found = fixes(&read(content), content, "summ");
+    assert_eq!(found.len(), 1);
+    assert!(found[0].1.contains("let c = math::sum(1, 2);"));
+    // Missed arguments
+    let content = r#"mod math { fn sum(a: num, b: num) { a + b; }; };
+component a() { task b() { let c = math::sum(1); } };"#;
+    let found = fixes(&read(content), content, "math::sum(1)");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].0, FixKind::Arguments);
+    assert!(found[0].1.contains("let c = math::sum(1, 0);"));
+    // Type annotation
+    let content =
+        "component a() { task b() { let a; let b = 1; if b == 1 { a = 1; }; let c = a; } };";
+    let found = fixes(&read(content), content, "= a;");
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].0, FixKind::Annotation);
+    assert!(found[0].1.contains("let a: num;"), "{found:?}");
+    // Attaching of module is suggested only if file of module exists
+    let path = current_dir()
+        .unwrap()
+        .join("../tests/mods/mods/virtual.sibs");
+    let content =
+        "component a() { task b() { let c = nested::calc(1, 2); let d = unknown::calc(1, 2); } };";
+    let mut driver = Driver::buffered(&path, content, true);
+    driver.read().unwrap();
+    let found = fixes(&driver, content, "nested::calc");
+    assert!(found.iter().any(|(kind, fixed)| {
+        kind == &FixKind::Import(String::from("nested"))
+            && fixed.starts_with("mod from \"./nested.sibs\";\n")
+    }));
+    let found = fixes(&driver, content, "unknown::calc");
+    assert!(!found
+        .iter()
+        .any(|(kind, _)| matches!(kind, FixKind::Import(..))));
+}
@@ -0,0 +1,26 @@
+use crate::*;
+
+declare_embedded_fn!(
+    vec![(None, None, Ty::Determined(DeterminedTy::Str))],
+    DeterminedTy::Void
+);
+
+#[docs]
+/// Takes the named lock; waits while the lock is held by other jobs. By default a lock can
+/// be held by one job only; use `locks::capacity` to allow more holders. Fails if waiting
+/// would never end, for example if the lock is held by the caller or by a task which waits
+/// for the caller. All locks taken by a task are released when the task is done.
+#[boxed]
+pub fn executor(
+    args: Vec<FnArgValue>,
+    rt: Runtime,
+    cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    let key = super::key_arg(&args).map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    rt.locks()
+        .acquire(key, &cx.job)
+        .await
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    Ok(RtValue::Void)
+}
@@ -0,0 +1,45 @@
+use crate::*;
+
+declare_embedded_fn!(
+    vec![
+        (None, None, Ty::Determined(DeterminedTy::Str)),
+        (None, None, Ty::Determined(DeterminedTy::Num))
+    ],
+    DeterminedTy::Void
+);
+
+#[docs]
+/// Sets how many jobs can hold the named lock at the same time; with capacity greater
+/// than 1 the lock works as a counting semaphore.
+#[boxed]
+pub fn executor(
+    args: Vec<FnArgValue>,
+    rt: Runtime,
+    _cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    let key = super::key_arg(&args).map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    let Some(arg) = args.get(1) else {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentsNumber(2, args.len()),
+            (&caller).into(),
+        ));
+    };
+    let RtValue::Num(capacity) = arg.value else {
+        return Err(LinkedErr::by_link(
+            E::InvalidValueType(RtValueId::Num.to_string()),
+            (&arg.link).into(),
+        ));
+    };
+    if capacity < 1.0 {
+        return Err(LinkedErr::by_link(
+            E::InvalidLockCapacity(key),
+            (&arg.link).into(),
+        ));
+    }
+    rt.locks()
+        .capacity(key, capacity as usize)
+        .await
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    Ok(RtValue::Void)
+}
@@ -0,0 +1,23 @@
+mod acquire;
+mod capacity;
+mod release;
+
+use crate::*;
+
+pub fn register(efns: &mut EFns) -> Result<(), E> {
+    import_embedded_fn!(efns, acquire);
+    import_embedded_fn!(efns, release);
+    import_embedded_fn!(efns, capacity);
+    Ok(())
+}
+
+/// Reads the name of lock from the first argument of function
+pub(crate) fn key_arg(args: &[FnArgValue]) -> Result<String, E> {
+    let Some(arg) = args.first() else {
+        return Err(E::InvalidFnArgumentsNumber(1, 0));
+    };
+    arg.value
+        .clone()
+        .as_string()
+        .ok_or(E::InvalidFnArgumentType)
+}
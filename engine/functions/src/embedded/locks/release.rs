@@ -0,0 +1,24 @@
+use crate::*;
+
+declare_embedded_fn!(
+    vec![(None, None, Ty::Determined(DeterminedTy::Str))],
+    DeterminedTy::Void
+);
+
+#[docs]
+/// Releases the named lock taken with `locks::acquire`. Fails if the lock isn't held by
+/// the caller.
+#[boxed]
+pub fn executor(
+    args: Vec<FnArgValue>,
+    rt: Runtime,
+    cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    let key = super::key_arg(&args).map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    rt.locks()
+        .release(key, &cx.job)
+        .await
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    Ok(RtValue::Void)
+}
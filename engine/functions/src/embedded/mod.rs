@@ -1,7 +1,9 @@
 mod console;
 mod debugging;
 mod hash;
+mod locks;
 mod math;
+mod output;
 mod signals;
 mod status;
 
@@ -14,5 +16,7 @@ pub fn register(efns: &mut EFns) -> Result<(), E> {
     signals::register(efns)?;
     debugging::register(efns)?;
     hash::register(efns)?;
+    output::register(efns)?;
+    locks::register(efns)?;
     Ok(())
 }
@@ -0,0 +1,29 @@
+mod component_mode;
+mod mode;
+mod next;
+
+use crate::*;
+use runtime::spawner::OutputMode;
+
+pub fn register(efns: &mut EFns) -> Result<(), E> {
+    import_embedded_fn!(efns, mode);
+    import_embedded_fn!(efns, next);
+    import_embedded_fn!(efns, component_mode);
+    Ok(())
+}
+
+/// Reads mode of output from the single argument of function. Relative path of file of
+/// `tee` mode is resolved from the current working folder of context, where commands
+/// are spawned.
+pub(crate) async fn mode_arg(args: &[FnArgValue], cx: &Context) -> Result<OutputMode, E> {
+    let [arg] = args else {
+        return Err(E::InvalidFnArgumentsNumber(1, args.len()));
+    };
+    let Some(value) = arg.value.clone().as_string() else {
+        return Err(E::InvalidFnArgumentType);
+    };
+    match OutputMode::try_from(value.as_str())? {
+        OutputMode::Tee(path) => Ok(OutputMode::Tee(cx.cwd().get().await?.join(path))),
+        mode => Ok(mode),
+    }
+}
@@ -0,0 +1,39 @@
+use crate::*;
+
+declare_embedded_fn!(
+    vec![(None, None, Ty::Determined(DeterminedTy::Str))],
+    DeterminedTy::Void
+);
+
+#[docs]
+/// Sets mode of output for all following commands of the task. Tasks called by the task
+/// inherit the mode unless their component defines its own mode.
+///
+/// Supported modes:
+/// * `stream` - output is posted into the progress and the journal and kept in memory
+///   (default);
+/// * `inherit` - command writes directly into the terminal; output isn't kept;
+/// * `interactive` - command is attached to the terminal (including stdin), which is
+///   required by interactive tools; output isn't kept;
+/// * `capture` - output is kept in memory only;
+/// * `silent` - output is discarded;
+/// * `tee:<path>` - same as `stream`, but output is also written into the file;
+/// * `tail:<lines>` - same as `stream`, but only the last lines of output are kept.
+///
+/// Progress isn't rendered while a command in `inherit` or `interactive` mode is running.
+#[boxed]
+pub fn executor(
+    args: Vec<FnArgValue>,
+    _rt: Runtime,
+    cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    let mode = super::mode_arg(&args, &cx)
+        .await
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    cx.output()
+        .set(mode)
+        .await
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    Ok(RtValue::Void)
+}
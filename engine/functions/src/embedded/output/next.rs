@@ -0,0 +1,26 @@
+use crate::*;
+
+declare_embedded_fn!(
+    vec![(None, None, Ty::Determined(DeterminedTy::Str))],
+    DeterminedTy::Void
+);
+
+#[docs]
+/// Sets mode of output for the next command only. See `output::mode` for supported
+/// modes.
+#[boxed]
+pub fn executor(
+    args: Vec<FnArgValue>,
+    _rt: Runtime,
+    cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    let mode = super::mode_arg(&args, &cx)
+        .await
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    cx.output()
+        .next(mode)
+        .await
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    Ok(RtValue::Void)
+}
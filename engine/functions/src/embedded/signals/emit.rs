@@ -6,7 +6,8 @@ declare_embedded_fn!(
 );
 
 #[docs]
-/// Documentation placeholder
+/// Emits the signal without payload; all waiters of signal get `void`. Signal can be
+/// emitted once; use `signals::reset` to emit it again.
 #[boxed]
 pub fn executor(
     mut args: Vec<FnArgValue>,
@@ -28,7 +29,7 @@ pub fn executor(
         ));
     };
     rt.signals()
-        .emit_signal(key)
+        .emit_signal(key, RtValue::Void)
         .await
         .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
     Ok(RtValue::Void)
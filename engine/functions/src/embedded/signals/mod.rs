@@ -1,5 +1,8 @@
 mod emit;
+mod reset;
+mod send;
 mod wait;
+mod wait_timeout;
 mod waiters;
 
 use crate::*;
@@ -8,5 +11,8 @@ pub fn register(efns: &mut EFns) -> Result<(), E> {
     import_embedded_fn!(efns, wait);
     import_embedded_fn!(efns, emit);
     import_embedded_fn!(efns, waiters);
+    import_embedded_fn!(efns, send);
+    import_embedded_fn!(efns, wait_timeout);
+    import_embedded_fn!(efns, reset);
     Ok(())
 }
@@ -0,0 +1,35 @@
+use crate::*;
+
+declare_embedded_fn!(
+    vec![(None, None, Ty::Determined(DeterminedTy::Str))],
+    DeterminedTy::Void
+);
+
+#[docs]
+/// Resets the emitted signal, so it can be emitted again. Waiters, which haven't got the
+/// payload yet, keep waiting for the next emit.
+#[boxed]
+pub fn executor(
+    mut args: Vec<FnArgValue>,
+    rt: Runtime,
+    _cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    if args.len() != 1 {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentsNumber(1, args.len()),
+            (&caller).into(),
+        ));
+    }
+    let Some(key) = args.remove(0).value.as_string() else {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentType,
+            (&caller).into(),
+        ));
+    };
+    rt.signals()
+        .reset_signal(key)
+        .await
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    Ok(RtValue::Void)
+}
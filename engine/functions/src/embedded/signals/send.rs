@@ -0,0 +1,39 @@
+use crate::*;
+
+declare_embedded_fn!(
+    vec![
+        (None, None, Ty::Determined(DeterminedTy::Str)),
+        (None, None, Ty::Determined(DeterminedTy::Any))
+    ],
+    DeterminedTy::Void
+);
+
+#[docs]
+/// Emits the signal with payload; all waiters of signal get the same value. Signal can
+/// be emitted once; use `signals::reset` to emit it again.
+#[boxed]
+pub fn executor(
+    mut args: Vec<FnArgValue>,
+    rt: Runtime,
+    _cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    if args.len() != 2 {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentsNumber(2, args.len()),
+            (&caller).into(),
+        ));
+    }
+    let payload = args.remove(1);
+    let Some(key) = args.remove(0).value.as_string() else {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentType,
+            (&caller).into(),
+        ));
+    };
+    rt.signals()
+        .emit_signal(key, payload.value)
+        .await
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    Ok(RtValue::Void)
+}
@@ -2,11 +2,12 @@ use crate::*;
 
 declare_embedded_fn!(
     vec![(None, None, Ty::Determined(DeterminedTy::Str))],
-    DeterminedTy::Void
+    DeterminedTy::Any
 );
 
 #[docs]
-/// Documentation placeholder
+/// Waits for the signal and returns its payload. If the signal has been emitted already,
+/// returns immediately.
 #[boxed]
 pub fn executor(
     mut args: Vec<FnArgValue>,
@@ -27,15 +28,8 @@ pub fn executor(
             (&caller).into(),
         ));
     };
-    if let Some(tk) = rt
-        .signals()
+    rt.signals()
         .wait_signal(key)
         .await
-        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?
-    {
-        if !tk.is_cancelled() {
-            tk.cancelled().await;
-        }
-    }
-    Ok(RtValue::Void)
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))
 }
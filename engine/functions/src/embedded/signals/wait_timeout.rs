@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use crate::*;
+
+declare_embedded_fn!(
+    vec![
+        (None, None, Ty::Determined(DeterminedTy::Str)),
+        (None, None, Ty::Determined(DeterminedTy::Num))
+    ],
+    DeterminedTy::Any
+);
+
+#[docs]
+/// Waits for the signal during the given number of milliseconds and returns its payload.
+/// If the signal isn't emitted in time, returns an error value.
+#[boxed]
+pub fn executor(
+    args: Vec<FnArgValue>,
+    rt: Runtime,
+    cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    let [key, timeout] = args.as_slice() else {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentsNumber(2, args.len()),
+            (&caller).into(),
+        ));
+    };
+    let Some(key) = key.value.clone().as_string() else {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentType,
+            (&caller).into(),
+        ));
+    };
+    let RtValue::Num(ms) = timeout.value else {
+        return Err(LinkedErr::by_link(
+            E::InvalidValueType(RtValueId::Num.to_string()),
+            (&timeout.link).into(),
+        ));
+    };
+    let payload = rt
+        .signals()
+        .wait_signal_timeout(&key, Duration::from_millis(ms.max(0.0) as u64))
+        .await
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    Ok(payload.unwrap_or_else(|| {
+        cx.job
+            .journal
+            .warn(format!("Signal \"{key}\" hasn't been emitted in {ms}ms"));
+        RtValue::Error
+    }))
+}
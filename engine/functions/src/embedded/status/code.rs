@@ -0,0 +1,31 @@
+use crate::*;
+
+declare_embedded_fn!(
+    vec![(None, None, Ty::Determined(DeterminedTy::ExecuteResult))],
+    DeterminedTy::Num
+);
+
+#[docs]
+/// Returns exit code of command. If code is unknown (command wasn't run, was cancelled
+/// or was terminated by signal), returns -1.
+#[boxed]
+pub fn executor(
+    args: Vec<FnArgValue>,
+    _rt: Runtime,
+    _cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    let Some(arg) = args.first() else {
+        return Err(LinkedErr::by_link(
+            E::MissedFnArgument(RtValueId::ExecuteResult.to_string()),
+            (&caller).into(),
+        ));
+    };
+    let RtValue::ExecuteResult(status) = &arg.value else {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentType,
+            (&arg.link).into(),
+        ));
+    };
+    Ok(RtValue::Num(status.code().map(f64::from).unwrap_or(-1.0)))
+}
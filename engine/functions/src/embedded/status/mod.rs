@@ -1,7 +1,11 @@
+mod code;
 mod executed;
 mod is_cancelled;
 mod is_failed;
 mod is_success;
+mod lines;
+mod stderr;
+mod stdout;
 mod success;
 
 use crate::*;
@@ -12,5 +16,9 @@ pub fn register(efns: &mut EFns) -> Result<(), E> {
     import_embedded_fn!(efns, is_cancelled);
     import_embedded_fn!(efns, success);
     import_embedded_fn!(efns, executed);
+    import_embedded_fn!(efns, stdout);
+    import_embedded_fn!(efns, stderr);
+    import_embedded_fn!(efns, lines);
+    import_embedded_fn!(efns, code);
     Ok(())
 }
@@ -0,0 +1,41 @@
+use crate::*;
+
+declare_embedded_fn!(
+    vec![(None, None, Ty::Determined(DeterminedTy::ExecuteResult))],
+    DeterminedTy::Vec(Some(Box::new(DeterminedTy::Str)))
+);
+
+#[docs]
+/// Returns lines written by command into stdout. Command, which wasn't run or was
+/// cancelled, doesn't have output; in this case empty list is returned.
+#[boxed]
+pub fn executor(
+    args: Vec<FnArgValue>,
+    _rt: Runtime,
+    _cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    let Some(arg) = args.first() else {
+        return Err(LinkedErr::by_link(
+            E::MissedFnArgument(RtValueId::ExecuteResult.to_string()),
+            (&caller).into(),
+        ));
+    };
+    let RtValue::ExecuteResult(status) = &arg.value else {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentType,
+            (&arg.link).into(),
+        ));
+    };
+    Ok(RtValue::Vec(
+        status
+            .output()
+            .map(|output| {
+                output
+                    .stdout()
+                    .map(|ln| RtValue::Str(ln.to_owned()))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    ))
+}
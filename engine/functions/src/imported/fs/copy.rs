@@ -0,0 +1,78 @@
+use crate::*;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Returns path given as argument. Like other functions of `fs`, relative path is
+/// resolved from the current working folder of process.
+pub(super) fn path_arg(arg: &FnArgValue) -> Result<PathBuf, E> {
+    match &arg.value {
+        RtValue::PathBuf(path) => Ok(path.clone()),
+        RtValue::Str(path) => Ok(PathBuf::from(path)),
+        _ => Err(E::DismatchValueType(
+            RtValueId::PathBuf.to_string(),
+            arg.value.id().to_string(),
+        )),
+    }
+}
+
+/// Returns all entries of folder (recursively) as paths relative to the folder and flags
+/// of folders. Entries are sorted, so each folder goes before its content.
+pub(super) fn entries(root: &Path) -> Result<Vec<(PathBuf, bool)>, E> {
+    if !root.is_dir() {
+        return Err(E::NotDirectory(root.to_string_lossy().to_string()));
+    }
+    let mut entries = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let is_dir = path.is_dir();
+            if is_dir {
+                pending.push(path.clone());
+            }
+            if let Ok(rel) = path.strip_prefix(root) {
+                entries.push((rel.to_path_buf(), is_dir));
+            }
+        }
+    }
+    entries.sort();
+    Ok(entries)
+}
+
+/// Copies entries of folder `from` into folder `to`. Files are copied only if `filter`
+/// returns `true`; each copied and skipped file is recorded into the journal of job.
+/// Progress of job is updated after each file. Returns the number of copied files.
+pub(super) fn copy_entries<F: Fn(&Path, &Path) -> Result<bool, E>>(
+    from: &Path,
+    to: &Path,
+    entries: &[(PathBuf, bool)],
+    job: &Job,
+    filter: F,
+) -> Result<u64, E> {
+    let total = entries.iter().filter(|(_, is_dir)| !is_dir).count() as u64;
+    let mut done = 0;
+    let mut copied = 0;
+    fs::create_dir_all(to)?;
+    job.progress.progress(done, total);
+    for (rel, is_dir) in entries.iter() {
+        let (src, dest) = (from.join(rel), to.join(rel));
+        if *is_dir {
+            fs::create_dir_all(&dest)?;
+            continue;
+        }
+        if filter(&src, &dest)? {
+            fs::copy(&src, &dest)?;
+            job.journal
+                .info(format!("{}: copied", rel.to_string_lossy()));
+            copied += 1;
+        } else {
+            job.journal
+                .info(format!("{}: skipped", rel.to_string_lossy()));
+        }
+        done += 1;
+        job.progress.progress(done, total);
+    }
+    Ok(copied)
+}
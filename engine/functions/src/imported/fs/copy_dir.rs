@@ -0,0 +1,47 @@
+use crate::*;
+
+declare_embedded_fn!(
+    vec![
+        (
+            Some(String::from("from")),
+            None,
+            Ty::OneOf(vec![DeterminedTy::PathBuf, DeterminedTy::Str])
+        ),
+        (
+            Some(String::from("to")),
+            None,
+            Ty::OneOf(vec![DeterminedTy::PathBuf, DeterminedTy::Str])
+        ),
+    ],
+    DeterminedTy::Num
+);
+
+#[docs]
+/// Recursively copies the content of folder `from` into folder `to`. Missed folders are
+/// created; existing files are overwritten. Each copied file is recorded into the journal
+/// and the progress of task is updated after each file.
+///
+/// Returns the number of copied files.
+#[boxed]
+pub fn executor(
+    args: Vec<FnArgValue>,
+    _rt: Runtime,
+    cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    if args.len() != 2 {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentsNumber(2, args.len()),
+            (&caller).into(),
+        ));
+    }
+    let from = super::copy::path_arg(&args[0])
+        .map_err(|err| LinkedErr::by_link(err, (&args[0].link).into()))?;
+    let to = super::copy::path_arg(&args[1])
+        .map_err(|err| LinkedErr::by_link(err, (&args[1].link).into()))?;
+    let entries =
+        super::copy::entries(&from).map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    let copied = super::copy::copy_entries(&from, &to, &entries, &cx.job, |_, _| Ok(true))
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    Ok(RtValue::Num(copied as f64))
+}
@@ -1,3 +1,9 @@
+mod copy;
+mod copy_dir;
+mod move_to;
+mod sync;
+mod walk;
+
 use crate::*;
 use std::path::PathBuf;
 
@@ -393,5 +399,48 @@ pub fn register(efns: &mut EFns) -> Result<(), E> {
         });
         Ok(path)
     }
+    #[import(fs)]
+    /// Returns entries (files and directories) of the directory sorted by name. Nested
+    /// directories aren't read.
+    fn read_dir(path: PathBuf) -> Result<Vec<PathBuf>, E> {
+        walk::read_dir(path)
+    }
+    #[import(fs)]
+    /// Returns files of the directory and all nested directories sorted by path.
+    ///
+    /// Patterns are matched with paths relative to the directory: `**/*.rs`, `target`,
+    /// `docs/*.md`. A file is returned if it matches any of `include` patterns (any
+    /// file if there are no `include` patterns) and doesn't match any of `exclude`
+    /// patterns. Excluded directories aren't walked. `gitignore` enables rules of
+    /// `.gitignore` files.
+    ///
+    /// # Arguments Docs
+    ///
+    /// - path to the directory to walk
+    /// - patterns of files to return
+    /// - patterns of files and directories to skip
+    /// - consider `.gitignore` files
+    fn walk(
+        path: PathBuf,
+        include: Vec<String>,
+        exclude: Vec<String>,
+        gitignore: bool,
+    ) -> Result<Vec<PathBuf>, E> {
+        walk::walk(path, include, exclude, gitignore)
+    }
+    #[import(fs)]
+    /// Returns files matching the pattern (`src/**/*.rs`) sorted by path. `gitignore`
+    /// enables rules of `.gitignore` files.
+    ///
+    /// # Arguments Docs
+    ///
+    /// - glob pattern
+    /// - consider `.gitignore` files
+    fn glob(pattern: String, gitignore: bool) -> Result<Vec<PathBuf>, E> {
+        walk::glob(pattern, gitignore)
+    }
+    import_embedded_fn!(efns, copy_dir);
+    import_embedded_fn!(efns, move_to);
+    import_embedded_fn!(efns, sync);
     Ok(())
 }
@@ -0,0 +1,65 @@
+use crate::*;
+use std::fs;
+
+declare_embedded_fn!(
+    vec![
+        (
+            Some(String::from("from")),
+            None,
+            Ty::OneOf(vec![DeterminedTy::PathBuf, DeterminedTy::Str])
+        ),
+        (
+            Some(String::from("to")),
+            None,
+            Ty::OneOf(vec![DeterminedTy::PathBuf, DeterminedTy::Str])
+        ),
+    ],
+    DeterminedTy::Void
+);
+
+#[docs]
+/// Moves file or folder `from` to `to`. If it cannot be renamed (for example, `from` and
+/// `to` are on separate filesystems), the content is copied and the source is removed.
+/// In this case each copied file is recorded into the journal and the progress of task
+/// is updated after each file.
+#[boxed]
+pub fn executor(
+    args: Vec<FnArgValue>,
+    _rt: Runtime,
+    cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    if args.len() != 2 {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentsNumber(2, args.len()),
+            (&caller).into(),
+        ));
+    }
+    let from = super::copy::path_arg(&args[0])
+        .map_err(|err| LinkedErr::by_link(err, (&args[0].link).into()))?;
+    let to = super::copy::path_arg(&args[1])
+        .map_err(|err| LinkedErr::by_link(err, (&args[1].link).into()))?;
+    let Err(err) = fs::rename(&from, &to) else {
+        return Ok(RtValue::Void);
+    };
+    if !from.exists() {
+        return Err(LinkedErr::by_link(err.into(), (&caller).into()));
+    }
+    cx.job.journal.debug(format!(
+        "{}: cannot be renamed ({err}); will be copied and removed",
+        from.to_string_lossy()
+    ));
+    if from.is_dir() {
+        super::copy::entries(&from)
+            .and_then(|entries| {
+                super::copy::copy_entries(&from, &to, &entries, &cx.job, |_, _| Ok(true))
+            })
+            .and_then(|_| fs::remove_dir_all(&from).map_err(|err| err.into()))
+    } else {
+        fs::copy(&from, &to)
+            .and_then(|_| fs::remove_file(&from))
+            .map_err(|err| err.into())
+    }
+    .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    Ok(RtValue::Void)
+}
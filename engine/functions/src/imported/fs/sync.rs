@@ -0,0 +1,98 @@
+use crate::*;
+use std::{collections::HashSet, fs, path::PathBuf};
+
+declare_embedded_fn!(
+    vec![
+        (
+            Some(String::from("from")),
+            None,
+            Ty::OneOf(vec![DeterminedTy::PathBuf, DeterminedTy::Str])
+        ),
+        (
+            Some(String::from("to")),
+            None,
+            Ty::OneOf(vec![DeterminedTy::PathBuf, DeterminedTy::Str])
+        ),
+        (
+            Some(String::from("delete")),
+            None,
+            Ty::Determined(DeterminedTy::Bool)
+        ),
+    ],
+    DeterminedTy::Num
+);
+
+#[docs]
+/// Synchronizes folder `to` with folder `from`. A file is copied only if it's missed in
+/// `to`, has another size or it's modified later than the copy. If `delete` is `true`,
+/// files and folders of `to`, which don't exist in `from`, are removed. Each copied,
+/// skipped and removed file is recorded into the journal and the progress of task is
+/// updated after each file.
+///
+/// Returns the number of copied files.
+#[boxed]
+pub fn executor(
+    args: Vec<FnArgValue>,
+    _rt: Runtime,
+    cx: Context,
+    caller: SrcLink,
+) -> RtPinnedResult<'static, LinkedErr<E>> {
+    if args.len() != 3 {
+        return Err(LinkedErr::by_link(
+            E::InvalidFnArgumentsNumber(3, args.len()),
+            (&caller).into(),
+        ));
+    }
+    let from = super::copy::path_arg(&args[0])
+        .map_err(|err| LinkedErr::by_link(err, (&args[0].link).into()))?;
+    let to = super::copy::path_arg(&args[1])
+        .map_err(|err| LinkedErr::by_link(err, (&args[1].link).into()))?;
+    let RtValue::Bool(delete) = args[2].value else {
+        return Err(LinkedErr::by_link(
+            E::DismatchValueType(RtValueId::Bool.to_string(), args[2].value.id().to_string()),
+            (&args[2].link).into(),
+        ));
+    };
+    let entries =
+        super::copy::entries(&from).map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    let copied = super::copy::copy_entries(&from, &to, &entries, &cx.job, |src, dest| {
+        if !dest.is_file() {
+            return Ok(true);
+        }
+        let (src, dest) = (src.metadata()?, dest.metadata()?);
+        Ok(src.len() != dest.len() || src.modified()? > dest.modified()?)
+    })
+    .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?;
+    if !delete {
+        return Ok(RtValue::Num(copied as f64));
+    }
+    let sources = entries
+        .into_iter()
+        .map(|(rel, _)| rel)
+        .collect::<HashSet<PathBuf>>();
+    let extraneous = super::copy::entries(&to)
+        .map_err(|err| LinkedErr::by_link(err, (&caller).into()))?
+        .into_iter()
+        .filter(|(rel, _)| !sources.contains(rel))
+        .collect::<Vec<(PathBuf, bool)>>();
+    for (rel, is_dir) in extraneous.iter() {
+        // Content of removed folder is already removed with the folder
+        if extraneous
+            .iter()
+            .any(|(parent, is_dir)| *is_dir && rel != parent && rel.starts_with(parent))
+        {
+            continue;
+        }
+        let path = to.join(rel);
+        if *is_dir {
+            fs::remove_dir_all(&path)
+        } else {
+            fs::remove_file(&path)
+        }
+        .map_err(|err| LinkedErr::by_link(err.into(), (&caller).into()))?;
+        cx.job
+            .journal
+            .info(format!("{}: removed", rel.to_string_lossy()));
+    }
+    Ok(RtValue::Num(copied as f64))
+}
@@ -0,0 +1,135 @@
+use crate::*;
+use fshasher::{ContextFile, Entry, Filter, Options, Tolerance};
+use glob::{MatchOptions, Pattern};
+use std::path::{Path, PathBuf};
+
+/// Characters, which make a component of path to be a pattern
+const GLOB_CHARS: [char; 3] = ['*', '?', '['];
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+fn pattern(pattern: &str) -> Result<Pattern, E> {
+    Pattern::new(pattern).map_err(|err| E::InvalidGlobPattern(pattern.to_owned(), err.to_string()))
+}
+
+fn fshasher_err<Er: ToString>(err: Er) -> E {
+    E::Other(err.to_string())
+}
+
+/// Returns entries of directory (files and directories) sorted by name
+pub(super) fn read_dir(path: PathBuf) -> Result<Vec<PathBuf>, E> {
+    let mut entries = std::fs::read_dir(&path)?
+        .map(|entry| entry.map(|entry| path.join(entry.file_name())))
+        .collect::<Result<Vec<PathBuf>, _>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+/// Returns files of directory and all nested directories sorted by path. Patterns are
+/// matched with paths relative to the given directory. A file is returned if it matches
+/// any of `include` patterns (all files are matched if there are no `include` patterns)
+/// and doesn't match any of `exclude` patterns. Excluded directories aren't walked at
+/// all. If `gitignore` is true, rules of `.gitignore` files are applied as well.
+pub(super) fn walk(
+    path: PathBuf,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    gitignore: bool,
+) -> Result<Vec<PathBuf>, E> {
+    let root = std::path::absolute(&path)?;
+    let include = include
+        .iter()
+        .map(|p| pattern(p))
+        .collect::<Result<Vec<Pattern>, E>>()?;
+    let exclude = exclude
+        .iter()
+        .map(|p| pattern(p))
+        .collect::<Result<Vec<Pattern>, E>>()?;
+    let mut entry = Entry::from(&root).map_err(fshasher_err)?;
+    for pattern in exclude.iter() {
+        // Excluded directories are skipped by walker
+        entry = entry
+            .exclude(Filter::Common(
+                Path::new(&Pattern::escape(&root.to_string_lossy()))
+                    .join(pattern.as_str())
+                    .to_string_lossy(),
+            ))
+            .map_err(fshasher_err)?;
+    }
+    if gitignore {
+        entry = entry.context(ContextFile::Ignore(".gitignore"));
+    }
+    let mut walker = Options::new()
+        .entry(entry)
+        .map_err(fshasher_err)?
+        .tolerance(Tolerance::LogErrors)
+        .walker()
+        .map_err(fshasher_err)?;
+    walker.collect().map_err(fshasher_err)?;
+    let mut files = walker
+        .iter()
+        .filter(|(_, err)| err.is_none())
+        .filter_map(|(file, _)| file.strip_prefix(&root).ok().map(|p| p.to_path_buf()))
+        .filter(|file| {
+            (include.is_empty()
+                || include
+                    .iter()
+                    .any(|p| p.matches_path_with(file, MATCH_OPTIONS)))
+                && !exclude
+                    .iter()
+                    .any(|p| p.matches_path_with(file, MATCH_OPTIONS))
+        })
+        .map(|file| path.join(file))
+        .collect::<Vec<PathBuf>>();
+    files.sort();
+    Ok(files)
+}
+
+/// Returns files matching the pattern sorted by path. Leading components of pattern without
+/// wildcards define the directory to walk.
+pub(super) fn glob(pattern: String, gitignore: bool) -> Result<Vec<PathBuf>, E> {
+    let pattern = PathBuf::from(pattern);
+    let mut base = PathBuf::new();
+    let mut rest = PathBuf::new();
+    for component in pattern.components() {
+        if rest.as_os_str().is_empty()
+            && !component.as_os_str().to_string_lossy().contains(GLOB_CHARS)
+        {
+            base.push(component);
+        } else {
+            rest.push(component);
+        }
+    }
+    if rest.as_os_str().is_empty() {
+        return Ok(if base.is_file() {
+            vec![base]
+        } else {
+            Vec::new()
+        });
+    }
+    let dir = if base.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        base.clone()
+    };
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    Ok(walk(
+        dir,
+        vec![rest.to_string_lossy().to_string()],
+        Vec::new(),
+        gitignore,
+    )?
+    .into_iter()
+    .map(|file| {
+        file.strip_prefix(".")
+            .map(|file| base.join(file))
+            .unwrap_or(file)
+    })
+    .collect())
+}
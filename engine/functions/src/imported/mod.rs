@@ -1,10 +1,14 @@
 mod fs;
+mod path;
+mod re;
 mod strs;
 
 use crate::*;
 
 pub fn register(efns: &mut EFns) -> Result<(), E> {
     fs::register(efns)?;
+    path::register(efns)?;
+    re::register(efns)?;
     strs::register(efns)?;
     Ok(())
 }
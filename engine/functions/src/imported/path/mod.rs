@@ -0,0 +1,122 @@
+use crate::*;
+use std::path::{Component, PathBuf};
+
+/// Normalizes path lexically: removes `.` and resolves `..` without touching the disk.
+/// Leading `..` of relative path are kept; `..` of root is ignored.
+fn normalize_path(path: PathBuf) -> PathBuf {
+    let mut normalized: Vec<Component> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.last() {
+                Some(Component::Normal(..)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir | Component::Prefix(..)) => {}
+                Some(Component::ParentDir | Component::CurDir) | None => {
+                    normalized.push(component);
+                }
+            },
+            component => normalized.push(component),
+        }
+    }
+    normalized.iter().collect()
+}
+
+fn to_str(path: &std::path::Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+pub fn register(efns: &mut EFns) -> Result<(), E> {
+    #[import(path)]
+    /// Returns path without its final component. Returns empty path if path terminates
+    /// in a root or prefix or if it's the empty string.
+    fn parent(path: PathBuf) -> Result<PathBuf, E> {
+        Ok(path.parent().map(|p| p.to_path_buf()).unwrap_or_default())
+    }
+    #[import(path)]
+    /// Returns the final component of path (name of file or directory). Returns empty
+    /// string if path terminates in `..`.
+    fn file_name(path: PathBuf) -> Result<String, E> {
+        Ok(path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default())
+    }
+    #[import(path)]
+    /// Returns name of file without extension. Returns empty string if there is no
+    /// file name.
+    fn stem(path: PathBuf) -> Result<String, E> {
+        Ok(path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default())
+    }
+    #[import(path)]
+    /// Returns extension of file without leading dot. Returns empty string if there is
+    /// no extension.
+    fn extension(path: PathBuf) -> Result<String, E> {
+        Ok(path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_string())
+            .unwrap_or_default())
+    }
+    #[import(path)]
+    /// Returns path with the given extension. Empty extension removes the existing one.
+    fn with_extension(path: PathBuf, ext: String) -> Result<PathBuf, E> {
+        Ok(path.with_extension(ext))
+    }
+    #[import(path)]
+    /// Returns path without the given prefix. Fails if path doesn't start with prefix.
+    fn strip_prefix(path: PathBuf, prefix: PathBuf) -> Result<PathBuf, E> {
+        path.strip_prefix(&prefix)
+            .map(|p| p.to_path_buf())
+            .map_err(|_| E::NotPathPrefix(to_str(&prefix), to_str(&path)))
+    }
+    #[import(path)]
+    /// Returns path relative to the given base. Both paths are normalized before; `..`
+    /// is used to leave base. Fails if one of paths is absolute and another is relative.
+    fn relative_to(path: PathBuf, base: PathBuf) -> Result<PathBuf, E> {
+        let (path, base) = (normalize_path(path), normalize_path(base));
+        if path.has_root() != base.has_root() {
+            return Err(E::NoRelativePath(to_str(&path), to_str(&base)));
+        }
+        let path_cmps = path.components().collect::<Vec<Component>>();
+        let base_cmps = base.components().collect::<Vec<Component>>();
+        let common = path_cmps
+            .iter()
+            .zip(base_cmps.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        if base_cmps[common..]
+            .iter()
+            .any(|cmp| matches!(cmp, Component::ParentDir | Component::Prefix(..)))
+        {
+            return Err(E::NoRelativePath(to_str(&path), to_str(&base)));
+        }
+        let mut relative = PathBuf::new();
+        base_cmps[common..]
+            .iter()
+            .for_each(|_| relative.push(Component::ParentDir));
+        path_cmps[common..]
+            .iter()
+            .for_each(|cmp| relative.push(cmp));
+        Ok(relative)
+    }
+    #[import(path)]
+    /// Normalizes path without touching the disk: removes `.` and resolves `..`.
+    /// Symbolic links aren't resolved; use `fs::canonicalize` for it.
+    fn normalize(path: PathBuf) -> Result<PathBuf, E> {
+        Ok(normalize_path(path))
+    }
+    #[import(path)]
+    /// Returns components of path. Root of absolute path is included as the first
+    /// component.
+    fn components(path: PathBuf) -> Result<Vec<String>, E> {
+        Ok(path
+            .components()
+            .map(|cmp| cmp.as_os_str().to_string_lossy().to_string())
+            .collect())
+    }
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::*;
+use regex::Regex;
+
+fn compile(pattern: &str) -> Result<Regex, E> {
+    Regex::new(pattern).map_err(|err| E::InvalidRegex(pattern.to_owned(), err.to_string()))
+}
+
+pub fn register(efns: &mut EFns) -> Result<(), E> {
+    #[import(re)]
+    /// Returns true if string has a match of regular expression
+    fn is_match(target: String, pattern: String) -> Result<bool, E> {
+        Ok(compile(&pattern)?.is_match(&target))
+    }
+    #[import(re)]
+    /// Returns the first match of regular expression or empty string if there are no
+    /// matches
+    fn find(target: String, pattern: String) -> Result<String, E> {
+        Ok(compile(&pattern)?
+            .find(&target)
+            .map(|m| m.as_str().to_owned())
+            .unwrap_or_default())
+    }
+    #[import(re)]
+    /// Returns all matches of regular expression
+    fn find_all(target: String, pattern: String) -> Result<Vec<String>, E> {
+        Ok(compile(&pattern)?
+            .find_iter(&target)
+            .map(|m| m.as_str().to_owned())
+            .collect())
+    }
+    #[import(re)]
+    /// Returns groups of the first match of regular expression. The first item is the
+    /// whole match; groups, which didn't participate in the match, are empty strings.
+    /// Returns empty list if there are no matches.
+    fn captures(target: String, pattern: String) -> Result<Vec<String>, E> {
+        Ok(compile(&pattern)?
+            .captures(&target)
+            .map(|caps| {
+                caps.iter()
+                    .map(|m| m.map(|m| m.as_str().to_owned()).unwrap_or_default())
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+    #[import(re)]
+    /// Replaces all matches of regular expression. Replacement can refer to groups of
+    /// match: `$1`, `${name}`.
+    fn replace_all(target: String, pattern: String, replacement: String) -> Result<String, E> {
+        Ok(compile(&pattern)?
+            .replace_all(&target, replacement.as_str())
+            .into_owned())
+    }
+    Ok(())
+}
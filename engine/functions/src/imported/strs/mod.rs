@@ -1,5 +1,14 @@
 use crate::*;
 
+/// Returns padding, which is needed to reach the given width
+fn padding(target: &str, width: usize, fill: &str) -> String {
+    fill.chars()
+        .next()
+        .unwrap_or(' ')
+        .to_string()
+        .repeat(width.saturating_sub(target.chars().count()))
+}
+
 pub fn register(efns: &mut EFns) -> Result<(), E> {
     #[import(strs)]
     /// Documentation placeholder
@@ -80,6 +89,53 @@ pub fn register(efns: &mut EFns) -> Result<(), E> {
     fn is_trimmed_empty(target: String) -> Result<bool, E> {
         Ok(target.trim().is_empty())
     }
+    #[import(strs)]
+    /// Splits string by separator and returns parts
+    fn split(target: String, sep: String) -> Result<Vec<String>, E> {
+        Ok(target.split(sep.as_str()).map(|s| s.to_owned()).collect())
+    }
+    #[import(strs)]
+    /// Returns lines of string
+    fn lines(target: String) -> Result<Vec<String>, E> {
+        Ok(target.lines().map(|s| s.to_owned()).collect())
+    }
+    #[import(strs)]
+    /// Joins strings with separator
+    fn join_with(parts: Vec<String>, sep: String) -> Result<String, E> {
+        Ok(parts.join(&sep))
+    }
+    #[import(strs)]
+    /// Returns true if string starts with given prefix
+    fn starts_with(target: String, prefix: String) -> Result<bool, E> {
+        Ok(target.starts_with(prefix.as_str()))
+    }
+    #[import(strs)]
+    /// Returns true if string ends with given suffix
+    fn ends_with(target: String, suffix: String) -> Result<bool, E> {
+        Ok(target.ends_with(suffix.as_str()))
+    }
+    #[import(strs)]
+    /// Returns true if string contains given substring
+    fn contains(target: String, pat: String) -> Result<bool, E> {
+        Ok(target.contains(pat.as_str()))
+    }
+    #[import(strs)]
+    /// Pads string from the left with the first char of `fill` (or with spaces if `fill`
+    /// is empty) up to the given width in chars
+    fn pad_start(target: String, width: usize, fill: String) -> Result<String, E> {
+        Ok(format!("{}{target}", padding(&target, width, &fill)))
+    }
+    #[import(strs)]
+    /// Pads string from the right with the first char of `fill` (or with spaces if `fill`
+    /// is empty) up to the given width in chars
+    fn pad_end(target: String, width: usize, fill: String) -> Result<String, E> {
+        Ok(format!("{target}{}", padding(&target, width, &fill)))
+    }
+    #[import(strs)]
+    /// Formats number with the given number of digits after the decimal point
+    fn to_fixed(value: f64, digits: usize) -> Result<String, E> {
+        Ok(format!("{value:.digits$}"))
+    }
     Ok(())
 }
 
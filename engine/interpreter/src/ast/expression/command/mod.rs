@@ -34,6 +34,7 @@ impl Interpret for Command {
                 .await
                 .map_err(|err| LinkedErr::from(err, self))?,
             self.uuid,
+            rt,
             cx,
         )
         .await
@@ -88,3 +88,22 @@ test_fail!(
         `ls -fake`.success();
     }"#
 );
+
+test_limits!(
+    command_000,
+    "comp",
+    "task_a",
+    RtLimits {
+        timeout: Some(std::time::Duration::from_millis(200)),
+        ..Default::default()
+    },
+    E::TimeLimit(..),
+    r#"
+    component comp() {
+        task task_a() {
+            // Process is killed on timeout, even if nothing checks the time of execution
+            `sleep 10`;
+        }
+    };
+    "#
+);
@@ -32,9 +32,16 @@ impl Interpret for FunctionCall {
         } else {
             self.uuid
         };
-        rt.clone()
+        let rt_ref = rt.clone();
+        let args = if let Some(entity) = rt_ref
             .fns
-            .execute(&uuid, rt, cx, args, &self.link())
-            .await
+            .lookup_by_caller(&uuid)
+            .or_else(|| rt_ref.fns.lookup_closure(&uuid))
+        {
+            bind_call_args(&entity.args_sigs(), args, &rt, &cx, &self.link()).await?
+        } else {
+            args
+        };
+        rt_ref.fns.execute(&uuid, rt, cx, args, &self.link()).await
     }
 }
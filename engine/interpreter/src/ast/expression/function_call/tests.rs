@@ -20,6 +20,46 @@ test_task_results!(
     "#
 );
 
+test_task_results!(
+    function_call_001,
+    "my_component",
+    "task_a",
+    RtValue::Num(21.0),
+    r#"
+    mod aaa {
+        fn sum(a: num, b: num = 5, c: num = 10) {
+           a + b + c;
+        };
+    };
+    component my_component() {
+        task task_a() {
+            let a = aaa::sum(1);
+            let b = aaa::sum(1, 1, 1);
+            a + b + 2;
+        }
+    };
+    "#
+);
+
+test_task_results!(
+    function_call_002,
+    "my_component",
+    "task_a",
+    RtValue::Num(1015.0),
+    r#"
+    mod aaa {
+        fn calc(a: num, b: num = 5, c: num = 100) {
+           a * 10 + b + c;
+        };
+    };
+    component my_component() {
+        task task_a() {
+            aaa::calc(c = 1000, a = 1);
+        }
+    };
+    "#
+);
+
 test_value_expectation!(
     embedded_function_call_000,
     Block,
@@ -86,3 +126,51 @@ test_value_expectation!(
         a;
     }"#
 );
+
+test_task_results!(
+    recursion_000,
+    "my_component",
+    "task_a",
+    RtValue::Num(20.0),
+    r#"
+    mod aaa {
+        fn count(a: num) {
+            if a >= 20 {
+                return a;
+            }
+            aaa::count(a + 1);
+        };
+    };
+    component my_component() {
+        task task_a() {
+            aaa::count(0);
+        }
+    };
+    "#
+);
+
+test_limits!(
+    recursion_000,
+    "my_component",
+    "task_a",
+    RtLimits {
+        depth: Some(16),
+        ..Default::default()
+    },
+    E::DepthLimit(16),
+    r#"
+    mod aaa {
+        fn count(a: num) {
+            if a >= 20 {
+                return a;
+            }
+            aaa::count(a + 1);
+        };
+    };
+    component my_component() {
+        task task_a() {
+            aaa::count(0);
+        }
+    };
+    "#
+);
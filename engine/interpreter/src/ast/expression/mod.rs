@@ -13,6 +13,7 @@ mod compound_assignments;
 mod compound_assignments_op;
 mod function_call;
 mod logical_op;
+mod named_argument;
 mod range;
 mod task_call;
 mod variable;
@@ -37,6 +38,7 @@ impl Interpret for Expression {
             Expression::CompoundAssignmentsOp(n) => n.interpret(rt, cx),
             Expression::FunctionCall(n) => n.interpret(rt, cx),
             Expression::LogicalOp(n) => n.interpret(rt, cx),
+            Expression::NamedArgument(n) => n.interpret(rt, cx),
             Expression::Range(n) => n.interpret(rt, cx),
             Expression::TaskCall(n) => n.interpret(rt, cx),
             Expression::Variable(n) => n.interpret(rt, cx),
@@ -0,0 +1,11 @@
+use crate::*;
+
+impl Interpret for NamedArgument {
+    #[boxed]
+    fn interpret(&self, rt: Runtime, cx: Context) -> RtPinnedResult<LinkedErr<E>> {
+        Ok(RtValue::NamedArgumentValue(
+            self.ident.clone(),
+            Box::new(self.value.interpret(rt, cx).await?),
+        ))
+    }
+}
@@ -32,7 +32,13 @@ impl Interpret for TaskCall {
         } else {
             self.uuid
         };
-        rt.clone()
+        let rt_ref = rt.clone();
+        let args = if let Some(entity) = rt_ref.tasks.lookup_by_caller(&uuid) {
+            bind_call_args(&entity.args_sigs(), args, &rt, &cx, &self.link()).await?
+        } else {
+            args
+        };
+        rt_ref
             .tasks
             .execute(&uuid, rt, cx, args, &self.link())
             .await
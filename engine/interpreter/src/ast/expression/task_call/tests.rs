@@ -33,3 +33,17 @@ test_task_results!(
     };
     "#
 );
+
+test_task_results!(
+    task_call_002,
+    "comp",
+    "task_a",
+    RtValue::Num(10.0),
+    r#"
+    component comp() {
+        task task_a(a: num = 10) {
+            a;
+        }
+    };
+    "#
+);
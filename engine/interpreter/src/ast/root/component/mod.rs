@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod tests;
+
 use crate::*;
 
 impl Interpret for Component {
@@ -7,20 +10,28 @@ impl Interpret for Component {
             .get_rt_parameters()
             .await
             .map_err(|err| LinkedErr::from(err, self))?;
-        rt.clone()
-            .tasks
-            .execute_by_name(
-                &self.uuid,
-                rt_params.task,
-                rt,
-                cx,
-                rt_params
-                    .args
-                    .into_iter()
-                    .map(|arg| FnArgValue::new(RtValue::Str(arg), SrcLink::default()))
-                    .collect(),
-                &self.link(),
+        let rt_ref = rt.clone();
+        let Some(entity) = rt_ref.tasks.find_by_name(&self.uuid, &rt_params.task) else {
+            return Err(LinkedErr::from(
+                E::TaskNotFound(rt_params.task, self.uuid.to_string()),
+                self,
+            ));
+        };
+        // Arguments of command line don't have positions in the scenario; errors are
+        // reported with the signature of task
+        let args = match entity.cli_args(rt_params.args) {
+            Ok(args) => bind_call_args(&entity.args_sigs(), args, &rt, &cx, &self.link()).await,
+            Err(err) => Err(LinkedErr::from(err, self)),
+        }
+        .map_err(|err| {
+            LinkedErr::from(
+                E::InvalidTaskCliArguments(err.e.to_string(), entity.signature()),
+                self,
             )
+        })?;
+        rt_ref
+            .tasks
+            .execute_by_name(&self.uuid, &rt_params.task, rt, cx, args, &self.link())
             .await
     }
 }
@@ -0,0 +1,133 @@
+use crate::*;
+
+test_cli_args!(
+    cli_args_000,
+    "comp",
+    "deploy",
+    ["3", "true"],
+    Ok(RtValue::Num(3.0)),
+    r#"
+    component comp() {
+        task deploy(replicas: num, dry: bool) {
+            if dry {
+                replicas;
+            } else {
+                0;
+            }
+        }
+    };
+    "#
+);
+
+test_cli_args!(
+    cli_args_001,
+    "comp",
+    "deploy",
+    ["--dry", "--replicas=5"],
+    Ok(RtValue::Num(5.0)),
+    r#"
+    component comp() {
+        task deploy(replicas: num, dry: bool = false) {
+            if dry {
+                replicas;
+            } else {
+                0;
+            }
+        }
+    };
+    "#
+);
+
+test_cli_args!(
+    cli_args_002,
+    "comp",
+    "build",
+    ["--target=a", "--target=b", "--target=c"],
+    Ok(RtValue::Num(3.0)),
+    r#"
+    component comp() {
+        task build(target: Vec<str>) {
+            let count = 0;
+            for el in target {
+                count += 1;
+            }
+            count;
+        }
+    };
+    "#
+);
+
+test_cli_args!(
+    cli_args_003,
+    "comp",
+    "deploy",
+    ["--env=prod"],
+    Ok(RtValue::Str(String::from("prod"))),
+    r#"
+    component comp() {
+        task deploy(env: "dev" | "prod") {
+            env;
+        }
+    };
+    "#
+);
+
+test_cli_args!(
+    cli_args_004,
+    "comp",
+    "deploy",
+    ["five"],
+    Err(E::InvalidTaskCliArguments(..)),
+    r#"
+    component comp() {
+        task deploy(replicas: num) {
+            replicas;
+        }
+    };
+    "#
+);
+
+test_cli_args!(
+    cli_args_005,
+    "comp",
+    "deploy",
+    ["--env=test"],
+    Err(E::InvalidTaskCliArguments(..)),
+    r#"
+    component comp() {
+        task deploy(env: "dev" | "prod") {
+            env;
+        }
+    };
+    "#
+);
+
+test_cli_args!(
+    cli_args_006,
+    "comp",
+    "deploy",
+    ["--replicas"],
+    Err(E::InvalidTaskCliArguments(..)),
+    r#"
+    component comp() {
+        task deploy(replicas: num) {
+            replicas;
+        }
+    };
+    "#
+);
+
+test_cli_args!(
+    cli_args_007,
+    "comp",
+    "deploy",
+    [],
+    Err(E::InvalidTaskCliArguments(..)),
+    r#"
+    component comp() {
+        task deploy(replicas: num, dry: bool = false) {
+            replicas;
+        }
+    };
+    "#
+);
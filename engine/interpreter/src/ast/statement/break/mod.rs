@@ -5,9 +5,14 @@ use crate::*;
 
 impl Interpret for Break {
     #[boxed]
-    fn interpret(&self, _rt: Runtime, cx: Context) -> RtPinnedResult<LinkedErr<E>> {
+    fn interpret(&self, rt: Runtime, cx: Context) -> RtPinnedResult<LinkedErr<E>> {
+        let vl = if let Some(value) = self.value.as_ref() {
+            Some(value.interpret(rt, cx.clone()).await?)
+        } else {
+            None
+        };
         cx.loops()
-            .set_break()
+            .set_break(self.target.as_ref(), vl)
             .await
             .map_err(|err| LinkedErr::from(err, self))?;
         Ok(RtValue::Void)
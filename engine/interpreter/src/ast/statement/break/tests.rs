@@ -55,6 +55,64 @@ test_value_expectation!(
     }"#
 );
 
+test_value_expectation!(
+    r#break_003,
+    Block,
+    RtValue::Num(22.0),
+    r#"{
+        let n = 0;
+        let a = 0;
+        'outer: while (n < 20) {
+            n += 1;
+            for el in 0..10 {
+                a += 1;
+                if a == 20 {
+                    break 'outer;
+                };
+            };
+        };
+        n + a;
+    }"#
+);
+
+test_value_expectation!(
+    r#break_004,
+    Block,
+    RtValue::Num(16.0),
+    r#"{
+        let n = 1;
+        let pow = loop {
+            n = n * 2;
+            if n > 10 {
+                break n;
+            };
+        };
+        pow;
+    }"#
+);
+
+test_value_expectation!(
+    r#break_005,
+    Block,
+    RtValue::Str(String::from("found")),
+    r#"{
+        let n = 0;
+        // Labeled loop is the last statement; its value is the value of block
+        'search: loop {
+            n += 1;
+            for el in 0..10 {
+                let p = n * el;
+                if p == 12 {
+                    break 'search "found";
+                };
+            };
+            if n > 10 {
+                break "not found";
+            };
+        };
+    }"#
+);
+
 test_fail!(
     r#break_000,
     Block,
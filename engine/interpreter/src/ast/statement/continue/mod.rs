@@ -0,0 +1,15 @@
+#[cfg(test)]
+mod tests;
+
+use crate::*;
+
+impl Interpret for Continue {
+    #[boxed]
+    fn interpret(&self, _rt: Runtime, cx: Context) -> RtPinnedResult<LinkedErr<E>> {
+        cx.loops()
+            .set_continue(self.target.as_ref())
+            .await
+            .map_err(|err| LinkedErr::from(err, self))?;
+        Ok(RtValue::Void)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::*;
+
+test_value_expectation!(
+    r#continue_000,
+    Block,
+    RtValue::Num(50.0),
+    r#"{
+        let sum = 0;
+        for(el, n) in 0..10 {
+            if n == 5 {
+                continue;
+            };
+            sum = sum + n;
+        };
+        sum;
+    }"#
+);
+
+test_value_expectation!(
+    r#continue_001,
+    Block,
+    RtValue::Num(15.0),
+    r#"{
+        let n = 0;
+        let sum = 0;
+        while n < 10 {
+            n += 1;
+            n > 5 => continue;
+            sum += n;
+        };
+        sum;
+    }"#
+);
+
+test_value_expectation!(
+    r#continue_002,
+    Block,
+    RtValue::Num(3.0),
+    r#"{
+        let n = 0;
+        let count = 0;
+        loop {
+            n += 1;
+            if n > 6 {
+                break;
+            };
+            if n < 4 {
+                continue;
+            };
+            count += 1;
+        };
+        count;
+    }"#
+);
+
+test_value_expectation!(
+    r#continue_003,
+    Block,
+    RtValue::Num(12.0),
+    r#"{
+        let sum = 0;
+        for a in 0..2 {
+            for b in 0..2 {
+                b == 1 => continue;
+                sum += 1;
+            };
+            sum += 2;
+        };
+        sum;
+    }"#
+);
+
+test_value_expectation!(
+    r#continue_004,
+    Block,
+    RtValue::Num(4.0),
+    r#"{
+        let count = 0;
+        'outer: for n in 1..5 {
+            for el in 1..5 {
+                if el > n {
+                    continue 'outer;
+                };
+                if el == 2 {
+                    count += 1;
+                };
+            };
+        };
+        count;
+    }"#
+);
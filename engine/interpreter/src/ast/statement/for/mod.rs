@@ -48,12 +48,15 @@ impl Interpret for For {
         for (n, vl) in vls.into_iter().enumerate() {
             if cx
                 .loops()
-                .is_stopped()
+                .next()
                 .await
                 .map_err(|err| LinkedErr::by_link(err, (&self.slink()).into()))?
             {
                 break;
             }
+            rt.limits()
+                .iteration(n)
+                .map_err(|err| LinkedErr::by_link(err, (&self.slink()).into()))?;
             cx.values()
                 .insert(&el, vl)
                 .await
@@ -27,4 +27,25 @@ test_value_expectation!(
         };
         sum;
     }"#
-);
\ No newline at end of file
+);
+
+test_limits!(
+    for_000,
+    "comp",
+    "task_a",
+    RtLimits {
+        iterations: Some(10),
+        ..Default::default()
+    },
+    E::IterationsLimit(10),
+    r#"
+    component comp() {
+        task task_a() {
+            let sum = 0;
+            for n in 0..100 {
+                sum += n;
+            }
+        }
+    };
+    "#
+);
@@ -3,38 +3,79 @@ mod tests;
 
 use crate::*;
 use futures::stream::{FuturesUnordered, StreamExt};
-use std::collections::HashMap;
-use tokio::{spawn, task::JoinHandle};
+use std::{collections::HashMap, sync::Arc};
+use tokio::{spawn, sync::Semaphore, task::JoinHandle};
 use tokio_util::sync::CancellationToken;
 
 type LinkedJoinHandle = (SrcLink, JoinHandle<(Uuid, Result<RtValue, LinkedErr<E>>)>);
 
+/// Returns true if the result of branch is considered as failure
+fn failed(result: &Result<RtValue, LinkedErr<E>>) -> bool {
+    match result {
+        Ok(RtValue::ExecuteResult(result)) => result.is_failed(),
+        Ok(..) => false,
+        Err(..) => true,
+    }
+}
+
+/// Waits for all branches. Returns results of branches and the id of branch, which has
+/// caused cancelling of others (if it has happened).
 async fn wait(
     tasks: Vec<LinkedJoinHandle>,
     token: CancellationToken,
-) -> Result<HashMap<Uuid, Result<RtValue, LinkedErr<E>>>, LinkedErr<E>> {
+    policy: JoinPolicy,
+) -> Result<(HashMap<Uuid, Result<RtValue, LinkedErr<E>>>, Option<Uuid>), LinkedErr<E>> {
     let mut results: HashMap<Uuid, Result<RtValue, LinkedErr<E>>> = HashMap::new();
     let mut futures = FuturesUnordered::new();
+    let mut failures = 0;
+    let mut trigger = None;
     for (link, task) in tasks {
         futures.push(async move { task.await.map_err(|err| (link, err)) });
     }
     while let Some(result) = futures.next().await {
         match result {
-            Ok((uuid, Ok(result))) => {
-                results.insert(uuid, Ok(result));
-            }
-            Ok((uuid, Err(err))) => {
-                if !token.is_cancelled() {
-                    token.cancel();
+            Ok((uuid, result)) => {
+                if failed(&result) {
+                    failures += 1;
+                    if policy.cancels(failures) && !token.is_cancelled() {
+                        token.cancel();
+                        trigger = Some(uuid);
+                    }
                 }
-                results.insert(uuid, Err(err));
+                results.insert(uuid, result);
             }
             Err((link, err)) => {
                 return Err(LinkedErr::by_link(err.into(), (&link).into()));
             }
         }
     }
-    Ok(results)
+    Ok((results, trigger))
+}
+
+/// Reads options of join: a number is the limit of running branches, a string is the
+/// policy of failures.
+async fn options(
+    join: &Join,
+    rt: &Runtime,
+    cx: &Context,
+) -> Result<(Option<Arc<Semaphore>>, JoinPolicy), LinkedErr<E>> {
+    let mut slots = None;
+    let mut policy = JoinPolicy::default();
+    for option in join.options.iter() {
+        match option.interpret(rt.clone(), cx.clone()).await? {
+            RtValue::Str(vl) => {
+                policy = JoinPolicy::try_from(vl.as_str())
+                    .map_err(|err| LinkedErr::from(err, option))?;
+            }
+            RtValue::Num(n) if n >= 1.0 => {
+                slots = Some(Arc::new(Semaphore::new(n as usize)));
+            }
+            vl => {
+                return Err(LinkedErr::from(E::InvalidJoinLimit(vl.to_string()), option));
+            }
+        }
+    }
+    Ok((slots, policy))
 }
 
 impl Interpret for Join {
@@ -45,46 +86,94 @@ impl Interpret for Join {
             .iter()
             .map(|node| *node.uuid())
             .collect::<Vec<Uuid>>();
+        // Local limit of branches running at the same time; it works together with
+        // the global limit of job slots applied to each command
+        let (slots, policy) = options(self, &rt, &cx).await?;
+        // Branches are cancelled with own token, so cancelling of branches doesn't
+        // affect the caller; but cancelling of the caller cancels branches
+        let mut bcx = cx.clone();
+        bcx.job.cancel = cx.job.cancel.child_token();
         let tasks = self
             .commands
             .iter()
             .cloned()
             .map(|node| {
-                let (rt, cx) = (rt.clone(), cx.clone());
+                let (rt, cx, slots) = (rt.clone(), bcx.clone(), slots.clone());
                 (
                     node.link(),
-                    spawn(async move { (*node.uuid(), node.interpret(rt, cx).await) }),
+                    spawn(async move {
+                        let _slot = match slots {
+                            Some(slots) => match slots.acquire_owned().await {
+                                Ok(slot) => Some(slot),
+                                Err(err) => {
+                                    return (
+                                        *node.uuid(),
+                                        Err(LinkedErr::from(E::JobSlot(err.to_string()), &node)),
+                                    )
+                                }
+                            },
+                            None => None,
+                        };
+                        (*node.uuid(), node.interpret(rt, cx).await)
+                    }),
                 )
             })
             .collect::<Vec<LinkedJoinHandle>>();
-        match wait(tasks, cx.job.cancel).await {
-            Ok(mut results) => {
-                if order.len() != results.len() {
-                    return Err(LinkedErr::by_link(
-                        E::SomeNodesHadSameUuid,
-                        (&self.link()).into(),
-                    ));
+        let (mut results, trigger) = wait(tasks, bcx.job.cancel, policy).await?;
+        if order.len() != results.len() {
+            return Err(LinkedErr::by_link(
+                E::SomeNodesHadSameUuid,
+                (&self.link()).into(),
+            ));
+        }
+        // Errors of other branches might be caused by cancelling; so the error of branch,
+        // which has cancelled others, is reported
+        if let (JoinPolicy::FailFast, Some(uuid)) = (policy, trigger) {
+            if matches!(results.get(&uuid), Some(Err(..))) {
+                if let Some(Err(err)) = results.remove(&uuid) {
+                    return Err(err);
                 }
-                let mut output: Vec<RtValue> = Vec::new();
-                for uuid in order.into_iter() {
-                    match results.remove(&uuid) {
-                        Some(Ok(value)) => {
-                            output.push(value);
-                        }
-                        Some(Err(err)) => {
-                            return Err(err);
-                        }
-                        None => {
-                            return Err(LinkedErr::by_link(
-                                E::FailToFindJoinResult(uuid),
-                                (&self.link()).into(),
-                            ));
-                        }
+            }
+        }
+        let mut output: Vec<RtValue> = Vec::new();
+        let mut failures: Vec<String> = Vec::new();
+        for (n, uuid) in order.into_iter().enumerate() {
+            let Some(result) = results.remove(&uuid) else {
+                return Err(LinkedErr::by_link(
+                    E::FailToFindJoinResult(uuid),
+                    (&self.link()).into(),
+                ));
+            };
+            if failed(&result) {
+                failures.push(match &result {
+                    Ok(vl) => format!("#{n}: {vl}"),
+                    Err(err) => format!("#{n}: {}", err.e),
+                });
+            }
+            match result {
+                Ok(value) => {
+                    output.push(value);
+                }
+                Err(err) => {
+                    if matches!(policy, JoinPolicy::FailFast) {
+                        return Err(err);
                     }
+                    // Error becomes the result of branch, so it can be inspected
+                    cx.job
+                        .journal
+                        .err(format!("Branch #{n} of join: {}", err.e));
+                    output.push(RtValue::ExecuteResult(ExecuteResult::RunError(
+                        err.e.to_string(),
+                    )));
                 }
-                Ok(RtValue::Vec(output))
             }
-            Err(err) => Err(err),
         }
+        if policy.fails(failures.len()) {
+            return Err(LinkedErr::by_link(
+                E::JoinFailed(failures.len(), failures.join("\n")),
+                (&self.link()).into(),
+            ));
+        }
+        Ok(RtValue::Vec(output))
     }
 }
@@ -45,3 +45,154 @@ test_task_results!(
     };
     "#
 );
+
+test_value_expectation!(
+    join_002,
+    Block,
+    RtValue::Bool(true),
+    r#"
+    {
+        let limit = 2;
+        join[limit](
+            `../target/debug/exit 0 100 60 60`,
+            `../target/debug/exit 0 100 60 60`,
+            `../target/debug/exit 0 100 60 60`,
+        );
+        true;
+    }"#
+);
+
+test_fail!(
+    join_003,
+    Block,
+    r#"
+    {
+        join[0](
+            `../target/debug/exit 0 100 60 60`,
+            `../target/debug/exit 0 100 60 60`,
+        );
+    }"#
+);
+
+test_value_expectation!(
+    join_wait_all,
+    Block,
+    RtValue::Num(2.0),
+    r#"
+    {
+        let results = join["wait-all"](
+            `../target/debug/exit 1 50 10 10`,
+            `../target/debug/exit 0 200 10 10`,
+            `../target/debug/exit 1 100 10 10`,
+        );
+        let failed = 0;
+        for(el, n) in results {
+            if el.is_failed() {
+                failed += 1;
+            };
+        };
+        failed;
+    }"#
+);
+
+test_value_expectation!(
+    join_fail_fast,
+    Block,
+    RtValue::Num(1.0),
+    r#"
+    {
+        let results = join(
+            `../target/debug/exit 1 50 10 10`,
+            `../target/debug/exit 0 500 10 10`,
+        );
+        let failed = 0;
+        for(el, n) in results {
+            if el.is_failed() {
+                failed += 1;
+            };
+        };
+        failed;
+    }"#
+);
+
+test_value_expectation!(
+    join_allow,
+    Block,
+    RtValue::Bool(true),
+    r#"
+    {
+        join[2, "allow:1"](
+            `../target/debug/exit 1 50 10 10`,
+            `../target/debug/exit 0 50 10 10`,
+        );
+        true;
+    }"#
+);
+
+test_fail!(
+    join_allow_exceeded,
+    Block,
+    r#"
+    {
+        join["allow:1"](
+            `../target/debug/exit 1 50 10 10`,
+            `../target/debug/exit 1 50 10 10`,
+        );
+    }"#
+);
+
+test_fail!(
+    join_collect,
+    Block,
+    r#"
+    {
+        join["collect"](
+            `../target/debug/exit 1 50 10 10`,
+            `../target/debug/exit 0 50 10 10`,
+        );
+    }"#
+);
+
+test_fail!(
+    join_invalid_policy,
+    Block,
+    r#"
+    {
+        // Policy given by variable is checked only during execution
+        let policy = "never";
+        join[policy](
+            `../target/debug/exit 0 50 10 10`,
+        );
+    }"#
+);
+
+/// Under fail-fast the branch, which has cancelled others, is reported; even if a branch
+/// declared before it fails because of cancelling
+#[tokio::test]
+async fn join_fail_fast_trigger() {
+    use super::wait;
+    use tokio_util::sync::CancellationToken;
+
+    let token = CancellationToken::new();
+    let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+    let cancelled = token.clone();
+    let err = |e: E| LinkedErr::by_link(e, (&SrcLink::default()).into());
+    let tasks = vec![
+        (
+            SrcLink::default(),
+            tokio::spawn(async move {
+                cancelled.cancelled().await;
+                (first, Err(err(E::JobSlot(String::from("cancelled")))))
+            }),
+        ),
+        (
+            SrcLink::default(),
+            tokio::spawn(async move { (second, Err(err(E::InvalidJoinPolicy(String::new())))) }),
+        ),
+    ];
+    let (results, trigger) = wait(tasks, token, JoinPolicy::FailFast)
+        .await
+        .expect("Branches are done");
+    assert_eq!(results.len(), 2);
+    assert_eq!(trigger, Some(second));
+}
@@ -11,21 +11,27 @@ impl Interpret for Loop {
             .await
             .map_err(|err| LinkedErr::by_link(err, (&self.slink()).into()))?;
         let mut vl = None;
+        let mut iterations = 0;
         loop {
             if cx
                 .loops()
-                .is_stopped()
+                .next()
                 .await
                 .map_err(|err| LinkedErr::from(err, self))?
             {
                 break;
             }
+            rt.limits()
+                .iteration(iterations)
+                .map_err(|err| LinkedErr::by_link(err, (&self.slink()).into()))?;
+            iterations += 1;
             vl = Some(self.block.interpret(rt.clone(), cx.clone()).await?);
         }
-        cx.loops()
+        let brk = cx
+            .loops()
             .close()
             .await
             .map_err(|err| LinkedErr::by_link(err, (&self.slink()).into()))?;
-        Ok(vl.unwrap_or(RtValue::Void))
+        Ok(brk.or(vl).unwrap_or(RtValue::Void))
     }
 }
@@ -79,3 +79,52 @@ test_fail!(
         sum;
     }"#
 );
+
+test_limits!(
+    loop_000,
+    "comp",
+    "task_a",
+    RtLimits {
+        iterations: Some(10),
+        ..Default::default()
+    },
+    E::IterationsLimit(10),
+    r#"
+    component comp() {
+        task task_a() {
+            let a = 0;
+            loop {
+                a += 1;
+                if a < 0 {
+                    break;
+                }
+            }
+        }
+    };
+    "#
+);
+
+test_limits!(
+    loop_001,
+    "comp",
+    "task_a",
+    RtLimits {
+        iterations: None,
+        timeout: Some(std::time::Duration::from_millis(100)),
+        ..Default::default()
+    },
+    E::TimeLimit(..),
+    r#"
+    component comp() {
+        task task_a() {
+            let a = 0;
+            loop {
+                a += 1;
+                if a < 0 {
+                    break;
+                }
+            }
+        }
+    };
+    "#
+);
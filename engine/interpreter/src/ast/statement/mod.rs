@@ -4,6 +4,7 @@ mod assignation;
 mod assigned_value;
 mod block;
 mod r#break;
+mod r#continue;
 mod r#for;
 mod r#if;
 mod join;
@@ -24,6 +25,7 @@ impl Interpret for Statement {
             Statement::ArgumentAssignedValue(n) => n.interpret(rt, cx),
             Statement::Block(n) => n.interpret(rt, cx),
             Statement::Break(n) => n.interpret(rt, cx),
+            Statement::Continue(n) => n.interpret(rt, cx),
             Statement::For(n) => n.interpret(rt, cx),
             Statement::If(n) => n.interpret(rt, cx),
             Statement::Join(n) => n.interpret(rt, cx),
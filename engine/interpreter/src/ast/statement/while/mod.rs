@@ -10,15 +10,20 @@ impl Interpret for While {
             .open(&self.uuid)
             .await
             .map_err(|err| LinkedErr::by_link(err, (&self.slink()).into()))?;
+        let mut iterations = 0;
         loop {
             if cx
                 .loops()
-                .is_stopped()
+                .next()
                 .await
                 .map_err(|err| LinkedErr::by_link(err, (&self.slink()).into()))?
             {
                 break;
             }
+            rt.limits()
+                .iteration(iterations)
+                .map_err(|err| LinkedErr::by_link(err, (&self.slink()).into()))?;
+            iterations += 1;
             let vl = self.comparison.interpret(rt.clone(), cx.clone()).await?;
             let RtValue::Bool(vl) = vl else {
                 return Err(LinkedErr::from(
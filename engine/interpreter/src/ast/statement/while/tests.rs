@@ -77,3 +77,24 @@ test_value_expectation!(
         n + m;
     }"#
 );
+
+test_limits!(
+    while_000,
+    "comp",
+    "task_a",
+    RtLimits {
+        iterations: Some(10),
+        ..Default::default()
+    },
+    E::IterationsLimit(10),
+    r#"
+    component comp() {
+        task task_a() {
+            let a = 0;
+            while a >= 0 {
+                a += 1;
+            }
+        }
+    };
+    "#
+);
@@ -0,0 +1,141 @@
+use crate::*;
+
+test_value_expectation!(
+    fs_read_dir,
+    Block,
+    RtValue::Str(String::from("../target/tests/fs_read_dir/a.txt|../target/tests/fs_read_dir/b|../target/tests/fs_read_dir/c.rs")),
+    r#"{
+        let root = "../target/tests/fs_read_dir";
+        fs::create_dir_all(fs::path_join([root, "b"]));
+        fs::write(fs::path_join([root, "c.rs"]), "");
+        fs::write(fs::path_join([root, "a.txt"]), "");
+        let found = fs::read_dir(root).join_with("|");
+        fs::remove_dir_all(root);
+        found;
+    }"#
+);
+
+test_value_expectation!(
+    fs_walk,
+    Block,
+    RtValue::Str(String::from("../target/tests/fs_walk/a.rs|../target/tests/fs_walk/src/b.rs|../target/tests/fs_walk/src/nested/c.rs")),
+    r#"{
+        let root = "../target/tests/fs_walk";
+        fs::create_dir_all(fs::path_join([root, "src", "nested"]));
+        fs::create_dir_all(fs::path_join([root, "target"]));
+        fs::write(fs::path_join([root, "a.rs"]), "");
+        fs::write(fs::path_join([root, "a.txt"]), "");
+        fs::write(fs::path_join([root, "src", "b.rs"]), "");
+        fs::write(fs::path_join([root, "src", "nested", "c.rs"]), "");
+        fs::write(fs::path_join([root, "target", "d.rs"]), "");
+        let found = fs::walk(root, ["*.rs", "**/*.rs"], ["target"], false).join_with("|");
+        fs::remove_dir_all(root);
+        found;
+    }"#
+);
+
+test_value_expectation!(
+    fs_walk_gitignore,
+    Block,
+    RtValue::Str(String::from(
+        "../target/tests/fs_walk_gitignore/.gitignore|../target/tests/fs_walk_gitignore/a.rs"
+    )),
+    r#"{
+        let root = "../target/tests/fs_walk_gitignore";
+        fs::create_dir_all(root);
+        fs::write(fs::path_join([root, ".gitignore"]), "*.log");
+        fs::write(fs::path_join([root, "a.rs"]), "");
+        fs::write(fs::path_join([root, "b.log"]), "");
+        let found = fs::walk(root, [], [], true).join_with("|");
+        fs::remove_dir_all(root);
+        found;
+    }"#
+);
+
+test_value_expectation!(
+    fs_glob,
+    Block,
+    RtValue::Str(String::from(
+        "../target/tests/fs_glob/src/b.rs|../target/tests/fs_glob/src/nested/c.rs"
+    )),
+    r#"{
+        let root = "../target/tests/fs_glob";
+        fs::create_dir_all(fs::path_join([root, "src", "nested"]));
+        fs::write(fs::path_join([root, "a.rs"]), "");
+        fs::write(fs::path_join([root, "src", "b.rs"]), "");
+        fs::write(fs::path_join([root, "src", "b.txt"]), "");
+        fs::write(fs::path_join([root, "src", "nested", "c.rs"]), "");
+        let found = fs::glob(fs::path_join([root, "src/**/*.rs"]), false).join_with("|");
+        fs::remove_dir_all(root);
+        found;
+    }"#
+);
+
+test_value_expectation!(
+    fs_copy_dir,
+    Block,
+    RtValue::Str(String::from(
+        "../target/tests/fs_copy_dir/dest/a.txt|../target/tests/fs_copy_dir/dest/nested/b.txt"
+    )),
+    r#"{
+        let root = "../target/tests/fs_copy_dir";
+        fs::create_dir_all(fs::path_join([root, "src", "nested"]));
+        fs::write(fs::path_join([root, "src", "a.txt"]), "a");
+        fs::write(fs::path_join([root, "src", "nested", "b.txt"]), "b");
+        let copied = fs::copy_dir(fs::path_join([root, "src"]), fs::path_join([root, "dest"]));
+        let found = fs::walk(fs::path_join([root, "dest"]), [], [], false).join_with("|");
+        fs::remove_dir_all(root);
+        if copied == 2 {
+            found;
+        } else {
+            "";
+        };
+    }"#
+);
+
+test_value_expectation!(
+    fs_move_to,
+    Block,
+    RtValue::Str(String::from("../target/tests/fs_move_to/dest/a.txt")),
+    r#"{
+        let root = "../target/tests/fs_move_to";
+        fs::create_dir_all(fs::path_join([root, "src"]));
+        fs::write(fs::path_join([root, "src", "a.txt"]), "a");
+        fs::move_to(fs::path_join([root, "src"]), fs::path_join([root, "dest"]));
+        let moved = fs::exists(fs::path_join([root, "src"]));
+        let found = fs::walk(fs::path_join([root, "dest"]), [], [], false).join_with("|");
+        fs::remove_dir_all(root);
+        if moved {
+            "";
+        } else {
+            found;
+        };
+    }"#
+);
+
+test_value_expectation!(
+    fs_sync,
+    Block,
+    RtValue::Str(String::from(
+        "../target/tests/fs_sync/dest/a.txt|../target/tests/fs_sync/dest/b.txt"
+    )),
+    r#"{
+        let root = "../target/tests/fs_sync";
+        fs::create_dir_all(fs::path_join([root, "src"]));
+        fs::create_dir_all(fs::path_join([root, "dest", "extra"]));
+        fs::write(fs::path_join([root, "src", "a.txt"]), "a");
+        fs::write(fs::path_join([root, "src", "b.txt"]), "b");
+        fs::write(fs::path_join([root, "dest", "c.txt"]), "c");
+        fs::write(fs::path_join([root, "dest", "extra", "d.txt"]), "d");
+        let first = fs::sync(fs::path_join([root, "src"]), fs::path_join([root, "dest"]), false);
+        fs::write(fs::path_join([root, "src", "a.txt"]), "aa");
+        let second = fs::sync(fs::path_join([root, "src"]), fs::path_join([root, "dest"]), true);
+        let found = fs::walk(fs::path_join([root, "dest"]), [], [], false).join_with("|");
+        fs::remove_dir_all(root);
+        if first == 2 && second == 1 {
+            found;
+        } else {
+            "";
+        };
+    }"#
+);
@@ -0,0 +1,153 @@
+use crate::{tests::*, *};
+
+test_value_expectation!(
+    locks_acquire_release,
+    Block,
+    RtValue::Bool(true),
+    r#"
+    {
+        locks::acquire("db");
+        locks::release("db");
+        locks::acquire("db");
+        locks::release("db");
+        true;
+    }"#
+);
+
+test_value_expectation!(
+    locks_capacity,
+    Block,
+    RtValue::Bool(true),
+    r#"
+    {
+        locks::capacity("port", 2);
+        locks::acquire("port");
+        locks::acquire("port");
+        locks::release("port");
+        locks::release("port");
+        true;
+    }"#
+);
+
+test_fail!(
+    locks_deadlock,
+    Block,
+    r#"
+    {
+        locks::acquire("db");
+        locks::acquire("db");
+    }"#
+);
+
+test_fail!(
+    locks_release_not_held,
+    Block,
+    r#"
+    {
+        locks::release("db");
+    }"#
+);
+
+test_fail!(
+    locks_invalid_capacity,
+    Block,
+    r#"
+    {
+        locks::capacity("db", 0);
+    }"#
+);
+
+const TASKS: &str = r#"
+component comp() {
+    task a() {
+        locks::acquire("db");
+        `../target/debug/exit 0 300 60 60`;
+        locks::release("db");
+        true;
+    }
+    task b() {
+        locks::acquire("db");
+        `../target/debug/exit 0 300 60 60`;
+        locks::release("db");
+        true;
+    }
+    task fails() {
+        locks::acquire("db");
+        locks::release("other");
+    }
+    task long() {
+        locks::acquire("db");
+        `../target/debug/exit 0 10000 60 60`;
+    }
+    task take() {
+        locks::acquire("db");
+        locks::release("db");
+        true;
+    }
+};
+"#;
+
+/// Tasks running in parallel (as branches of `join`) wait for each other on the same lock
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn locks_parallel_branches() {
+    let rt = scenario_runtime(TASKS);
+    let mut branches = Vec::new();
+    for task in ["a", "b"] {
+        let cx = rt.create_cx(Uuid::new_v4(), task, None).await.unwrap();
+        branches.push(tokio::spawn(run_task(rt.clone(), cx, "comp", task)));
+    }
+    for branch in branches {
+        assert_eq!(branch.await.unwrap().unwrap(), RtValue::Bool(true));
+    }
+    let timings = rt.timings().await.unwrap();
+    let commands = timings
+        .jobs()
+        .filter(|job| job.alias.starts_with("../target/debug/exit"))
+        .collect::<Vec<&JobTiming>>();
+    assert_eq!(commands.len(), 2);
+    let (first, second) = if commands[0].start < commands[1].start {
+        (commands[0], commands[1])
+    } else {
+        (commands[1], commands[0])
+    };
+    assert!(first.end.unwrap() <= second.start);
+    let _ = rt.destroy().await;
+}
+
+/// Locks held by failed task are released
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn locks_released_on_failure() {
+    let rt = scenario_runtime(TASKS);
+    let cx = rt.create_cx(Uuid::new_v4(), "fails", None).await.unwrap();
+    assert!(run_task(rt.clone(), cx, "comp", "fails").await.is_err());
+    let cx = rt.create_cx(Uuid::new_v4(), "take", None).await.unwrap();
+    let taken = tokio::time::timeout(
+        std::time::Duration::from_secs(5),
+        run_task(rt.clone(), cx, "comp", "take"),
+    )
+    .await
+    .expect("Lock is released");
+    assert_eq!(taken.unwrap(), RtValue::Bool(true));
+    let _ = rt.destroy().await;
+}
+
+/// Locks held by cancelled task are released
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn locks_released_on_cancel() {
+    let rt = scenario_runtime(TASKS);
+    let cx = rt.create_cx(Uuid::new_v4(), "long", None).await.unwrap();
+    let cancel = cx.job.cancel.clone();
+    let long = tokio::spawn(run_task(rt.clone(), cx, "comp", "long"));
+    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+    let cx = rt.create_cx(Uuid::new_v4(), "take", None).await.unwrap();
+    let take = tokio::spawn(run_task(rt.clone(), cx, "comp", "take"));
+    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+    assert!(!take.is_finished());
+    cancel.cancel();
+    let taken = tokio::time::timeout(std::time::Duration::from_secs(5), take)
+        .await
+        .expect("Lock is released");
+    assert_eq!(taken.unwrap().unwrap(), RtValue::Bool(true));
+    assert!(!matches!(long.await.unwrap(), Ok(RtValue::Bool(true))));
+    let _ = rt.destroy().await;
+}
@@ -1,2 +1,8 @@
+mod fs;
+mod locks;
+mod output;
+mod path;
+mod re;
 mod signals;
+mod status;
 mod strs;
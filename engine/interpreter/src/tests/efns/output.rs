@@ -0,0 +1,130 @@
+use crate::{tests::*, *};
+
+test_value_expectation!(
+    output_capture,
+    Block,
+    RtValue::Str(String::from("a")),
+    r#"{
+        output::next("capture");
+        `echo a`.stdout().join_with("|");
+    }"#
+);
+
+test_value_expectation!(
+    output_silent,
+    Block,
+    RtValue::Bool(true),
+    r#"{
+        output::mode("silent");
+        let result = `echo a`;
+        result.stdout().join_with("|") == "" && result.is_success();
+    }"#
+);
+
+test_value_expectation!(
+    output_next,
+    Block,
+    RtValue::Bool(true),
+    r#"{
+        output::next("silent");
+        let first = `echo a`.stdout().join_with("|");
+        let second = `echo b`.stdout().join_with("|");
+        first == "" && second == "b";
+    }"#
+);
+
+test_value_expectation!(
+    output_tail,
+    Block,
+    RtValue::Str(String::from("c.txt")),
+    r#"{
+        let root = "../target/tests/output_tail";
+        fs::create_dir_all(root);
+        fs::write(fs::path_join([root, "a.txt"]), "");
+        fs::write(fs::path_join([root, "b.txt"]), "");
+        fs::write(fs::path_join([root, "c.txt"]), "");
+        output::next("tail:1");
+        let found = `ls ../target/tests/output_tail`.lines().join_with("|");
+        fs::remove_dir_all(root);
+        found;
+    }"#
+);
+
+test_value_expectation!(
+    output_tee,
+    Block,
+    RtValue::Bool(true),
+    r#"{
+        let root = "../target/tests/output_tee";
+        fs::create_dir_all(root);
+        output::next("tee:../target/tests/output_tee/output.log");
+        let output = `echo hello`.stdout().join_with("|");
+        let written = fs::read_to_string(fs::path_join([root, "output.log"])).trim_end();
+        fs::remove_dir_all(root);
+        output == "hello" && written == "hello";
+    }"#
+);
+
+test_value_expectation!(
+    output_component_mode,
+    Block,
+    RtValue::Str(String::from("")),
+    r#"{
+        output::component_mode("silent");
+        `echo a`.stdout().join_with("|");
+    }"#
+);
+
+test_fail!(
+    output_invalid_mode,
+    Block,
+    r#"{
+        output::mode("tail:many");
+    }"#
+);
+
+test_value_expectation!(
+    output_interactive,
+    Block,
+    RtValue::Bool(true),
+    r#"{
+        output::next("interactive");
+        let result = `true`;
+        result.is_success() && result.stdout().join_with("|") == "";
+    }"#
+);
+
+const COMPONENTS: &str = r#"
+component comp() {
+    task quiet() {
+        output::component_mode("silent");
+        true;
+    }
+    task check() {
+        `echo a`.stdout().join_with("|");
+    }
+};
+component other() {
+    task check() {
+        `echo a`.stdout().join_with("|");
+    }
+};
+"#;
+
+/// Mode of component set by one task applies to tasks of the same component executed later
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn output_component_mode_later_task() {
+    let rt = scenario_runtime(COMPONENTS);
+    let run = |component: &'static str, task: &'static str| {
+        let rt = rt.clone();
+        async move {
+            let cx = rt.create_cx(Uuid::new_v4(), task, None).await.unwrap();
+            run_task(rt, cx, component, task).await.unwrap()
+        }
+    };
+    assert_eq!(run("comp", "check").await, RtValue::Str(String::from("a")));
+    assert_eq!(run("comp", "quiet").await, RtValue::Bool(true));
+    assert_eq!(run("comp", "check").await, RtValue::Str(String::new()));
+    assert_eq!(run("other", "check").await, RtValue::Str(String::from("a")));
+    let _ = rt.destroy().await;
+}
@@ -0,0 +1,113 @@
+use std::path::PathBuf;
+
+use crate::*;
+
+test_value_expectation!(
+    path_parent,
+    Block,
+    RtValue::PathBuf(PathBuf::from("/a/b")),
+    r#"{
+        path::parent("/a/b/c.txt");
+    }"#
+);
+
+test_value_expectation!(
+    path_file_name,
+    Block,
+    RtValue::Str(String::from("c.tar.gz")),
+    r#"{
+        path::file_name("/a/b/c.tar.gz");
+    }"#
+);
+
+test_value_expectation!(
+    path_stem,
+    Block,
+    RtValue::Str(String::from("c.tar")),
+    r#"{
+        path::stem("/a/b/c.tar.gz");
+    }"#
+);
+
+test_value_expectation!(
+    path_extension,
+    Block,
+    RtValue::Str(String::from("gz")),
+    r#"{
+        path::extension("/a/b/c.tar.gz");
+    }"#
+);
+
+test_value_expectation!(
+    path_with_extension,
+    Block,
+    RtValue::PathBuf(PathBuf::from("/a/b/c.rs")),
+    r#"{
+        path::with_extension("/a/b/c.txt", "rs");
+    }"#
+);
+
+test_value_expectation!(
+    path_chain,
+    Block,
+    RtValue::Str(String::from("c.rs")),
+    r#"{
+        let src = path::with_extension("/a/b/c.txt", "rs");
+        src.file_name();
+    }"#
+);
+
+test_value_expectation!(
+    path_strip_prefix,
+    Block,
+    RtValue::PathBuf(PathBuf::from("b/c.txt")),
+    r#"{
+        path::strip_prefix("/a/b/c.txt", "/a");
+    }"#
+);
+
+test_value_expectation!(
+    path_relative_to,
+    Block,
+    RtValue::PathBuf(PathBuf::from("../../d/e")),
+    r#"{
+        path::relative_to("/a/d/./e", "/a/b/c");
+    }"#
+);
+
+test_value_expectation!(
+    path_normalize,
+    Block,
+    RtValue::PathBuf(PathBuf::from("../a/c")),
+    r#"{
+        path::normalize("../a/./b/../c");
+    }"#
+);
+
+test_value_expectation!(
+    path_components,
+    Block,
+    RtValue::Str(String::from("/|a|b")),
+    r#"{
+        path::components("/a/b").join_with("|");
+    }"#
+);
+
+test_value_expectation!(
+    path_as_str,
+    Block,
+    RtValue::Bool(true),
+    r#"{
+        let dir = path::parent("/a/b/c.txt");
+        dir.starts_with("/a");
+    }"#
+);
+
+test_value_expectation!(
+    path_join_strs,
+    Block,
+    RtValue::PathBuf(PathBuf::from("a/b/c")),
+    r#"{
+        fs::path_join(["a", "b", "c"]);
+    }"#
+);
@@ -0,0 +1,47 @@
+use crate::*;
+
+test_value_expectation!(
+    re_is_match,
+    Block,
+    RtValue::Bool(true),
+    r#"{
+        "v1.2.3".re::is_match("^v\d+\.\d+\.\d+$");
+    }"#
+);
+
+test_value_expectation!(
+    re_find,
+    Block,
+    RtValue::Str(String::from("1.2.3")),
+    r#"{
+        "release v1.2.3".find("\d+\.\d+\.\d+");
+    }"#
+);
+
+test_value_expectation!(
+    re_find_all,
+    Block,
+    RtValue::Str(String::from("1,22,333")),
+    r#"{
+        let found: Vec<str> = "a1 b22 c333".find_all("\d+");
+        found.join_with(",");
+    }"#
+);
+
+test_value_expectation!(
+    re_captures,
+    Block,
+    RtValue::Str(String::from("2.3|2|3")),
+    r#"{
+        "version: 2.3".captures("(\d+)\.(\d+)").join_with("|");
+    }"#
+);
+
+test_value_expectation!(
+    re_replace_all,
+    Block,
+    RtValue::Str(String::from("b-a d-c")),
+    r#"{
+        "a=b c=d".replace_all("(\w)=(\w)", "${2}-${1}");
+    }"#
+);
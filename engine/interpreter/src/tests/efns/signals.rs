@@ -31,3 +31,58 @@ test_task_results!(
     };
     "#
 );
+
+test_value_expectation!(
+    signals_payload,
+    Block,
+    RtValue::Str("ready".to_owned()),
+    r#"
+    {
+        signals::send("SignalA", "ready");
+        signals::wait("SignalA");
+    }"#
+);
+
+test_value_expectation!(
+    signals_wait_timeout,
+    Block,
+    RtValue::Error,
+    r#"
+    {
+        signals::wait_timeout("SignalA", 50);
+    }"#
+);
+
+test_value_expectation!(
+    signals_wait_timeout_emitted,
+    Block,
+    RtValue::Num(1.0),
+    r#"
+    {
+        signals::send("SignalA", 1);
+        signals::wait_timeout("SignalA", 50);
+    }"#
+);
+
+test_value_expectation!(
+    signals_reset,
+    Block,
+    RtValue::Num(2.0),
+    r#"
+    {
+        signals::send("SignalA", 1);
+        signals::reset("SignalA");
+        signals::send("SignalA", 2);
+        signals::wait("SignalA");
+    }"#
+);
+
+test_fail!(
+    signals_multiple_emit,
+    Block,
+    r#"
+    {
+        signals::emit("SignalA");
+        signals::emit("SignalA");
+    }"#
+);
@@ -0,0 +1,44 @@
+use crate::*;
+
+test_value_expectation!(
+    status_stdout,
+    Block,
+    RtValue::Str(String::from("hello world")),
+    r#"{
+        `echo hello world`.stdout().join_with("|");
+    }"#
+);
+
+test_value_expectation!(
+    status_stderr,
+    Block,
+    RtValue::Bool(true),
+    r#"{
+        let result = `ls ../target/tests/status_stderr_missed`;
+        let stdout = result.stdout().join_with("|");
+        let stderr = result.stderr().join_with("|");
+        stdout == "" && stderr != "";
+    }"#
+);
+
+test_value_expectation!(
+    status_lines,
+    Block,
+    RtValue::Str(String::from("a")),
+    r#"{
+        let result = `echo a`;
+        result.lines().join_with("|");
+    }"#
+);
+
+test_value_expectation!(
+    status_code,
+    Block,
+    RtValue::Bool(true),
+    r#"{
+        let success = `echo a`.code();
+        let failed = `ls ../target/tests/status_code_missed`.code();
+        let unknown = `same very fake command`.code();
+        success == 0 && failed > 0 && unknown < 0;
+    }"#
+);
@@ -137,3 +137,92 @@ test_value_expectation!(
         "   ".is_trimmed_empty();
     }"#
 );
+
+test_value_expectation!(
+    strs_split_join_with,
+    Block,
+    RtValue::Str(String::from("a-b-c")),
+    r#"{
+        let parts: Vec<str> = "a,b,c".split(",");
+        parts.join_with("-");
+    }"#
+);
+
+test_value_expectation!(
+    strs_split_iter,
+    Block,
+    RtValue::Str(String::from("ABC")),
+    r#"{
+        let result = "";
+        let parts = "a b c".split(" ");
+        for part in parts {
+            result += part.to_uppercase();
+        };
+        result;
+    }"#
+);
+
+test_value_expectation!(
+    strs_lines,
+    Block,
+    RtValue::Str(String::from("one|two")),
+    r#"{
+        "one
+two".lines().join_with("|");
+    }"#
+);
+
+test_value_expectation!(
+    strs_starts_with,
+    Block,
+    RtValue::Bool(true),
+    r#"{
+        "Hello World".starts_with("Hello");
+    }"#
+);
+
+test_value_expectation!(
+    strs_ends_with,
+    Block,
+    RtValue::Bool(false),
+    r#"{
+        "Hello World".ends_with("Hello");
+    }"#
+);
+
+test_value_expectation!(
+    strs_contains,
+    Block,
+    RtValue::Bool(true),
+    r#"{
+        "Hello World".contains("o W");
+    }"#
+);
+
+test_value_expectation!(
+    strs_pad_start,
+    Block,
+    RtValue::Str(String::from("007")),
+    r#"{
+        "7".pad_start(3, "0");
+    }"#
+);
+
+test_value_expectation!(
+    strs_pad_end,
+    Block,
+    RtValue::Str(String::from("ab  ")),
+    r#"{
+        "ab".pad_end(4, "");
+    }"#
+);
+
+test_value_expectation!(
+    strs_to_fixed,
+    Block,
+    RtValue::Str(String::from("3.14")),
+    r#"{
+        let pi = 3.14159;
+        pi.to_fixed(2);
+    }"#
+);
@@ -0,0 +1,137 @@
+use crate::*;
+
+test_exit_code!(
+    exit_000,
+    "comp",
+    "task_a",
+    RtLimits::default(),
+    EXIT_SUCCESS,
+    r#"
+    component comp() {
+        task task_a() {
+            `../target/debug/exit 0`;
+        }
+    };
+    "#
+);
+
+test_exit_code!(
+    exit_001,
+    "comp",
+    "task_a",
+    RtLimits::default(),
+    3,
+    r#"
+    component comp() {
+        task task_a() {
+            // Failed command gives own exit code
+            `../target/debug/exit 3`;
+        }
+    };
+    "#
+);
+
+test_exit_code!(
+    exit_002,
+    "comp",
+    "task_a",
+    RtLimits::default(),
+    EXIT_FAILURE,
+    r#"
+    component comp() {
+        task task_a() {
+            `../target/debug/exit 3`.is_success();
+        }
+    };
+    "#
+);
+
+test_exit_code!(
+    exit_003,
+    "comp",
+    "task_a",
+    RtLimits::default(),
+    EXIT_SUCCESS,
+    r#"
+    component comp() {
+        task task_a() {
+            `../target/debug/exit 3`.is_failed();
+        }
+    };
+    "#
+);
+
+test_exit_code!(
+    exit_004,
+    "comp",
+    "task_a",
+    RtLimits::default(),
+    42,
+    r#"
+    component comp() {
+        task task_a() {
+            // Failure is allowed, so the result of each branch is returned
+            join["allow:1"] (
+                `../target/debug/exit 0`,
+                `../target/debug/exit 42`,
+                `../target/debug/exit 0`,
+            );
+        }
+    };
+    "#
+);
+
+test_exit_code!(
+    exit_005,
+    "comp",
+    "task_a",
+    RtLimits::default(),
+    EXIT_RUNTIME_ERROR,
+    r#"
+    component comp() {
+        task task_a() {
+            `../target/debug/exit 3`.success();
+        }
+    };
+    "#
+);
+
+test_exit_code!(
+    exit_006,
+    "comp",
+    "task_a",
+    RtLimits {
+        iterations: None,
+        timeout: Some(std::time::Duration::from_millis(100)),
+        ..Default::default()
+    },
+    EXIT_TIMEOUT,
+    r#"
+    component comp() {
+        task task_a() {
+            let a = 0;
+            loop {
+                a += 1;
+                if a < 0 {
+                    break;
+                }
+            }
+        }
+    };
+    "#
+);
+
+test_exit_code!(
+    exit_007,
+    "comp",
+    "task_a",
+    RtLimits::default(),
+    EXIT_SUCCESS,
+    r#"
+    component comp() {
+        task task_a() {
+            "done";
+        }
+    };
+    "#
+);
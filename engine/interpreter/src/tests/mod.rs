@@ -1,4 +1,50 @@
 mod efns;
+mod exit;
+
+use crate::*;
+
+/// Creates runtime with declarations of the scenario
+pub(crate) fn scenario_runtime(content: &str) -> Runtime {
+    let mut lx = lexer::Lexer::new(content, 0);
+    let parser = Parser::unbound(lx.read().unwrap().tokens, &lx.uuid, content, false);
+    let node = Anchor::read(&parser)
+        .expect("Node is parsed without errors")
+        .expect("Node is parsed");
+    let mut scx = SemanticCx::new(false);
+    functions::register(&mut scx.fns.efns).expect("functions are registred");
+    node.initialize(&mut scx).expect("Node is initialized");
+    node.infer_type(&mut scx).expect("Types are inferred");
+    node.finalize(&mut scx).expect("Node is finalized");
+    let params = RtParameters::default_from_cwd().expect("RtParameter created");
+    runtime(params, scx).expect("Runtime created")
+}
+
+/// Runs task of component in the given context. Tasks are called directly instead of task
+/// calls of scenario, because a nested task call closes the context of the caller.
+pub(crate) async fn run_task(
+    rt: Runtime,
+    cx: Context,
+    component: &str,
+    task: &str,
+) -> Result<RtValue, LinkedErr<E>> {
+    let master = rt
+        .tasks
+        .table
+        .values()
+        .find(|en| en.master.name == component)
+        .map(|en| en.master.uuid)
+        .expect("Component is found");
+    rt.tasks
+        .execute_by_name(
+            &master,
+            task,
+            rt.clone(),
+            cx,
+            Vec::new(),
+            &SrcLink::default(),
+        )
+        .await
+}
 
 #[macro_export]
 macro_rules! test_value_expectation {
@@ -209,3 +255,141 @@ macro_rules! test_task_results_from_file {
         }
     };
 }
+
+/// Reads, checks and runs the task of scenario. Returns the parser (to report errors) and the
+/// result of task.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! run_task {
+    ($component_name:literal, $task_name:literal, $args:expr, $limits:expr, $content:literal) => {{
+        let mut lx = lexer::Lexer::new(&$content, 0);
+        let mut parser = Parser::unbound(lx.read().unwrap().tokens, &lx.uuid, $content, false);
+        let node = Anchor::read(&mut parser);
+        if let Err(err) = &node {
+            eprintln!("{}", parser.report_err(err).expect("Reporting error"));
+        }
+        let node = node
+            .expect("Node is parsed without errors")
+            .expect("Node is parsed");
+        let mut scx = SemanticCx::new(false);
+        functions::register(&mut scx.fns.efns).expect("functions are registred");
+        let result = node.initialize(&mut scx);
+        if let Err(err) = &result {
+            eprintln!("{}", parser.report_err(err).expect("Reporting error"));
+        }
+        assert!(result.is_ok());
+        let result = node.infer_type(&mut scx);
+        if let Err(err) = &result {
+            eprintln!("{}", parser.report_err(err).expect("Reporting error"));
+        }
+        assert!(result.is_ok());
+        let result = node.finalize(&mut scx);
+        if let Err(err) = &result {
+            eprintln!("{}", parser.report_err(err).expect("Reporting error"));
+        }
+        assert!(result.is_ok());
+        let params = RtParameters::new(
+            $component_name,
+            $task_name,
+            $args,
+            std::env::current_dir().expect("Current folder detected"),
+        )
+        .with_limits($limits);
+        let rt = runtime(params, scx).expect("Runtime created");
+        let cx = rt
+            .create_cx(Uuid::new_v4(), "Test", None)
+            .await
+            .expect("Context created");
+        let vl = node.interpret(rt.clone(), cx.clone()).await;
+        let _ = rt.destroy().await;
+        (parser, vl)
+    }};
+}
+
+#[macro_export]
+macro_rules! test_limits {
+    ($fn_name:ident, $component_name:literal, $task_name:literal, $limits:expr, $expectation:pat, $content:literal) => {
+        paste::item! {
+            #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+            async fn [< test_limits_ $fn_name >]() {
+                use $crate::*;
+
+                let (parser, vl) =
+                    $crate::run_task!($component_name, $task_name, Vec::new(), $limits, $content);
+                let Err(err) = vl else {
+                    panic!("Limit isn't reached: {vl:?}");
+                };
+                eprintln!("{}", parser.report_err(&err).expect("Reporting error"));
+                assert!(matches!(err.e, $expectation), "Unexpected error: {:?}", err.e);
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! test_cli_args {
+    ($fn_name:ident, $component_name:literal, $task_name:literal, [$($arg:literal),*], Ok($expectation:expr), $content:literal) => {
+        paste::item! {
+            #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+            async fn [< test_cli_args_ $fn_name >]() {
+                use $crate::*;
+
+                let (_, vl) = $crate::run_task!(
+                    $component_name,
+                    $task_name,
+                    vec![$($arg.to_owned()),*],
+                    RtLimits::default(),
+                    $content
+                );
+                let vl = vl.expect("Task is done");
+                assert!(
+                    vl == $expectation,
+                    "Values are not equal: {:?} vs {:?}",
+                    vl,
+                    $expectation
+                );
+            }
+        }
+    };
+    ($fn_name:ident, $component_name:literal, $task_name:literal, [$($arg:literal),*], Err($expectation:pat), $content:literal) => {
+        paste::item! {
+            #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+            async fn [< test_cli_args_ $fn_name >]() {
+                use $crate::*;
+
+                let (_, vl) = $crate::run_task!(
+                    $component_name,
+                    $task_name,
+                    vec![$($arg.to_owned()),*],
+                    RtLimits::default(),
+                    $content
+                );
+                let Err(err) = vl else {
+                    panic!("Arguments are accepted: {vl:?}");
+                };
+                eprintln!("{}", err.e);
+                assert!(matches!(err.e, $expectation), "Unexpected error: {:?}", err.e);
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! test_exit_code {
+    ($fn_name:ident, $component_name:literal, $task_name:literal, $limits:expr, $expectation:expr, $content:literal) => {
+        paste::item! {
+            #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+            async fn [< test_exit_code_ $fn_name >]() {
+                use $crate::*;
+
+                let (_, vl) =
+                    $crate::run_task!($component_name, $task_name, Vec::new(), $limits, $content);
+                let code = match &vl {
+                    Ok(vl) => vl.exit_code(),
+                    Err(err) => err.e.exit_code(),
+                };
+                assert_eq!(code, $expectation, "Unexpected exit code for: {vl:?}");
+            }
+        }
+    };
+}
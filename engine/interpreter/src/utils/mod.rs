@@ -1,11 +1,19 @@
 use crate::*;
 
+/// Checks the type of value. If the node is followed by calls or accessors (`value.fn()`),
+/// the type of the last one is checked.
 pub(crate) async fn chk_ty(
     node: &LinkedNode,
     vl: &RtValue,
     rt: &Runtime,
 ) -> Result<(), LinkedErr<E>> {
-    let Some(ty) = rt.tys.get(node.uuid()) else {
+    let uuid = node
+        .get_md()
+        .ppm
+        .last()
+        .map(|ppm| ppm.uuid())
+        .unwrap_or(node.uuid());
+    let Some(ty) = rt.tys.get(uuid) else {
         return Err(LinkedErr::from(E::FailInferType, node));
     };
     if !ty.reassignable(
@@ -18,6 +26,56 @@ pub(crate) async fn chk_ty(
     }
 }
 
+/// Maps given arguments of call to declared arguments. Named arguments are placed on
+/// their positions; omitted arguments are replaced with default values. Returns arguments
+/// in the order of declaration.
+pub(crate) async fn bind_call_args(
+    sigs: &[ArgSig<'_>],
+    args: Vec<FnArgValue>,
+    rt: &Runtime,
+    cx: &Context,
+    caller: &SrcLink,
+) -> Result<Vec<FnArgValue>, LinkedErr<E>> {
+    let given = args
+        .into_iter()
+        .map(|arg| {
+            if let RtValue::NamedArgumentValue(name, vl) = arg.value {
+                (Some(name), FnArgValue::new(*vl, arg.link))
+            } else {
+                (None, arg)
+            }
+        })
+        .collect::<Vec<(Option<String>, FnArgValue)>>();
+    let given_len = given.len();
+    let bound = runtime::bind_args(sigs, given).map_err(|err| {
+        let err = match err {
+            ArgsMismatch::TooMany => E::TooManyArguments(sigs.len(), given_len),
+            ArgsMismatch::Missed(missed) => E::MissedArgument(
+                missed
+                    .first()
+                    .and_then(|idx| sigs[*idx].name)
+                    .map(|name| name.to_owned())
+                    .unwrap_or_default(),
+            ),
+            ArgsMismatch::Unknown(name) => E::UnknownArgument(name),
+            ArgsMismatch::Duplicated(name) => E::DuplicatedArgument(name),
+            ArgsMismatch::PositionalAfterNamed => E::PositionalArgAfterNamed,
+        };
+        LinkedErr::by_link(err, caller.into())
+    })?;
+    let mut args = Vec::new();
+    for (sig, mut vls) in sigs.iter().zip(bound) {
+        match sig.default {
+            Some(default) if vls.is_empty() => {
+                let vl = default.interpret(rt.clone(), cx.clone()).await?;
+                args.push(FnArgValue::by_node(vl, default));
+            }
+            _ => args.append(&mut vls),
+        }
+    }
+    Ok(args)
+}
+
 fn into_rt_ufns(mut fns: Fns) -> Fns {
     fns.ufns.funcs = fns
         .ufns
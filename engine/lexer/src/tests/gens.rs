@@ -171,6 +171,10 @@ pub fn kind(id: KindId) -> BoxedStrategy<Kind> {
             })
             .prop_map(Kind::Identifier)
             .boxed(),
+        KindId::Label => "[a-z][a-z0-9]*"
+            .prop_map(String::from)
+            .prop_map(Kind::Label)
+            .boxed(),
         KindId::Number => proptest::num::f64::NORMAL
             .prop_filter("Finite f64", |x| x.is_finite())
             .prop_map(Kind::Number)
@@ -274,6 +278,7 @@ pub fn keyword(id: KeywordId) -> BoxedStrategy<Keyword> {
         KeywordId::Each => Just(Keyword::Each).boxed(),
         KeywordId::Return => Just(Keyword::Return).boxed(),
         KeywordId::Break => Just(Keyword::Break).boxed(),
+        KeywordId::Continue => Just(Keyword::Continue).boxed(),
         KeywordId::Let => Just(Keyword::Let).boxed(),
         KeywordId::In => Just(Keyword::In).boxed(),
         KeywordId::OneOf => Just(Keyword::OneOf).boxed(),
@@ -0,0 +1,50 @@
+#[cfg(test)]
+use crate::*;
+
+#[cfg(test)]
+fn kinds(content: &str) -> Vec<KindId> {
+    Lexer::new(content, 0)
+        .read()
+        .expect("Content has to be read")
+        .tokens
+        .into_iter()
+        .map(|tk| tk.id())
+        .collect()
+}
+
+/// Single-quoted strings which look like labels of loops have to stay strings.
+#[test]
+fn strings_look_like_labels() {
+    for content in [
+        "let s = 'a: for all';",
+        "let s = 'x: loop forever';",
+        "'a: for all';",
+        "print('x: while true {a}');",
+        "let s = 'status: while running {v}';",
+        "let s = 'step: for {name} done: loop {count}';",
+    ] {
+        let ids = kinds(content);
+        assert!(!ids.contains(&KindId::Label), "{content}: {ids:?}");
+        assert!(ids.contains(&KindId::SingleQuote), "{content}: {ids:?}");
+    }
+}
+
+/// Labels of loops are recognized where a statement starts.
+#[test]
+fn labels() {
+    for content in [
+        "'outer: loop { break 'outer; }",
+        "{ 'a: while true { continue 'a; } }",
+        "let a = 1; 'b: for i in 0..1 { break 'b; }",
+        "if a { 'c: loop { break 'c 1; }; }",
+    ] {
+        assert_eq!(
+            kinds(content)
+                .iter()
+                .filter(|id| matches!(id, KindId::Label))
+                .count(),
+            2,
+            "{content}"
+        );
+    }
+}
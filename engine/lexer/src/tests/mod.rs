@@ -1,3 +1,4 @@
 pub mod gens;
+pub mod labels;
 pub mod proptests;
 pub mod runners;
@@ -57,6 +57,7 @@ proptest! {
         KindId::DoubleQuote,
         KindId::Backtick,
         KindId::Literal,
+        KindId::Label,
         KindId::EOF,
         KindId::BOF]), 1..1000)) {
         let mut cases = cases.into_iter().flat_map(|knd| {
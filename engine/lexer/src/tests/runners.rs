@@ -72,6 +72,7 @@ impl Token {
                     from + self.kind.id().length().expect("Fail to get element length");
             }
             Kind::Identifier(..)
+            | Kind::Label(..)
             | Kind::String(..)
             | Kind::Literal(..)
             | Kind::Comment(..)
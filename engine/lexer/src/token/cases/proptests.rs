@@ -28,6 +28,7 @@ pub fn content(id: Kind, deep: u8) -> BoxedStrategy<Vec<Kind>> {
                 KindId::Meta,
                 KindId::EOF,
                 KindId::BOF,
+                KindId::Label,
             ]),
             0..10,
         ),
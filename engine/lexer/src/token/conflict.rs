@@ -60,7 +60,8 @@ impl ConflictResolver for KindId {
             | Self::Backslash
             | Self::Tilde
             | Self::Backtick
-            | Self::Whitespace => self.clone(),
+            | Self::Whitespace
+            | Self::Label => self.clone(),
             Self::Plus => {
                 if matches!(id, KindId::PlusEqual) {
                     id.clone()
@@ -174,6 +175,7 @@ impl ConflictResolver for KindId {
                 | KindId::EOF
                 | KindId::BOF
                 | KindId::Identifier
+                | KindId::Label
                 | KindId::String
                 | KindId::Literal
                 | KindId::Whitespace
@@ -35,7 +35,7 @@ impl Interest for KindId {
             Self::Identifier | Self::Keyword => ch.is_alphabetic(),
             Self::Number => ch.is_alphanumeric(),
             Self::String => &'"' == ch,
-            Self::SingleQuote => &'\'' == ch,
+            Self::SingleQuote | Self::Label => &'\'' == ch,
             Self::DoubleQuote => &'"' == ch,
             Self::Backslash => &'\\' == ch,
             Self::Tilde => &'~' == ch,
@@ -93,6 +93,7 @@ impl Interest for KindId {
                 .any(|s| s == ident),
             Self::Identifier => true,
             Self::Whitespace
+            | Self::Label
             | Self::Number
             | Self::String
             | Self::Literal
@@ -21,6 +21,8 @@ pub enum Keyword {
     Return,
     /// The `break` keyword.
     Break,
+    /// The `continue` keyword.
+    Continue,
     /// The `let` keyword.
     Let,
     /// The `Join` keyword.
@@ -101,6 +103,7 @@ impl fmt::Display for Keyword {
                 Self::Each => "each".to_owned(),
                 Self::Return => "return".to_owned(),
                 Self::Break => "break".to_owned(),
+                Self::Continue => "continue".to_owned(),
                 Self::Let => "let".to_owned(),
                 Self::In => "in".to_owned(),
                 Self::OneOf => "oneof".to_owned(),
@@ -134,6 +137,7 @@ impl From<&KeywordId> for Keyword {
             KeywordId::Each => Keyword::Each,
             KeywordId::Return => Keyword::Return,
             KeywordId::Break => Keyword::Break,
+            KeywordId::Continue => Keyword::Continue,
             KeywordId::Let => Keyword::Let,
             KeywordId::In => Keyword::In,
             KeywordId::OneOf => Keyword::OneOf,
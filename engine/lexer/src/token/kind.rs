@@ -14,6 +14,8 @@ pub enum Kind {
     Keyword(Keyword),
     /// An identifier consisting of a string.
     Identifier(String),
+    /// A label of loop (`'outer`). Includes the name of label without leading quote.
+    Label(String),
     /// A numeric literal represented as a floating-point number.
     Number(f64),
     /// A string literal. Used in strings and commands
@@ -134,6 +136,7 @@ impl fmt::Display for Kind {
             match self {
                 Self::Keyword(s) => s.to_string(),
                 Self::Identifier(s) => s.clone(),
+                Self::Label(s) => format!("'{s}"),
                 Self::Number(n) => n.to_string(),
                 Self::Literal(n) => n.to_string(),
                 Self::String(s) => format!("\"{s}\""),
@@ -242,6 +245,7 @@ impl KindId {
             Self::CR => "\r",
             Self::CRLF => "\r\n",
             Self::Identifier
+            | Self::Label
             | Self::Keyword
             | Self::Number
             | Self::String
@@ -310,6 +314,7 @@ impl TryFrom<KindId> for Kind {
             KindId::EOF => Ok(Kind::EOF),
             KindId::BOF => Ok(Kind::BOF),
             KindId::Identifier
+            | KindId::Label
             | KindId::Keyword
             | KindId::Number
             | KindId::String
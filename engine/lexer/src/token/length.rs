@@ -66,6 +66,7 @@ impl ConstantLength for KindId {
             | Self::CRLF => Ok(2),
             Self::Meta => Ok(3),
             Self::Identifier
+            | Self::Label
             | Self::Number
             | Self::String
             | Self::Literal
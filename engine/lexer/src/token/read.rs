@@ -163,6 +163,43 @@ impl Read for Token {
                     ))
                 })
             }
+            KindId::Label => {
+                if !matches!(lx.char(), Some('\'')) {
+                    return Ok(None);
+                }
+                lx.advance();
+                let name = lx.read_identifier();
+                if name.is_empty() || name.starts_with(|ch: char| ch.is_numeric()) {
+                    return Ok(None);
+                }
+                let to = lx.current_pos();
+                // Single quote opens a string in all cases except two: label of loop's
+                // declaration (`'outer: loop`) and label after `break` or `continue`
+                // (`break 'outer;`), which isn't followed by the closing quote. Declaration is
+                // accepted only at the beginning of a statement, only if it's followed by the
+                // keyword of loop and only if the body of loop is opened before any quote, so
+                // `let s = 'a: for all';` stays a string.
+                let drop = lx.pin();
+                let labeled = if tks.is_after_loop_jump() {
+                    !matches!(lx.read_until(&[';', '\n', '\'']), Some((_, '\'')))
+                } else if tks.is_statement_start() && matches!(lx.char(), Some(':')) {
+                    lx.advance();
+                    lx.read_whitespace();
+                    let keyword = lx.read_identifier();
+                    [Keyword::Loop, Keyword::While, Keyword::For]
+                        .iter()
+                        .any(|kw| kw.to_string() == keyword)
+                        && matches!(lx.read_until(&['{', '\'']), Some((_, '{')))
+                } else {
+                    false
+                };
+                drop(lx);
+                Ok(if labeled {
+                    Some(Token::by_pos(Kind::Label(name), &lx.uuid, from, to))
+                } else {
+                    None
+                })
+            }
             KindId::Number => {
                 let mut restore = lx.pin();
                 let mut dot = false;
@@ -60,6 +60,57 @@ impl Tokens {
         nl
     }
 
+    /// Checks if the last significant token is `break` or `continue` keyword.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the last token (except whitespaces) is `break` or `continue`.
+    /// * `false` otherwise.
+    pub fn is_after_loop_jump(&self) -> bool {
+        self.tokens
+            .iter()
+            .rev()
+            .find(|tk| !matches!(tk.id(), KindId::Whitespace))
+            .map(|tk| {
+                matches!(
+                    tk.kind,
+                    Kind::Keyword(Keyword::Break) | Kind::Keyword(Keyword::Continue)
+                )
+            })
+            .unwrap_or(false)
+    }
+
+    /// Checks if a statement can start at the current position.
+    ///
+    /// # Returns
+    ///
+    /// * `true` if the last significant token (except whitespaces, line breaks,
+    ///   comments and meta) is the beginning of file, `;`, `{` or `}`.
+    /// * `false` otherwise.
+    pub fn is_statement_start(&self) -> bool {
+        self.tokens
+            .iter()
+            .rev()
+            .find(|tk| {
+                !matches!(
+                    tk.id(),
+                    KindId::Whitespace
+                        | KindId::LF
+                        | KindId::CR
+                        | KindId::CRLF
+                        | KindId::Comment
+                        | KindId::Meta
+                )
+            })
+            .map(|tk| {
+                matches!(
+                    tk.id(),
+                    KindId::BOF | KindId::Semicolon | KindId::LeftBrace | KindId::RightBrace
+                )
+            })
+            .unwrap_or(true)
+    }
+
     /// Returns a reference to the last token in the collection.
     ///
     /// # Returns
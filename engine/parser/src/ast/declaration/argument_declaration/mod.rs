@@ -37,9 +37,19 @@ impl ReadNode<ArgumentDeclaration> for ArgumentDeclaration {
         .map(Box::new) else {
             return Err(E::MissedArgumentTypeDefinition.link(&variable));
         };
+        let default = if parser.is_next(KindId::Equals) {
+            LinkedNode::try_oneof(
+                parser,
+                &[NodeTarget::Statement(&[StatementId::ArgumentAssignedValue])],
+            )?
+            .map(Box::new)
+        } else {
+            None
+        };
         Ok(Some(ArgumentDeclaration {
             variable: Box::new(variable),
             r#type: ty,
+            default,
             uuid: Uuid::new_v4(),
         }))
     }
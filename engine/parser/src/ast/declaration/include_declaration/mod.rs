@@ -50,18 +50,23 @@ impl ReadNode<IncludeDeclaration> for IncludeDeclaration {
                 )
                 .link(&filename_node));
             };
-            let mut inner = parser
-                .from_file(&filename.inner)
-                .map_err(|e| e.link(&filename_node))?;
-            let Some(root) =
-                LinkedNode::try_oneof(&mut inner, &[NodeTarget::Root(&[RootId::Anchor])])?
-            else {
+            let attached = parser.read_attached(&filename.inner, &filename_node, |inner| {
+                let Some(root) =
+                    LinkedNode::try_oneof(inner, &[NodeTarget::Root(&[RootId::Anchor])])?
+                else {
+                    return Err(
+                        E::FailToFindNode(RootId::Anchor.to_string()).link(&filename_node)
+                    );
+                };
+                if !inner.is_done() {
+                    return Err(E::UnrecognizedCode(inner.to_string()).link_until_end(inner));
+                }
+                Ok(Attached::Include(Box::new(root)))
+            })?;
+            let Attached::Include(root) = attached else {
                 return Err(E::FailToFindNode(RootId::Anchor.to_string()).link(&filename_node));
             };
-            if !inner.is_done() {
-                return Err(E::UnrecognizedCode(inner.to_string()).link_until_end(&inner));
-            }
-            root
+            *root
         };
         #[cfg(test)]
         let root = {
@@ -50,9 +50,6 @@ impl ReadNode<ModuleDeclaration> for ModuleDeclaration {
                 )
                 .link(&filename_node));
             };
-            let mut inner = parser
-                .from_file(&filename.inner)
-                .map_err(|e| e.link(&filename_node))?;
             let filepath = PathBuf::from(&filename.inner);
             let Some(name) = filepath
                 .file_stem()
@@ -60,7 +57,14 @@ impl ReadNode<ModuleDeclaration> for ModuleDeclaration {
             else {
                 return Err(E::FailGetModuleName(filename.inner.clone()).link(&filename_node));
             };
-            (get_mod_inner(&mut inner)?, name)
+            let attached = parser.read_attached(&filename.inner, &filename_node, |inner| {
+                Ok(Attached::Module(get_mod_inner(inner)?))
+            })?;
+            let Attached::Module(nodes) = attached else {
+                return Err(E::FailToFindNode(DeclarationId::ModuleDeclaration.to_string())
+                    .link(&filename_node));
+            };
+            (nodes, name)
         };
         #[cfg(test)]
         let (nodes, name) = { (Vec::new(), String::from("test")) };
@@ -25,6 +25,7 @@ impl ConflictResolver<ExpressionId> for ExpressionId {
             | Self::CompoundAssignmentsOp
             | Self::Command
             | Self::TaskCall
+            | Self::NamedArgument
             | Self::BinaryOp
             | Self::BinaryExp => self.to_owned(),
         }
@@ -63,6 +63,7 @@ impl ReadNode<FunctionCall> for FunctionCall {
                     ExpressionId::BinaryExpSeq,
                     ExpressionId::ComparisonSeq,
                     ExpressionId::FunctionCall,
+                    ExpressionId::NamedArgument,
                 ]),
             ],
         )? {
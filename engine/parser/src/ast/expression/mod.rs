@@ -15,6 +15,7 @@ mod compound_assignments;
 mod compound_assignments_op;
 mod function_call;
 mod logical_op;
+mod named_argument;
 mod range;
 mod task_call;
 mod variable;
@@ -48,6 +49,7 @@ impl TryRead<Expression, ExpressionId> for Expression {
             ExpressionId::CompoundAssignmentsOp => CompoundAssignmentsOp::read_as_linked(parser)?,
             ExpressionId::Command => Command::read_as_linked(parser)?,
             ExpressionId::TaskCall => TaskCall::read_as_linked(parser)?,
+            ExpressionId::NamedArgument => NamedArgument::read_as_linked(parser)?,
         })
     }
 }
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod proptests;
+
+use crate::*;
+
+impl Interest for NamedArgument {
+    fn intrested(token: &Token) -> bool {
+        matches!(token.kind, Kind::Identifier(..))
+    }
+}
+
+impl ReadNode<NamedArgument> for NamedArgument {
+    fn read(parser: &Parser) -> Result<Option<NamedArgument>, LinkedErr<E>> {
+        let Some(token) = parser.token() else {
+            return Ok(None);
+        };
+        let Kind::Identifier(ident) = &token.kind else {
+            return Ok(None);
+        };
+        let Some(equals) = parser.token() else {
+            return Ok(None);
+        };
+        if !matches!(equals.kind, Kind::Equals) {
+            return Ok(None);
+        }
+        let value = LinkedNode::try_oneof(
+            parser,
+            &[
+                NodeTarget::Value(&[
+                    ValueId::Number,
+                    ValueId::Boolean,
+                    ValueId::PrimitiveString,
+                    ValueId::InterpolatedString,
+                    ValueId::Closure,
+                    ValueId::Array,
+                ]),
+                NodeTarget::Expression(&[
+                    ExpressionId::Variable,
+                    ExpressionId::BinaryExpSeq,
+                    ExpressionId::ComparisonSeq,
+                    ExpressionId::FunctionCall,
+                ]),
+            ],
+        )?
+        .ok_or_else(|| E::InvalidAssignation(parser.to_string()).link_with_token(&equals))?;
+        Ok(Some(NamedArgument {
+            ident: ident.to_owned(),
+            token: token.clone(),
+            equals: equals.clone(),
+            value: Box::new(value),
+            uuid: Uuid::new_v4(),
+        }))
+    }
+}
@@ -51,6 +51,7 @@ impl ReadNode<TaskCall> for TaskCall {
                     ExpressionId::BinaryExpSeq,
                     ExpressionId::ComparisonSeq,
                     ExpressionId::FunctionCall,
+                    ExpressionId::NamedArgument,
                 ]),
             ],
         )? {
@@ -28,7 +28,7 @@ impl ReadNode<AssignedValue> for AssignedValue {
                     ValueId::Array,
                     ValueId::Closure,
                 ]),
-                NodeTarget::Statement(&[StatementId::If]),
+                NodeTarget::Statement(&[StatementId::If, StatementId::Loop, StatementId::Join]),
                 NodeTarget::Expression(&[
                     ExpressionId::Variable,
                     ExpressionId::BinaryExpSeq,
@@ -40,6 +40,7 @@ impl ReadNode<Block> for Block {
                     NodeTarget::Statement(&[
                         StatementId::Assignation,
                         StatementId::Break,
+                        StatementId::Continue,
                         StatementId::Return,
                         StatementId::For,
                         StatementId::If,
@@ -17,8 +17,37 @@ impl ReadNode<Break> for Break {
         if !matches!(tk.kind, Kind::Keyword(Keyword::Break)) {
             return Ok(None);
         }
+        let label = if parser.is_next(KindId::Label) {
+            parser.token().map(|tk| tk.clone())
+        } else {
+            None
+        };
+        let value = LinkedNode::try_oneof(
+            parser,
+            &[
+                NodeTarget::Value(&[
+                    ValueId::Number,
+                    ValueId::Boolean,
+                    ValueId::PrimitiveString,
+                    ValueId::InterpolatedString,
+                    ValueId::Array,
+                ]),
+                NodeTarget::Statement(&[StatementId::If, StatementId::Loop]),
+                NodeTarget::Expression(&[
+                    ExpressionId::Variable,
+                    ExpressionId::BinaryExpSeq,
+                    ExpressionId::ComparisonSeq,
+                    ExpressionId::FunctionCall,
+                    ExpressionId::Command,
+                    ExpressionId::TaskCall,
+                    ExpressionId::Range,
+                ]),
+            ],
+        )?;
         Ok(Some(Break {
             token: tk.to_owned(),
+            label,
+            value: value.map(Box::new),
             target: None,
             uuid: Uuid::new_v4(),
         }))
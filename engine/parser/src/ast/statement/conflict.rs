@@ -4,6 +4,7 @@ impl ConflictResolver<StatementId> for StatementId {
     fn resolve_conflict(&self, _id: &StatementId) -> StatementId {
         match self {
             Self::Break
+            | Self::Continue
             | Self::Return
             | Self::For
             | Self::Loop
@@ -0,0 +1,32 @@
+#[cfg(test)]
+mod proptests;
+
+use crate::*;
+
+impl Interest for Continue {
+    fn intrested(token: &Token) -> bool {
+        matches!(token.kind, Kind::Keyword(Keyword::Continue))
+    }
+}
+
+impl ReadNode<Continue> for Continue {
+    fn read(parser: &Parser) -> Result<Option<Continue>, LinkedErr<E>> {
+        let Some(tk) = parser.token() else {
+            return Ok(None);
+        };
+        if !matches!(tk.kind, Kind::Keyword(Keyword::Continue)) {
+            return Ok(None);
+        }
+        let label = if parser.is_next(KindId::Label) {
+            parser.token().map(|tk| tk.clone())
+        } else {
+            None
+        };
+        Ok(Some(Continue {
+            token: tk.to_owned(),
+            label,
+            target: None,
+            uuid: Uuid::new_v4(),
+        }))
+    }
+}
@@ -0,0 +1,4 @@
+use crate::*;
+use proptest::prelude::*;
+
+test_node_reading!(Continue, 10);
@@ -5,18 +5,15 @@ use crate::*;
 
 impl Interest for For {
     fn intrested(token: &Token) -> bool {
-        matches!(token.kind, Kind::Keyword(Keyword::For))
+        matches!(token.kind, Kind::Keyword(Keyword::For) | Kind::Label(..))
     }
 }
 
 impl ReadNode<For> for For {
     fn read(parser: &Parser) -> Result<Option<For>, LinkedErr<E>> {
-        let Some(token_for) = parser.token() else {
+        let Some((label, token_for)) = super::read_loop_opening(parser, Keyword::For) else {
             return Ok(None);
         };
-        if !matches!(token_for.kind, Kind::Keyword(Keyword::For)) {
-            return Ok(None);
-        }
         let restore = parser.pin();
         let (el, index) = if let Some((mut inner, ..)) =
             parser.between(KindId::LeftParen, KindId::RightParen)?
@@ -76,6 +73,7 @@ impl ReadNode<For> for For {
         let nodes = block
             .lookup(&[NodeTarget::Statement(&[
                 StatementId::Break,
+                StatementId::Continue,
                 StatementId::Return,
             ])])
             .into_iter()
@@ -88,13 +86,17 @@ impl ReadNode<For> for For {
             };
             match &mut node.get_mut_node() {
                 Node::Statement(Statement::Break(node)) => {
-                    node.set_target(&self_uuid);
+                    node.set_target(&self_uuid, label.as_ref());
+                }
+                Node::Statement(Statement::Continue(node)) => {
+                    node.set_target(&self_uuid, label.as_ref());
                 }
                 Node::Statement(Statement::Return(node)) => node.add_target(&self_uuid),
                 _ => {}
             }
         }
         Ok(Some(For {
+            label,
             token_for: token_for.clone(),
             token_in: token_in.clone(),
             element: Box::new(el),
@@ -17,6 +17,44 @@ impl ReadNode<Join> for Join {
         if !matches!(token.kind, Kind::Keyword(Keyword::Join)) {
             return Ok(None);
         }
+        let mut options = Vec::new();
+        if parser.is_next(KindId::LeftBracket) {
+            let Some((mut inner, open, close)) =
+                parser.between(KindId::LeftBracket, KindId::RightBracket)?
+            else {
+                return Ok(None);
+            };
+            while let Some(node) = LinkedNode::try_oneof(
+                &mut inner,
+                &[
+                    NodeTarget::Value(&[
+                        ValueId::Number,
+                        ValueId::PrimitiveString,
+                        ValueId::InterpolatedString,
+                    ]),
+                    NodeTarget::Expression(&[
+                        ExpressionId::Variable,
+                        ExpressionId::BinaryExpSeq,
+                        ExpressionId::FunctionCall,
+                    ]),
+                ],
+            )? {
+                options.push(node);
+                if let Some(tk) = inner.token() {
+                    if tk.id() != KindId::Comma {
+                        return Err(E::MissedComma.link_by_current(&inner));
+                    }
+                } else {
+                    break;
+                }
+            }
+            if !inner.is_done() {
+                return Err(E::UnrecognizedCode(inner.to_string()).link_until_end(&inner));
+            }
+            if options.is_empty() {
+                return Err(E::MissedJoinOptions.link_between(&open, &close));
+            }
+        }
         let Some((mut inner, open, close)) =
             parser.between(KindId::LeftParen, KindId::RightParen)?
         else {
@@ -42,6 +80,7 @@ impl ReadNode<Join> for Join {
             return Err(E::UnrecognizedCode(inner.to_string()).link_until_end(&inner));
         };
         Ok(Some(Join {
+            options,
             commands,
             token: token.clone(),
             open: open.clone(),
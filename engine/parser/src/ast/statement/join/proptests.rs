@@ -2,3 +2,21 @@ use crate::*;
 use proptest::prelude::*;
 
 test_node_reading!(Join, 10);
+
+test_node_reading_case!(
+    join_with_limit,
+    Join,
+    r#"join[2] ( `cmd a`,`cmd b`,`cmd c` )"#
+);
+
+test_node_reading_case!(
+    join_with_var_limit,
+    Join,
+    r#"join[limit] ( `cmd a`,`cmd b` )"#
+);
+
+test_node_reading_case!(
+    join_with_options,
+    Join,
+    r#"join[4,"allow:2"] ( `cmd a`,`cmd b` )"#
+);
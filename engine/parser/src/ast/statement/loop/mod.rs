@@ -5,24 +5,22 @@ use crate::*;
 
 impl Interest for Loop {
     fn intrested(token: &Token) -> bool {
-        matches!(token.kind, Kind::Keyword(Keyword::Loop))
+        matches!(token.kind, Kind::Keyword(Keyword::Loop) | Kind::Label(..))
     }
 }
 
 impl ReadNode<Loop> for Loop {
     fn read(parser: &Parser) -> Result<Option<Loop>, LinkedErr<E>> {
-        let Some(token) = parser.token() else {
+        let Some((label, token)) = super::read_loop_opening(parser, Keyword::Loop) else {
             return Ok(None);
         };
-        if !matches!(token.kind, Kind::Keyword(Keyword::Loop)) {
-            return Ok(None);
-        }
         let mut block =
             LinkedNode::try_oneof(parser, &[NodeTarget::Statement(&[StatementId::Block])])?
                 .ok_or_else(|| E::MissedBlock.link_with_token(&token))?;
         let nodes = block
             .lookup(&[NodeTarget::Statement(&[
                 StatementId::Break,
+                StatementId::Continue,
                 StatementId::Return,
             ])])
             .into_iter()
@@ -37,7 +35,10 @@ impl ReadNode<Loop> for Loop {
             };
             match &mut node.get_mut_node() {
                 Node::Statement(Statement::Break(node)) => {
-                    node.set_target(&self_uuid);
+                    node.set_target(&self_uuid, label.as_ref());
+                }
+                Node::Statement(Statement::Continue(node)) => {
+                    node.set_target(&self_uuid, label.as_ref());
                 }
                 Node::Statement(Statement::Return(node)) => node.add_target(&self_uuid),
                 _ => {
@@ -46,6 +47,7 @@ impl ReadNode<Loop> for Loop {
             }
         }
         Ok(Some(Loop {
+            label,
             token: token.clone(),
             block: Box::new(block),
             uuid: self_uuid,
@@ -6,6 +6,7 @@ mod assignation;
 mod assigned_value;
 mod block;
 mod r#break;
+mod r#continue;
 mod r#for;
 mod r#if;
 mod join;
@@ -17,6 +18,28 @@ mod r#while;
 
 use crate::*;
 
+/// Reads the opening of loop: optional label (`'outer:`) and the keyword of loop.
+/// Returns `None` if the opening doesn't fit to the given keyword.
+pub(crate) fn read_loop_opening(
+    parser: &Parser,
+    keyword: Keyword,
+) -> Option<(Option<Token>, Token)> {
+    let token = parser.token()?.clone();
+    let (label, token) = if matches!(token.kind, Kind::Label(..)) {
+        if !parser.is_next(KindId::Colon) {
+            return None;
+        }
+        let _ = parser.token();
+        (Some(token), parser.token()?.clone())
+    } else {
+        (None, token)
+    };
+    if token.kind != Kind::Keyword(keyword) {
+        return None;
+    }
+    Some((label, token))
+}
+
 impl AsVec<StatementId> for StatementId {
     fn as_vec() -> Vec<StatementId> {
         StatementId::as_vec()
@@ -28,6 +51,7 @@ impl TryRead<Statement, StatementId> for Statement {
         Ok(match id {
             StatementId::Block => Block::read_as_linked(parser)?,
             StatementId::Break => Break::read_as_linked(parser)?,
+            StatementId::Continue => Continue::read_as_linked(parser)?,
             StatementId::Return => Return::read_as_linked(parser)?,
             StatementId::While => While::read_as_linked(parser)?,
             StatementId::For => For::read_as_linked(parser)?,
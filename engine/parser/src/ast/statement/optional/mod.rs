@@ -39,6 +39,7 @@ impl ReadNode<Optional> for Optional {
             &[
                 NodeTarget::Statement(&[
                     StatementId::Break,
+                    StatementId::Continue,
                     StatementId::Return,
                     StatementId::Block,
                     StatementId::Loop,
@@ -5,18 +5,15 @@ use crate::*;
 
 impl Interest for While {
     fn intrested(token: &Token) -> bool {
-        matches!(token.kind, Kind::Keyword(Keyword::While))
+        matches!(token.kind, Kind::Keyword(Keyword::While) | Kind::Label(..))
     }
 }
 
 impl ReadNode<While> for While {
     fn read(parser: &Parser) -> Result<Option<While>, LinkedErr<E>> {
-        let Some(token) = parser.token() else {
+        let Some((label, token)) = super::read_loop_opening(parser, Keyword::While) else {
             return Ok(None);
         };
-        if !matches!(token.kind, Kind::Keyword(Keyword::While)) {
-            return Ok(None);
-        }
         let comparison = LinkedNode::try_oneof(
             parser,
             &[
@@ -31,6 +28,7 @@ impl ReadNode<While> for While {
         let nodes = block
             .lookup(&[NodeTarget::Statement(&[
                 StatementId::Break,
+                StatementId::Continue,
                 StatementId::Return,
             ])])
             .into_iter()
@@ -43,13 +41,17 @@ impl ReadNode<While> for While {
             };
             match &mut node.get_mut_node() {
                 Node::Statement(Statement::Break(node)) => {
-                    node.set_target(&self_uuid);
+                    node.set_target(&self_uuid, label.as_ref());
+                }
+                Node::Statement(Statement::Continue(node)) => {
+                    node.set_target(&self_uuid, label.as_ref());
                 }
                 Node::Statement(Statement::Return(node)) => node.add_target(&self_uuid),
                 _ => {}
             }
         }
         Ok(Some(While {
+            label,
             token: token.clone(),
             comparison: Box::new(comparison),
             block: Box::new(block),
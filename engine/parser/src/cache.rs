@@ -0,0 +1,164 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fs,
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+use crate::*;
+
+/// Content of attached file (`mod from`, `include from`)
+#[derive(Debug, Clone)]
+pub enum Attached {
+    /// Nodes of module's file
+    Module(Vec<LinkedNode>),
+    /// Root of included scenario
+    Include(Box<LinkedNode>),
+}
+
+#[derive(Debug)]
+struct Dependency {
+    src: Uuid,
+    filename: PathBuf,
+    /// Canonical path to file; used to find entries affected by changes
+    canonical: PathBuf,
+    /// Hash of the content, which has been parsed
+    hash: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    /// The attached file itself and all files attached by it (recursively)
+    deps: Vec<Dependency>,
+    attached: Attached,
+}
+
+/// Cache of parsed attached files. Also keeps in-memory content of files, which should be
+/// used instead of content on disk (for example, unsaved documents).
+///
+/// The entry of a file is valid while the content of the file and of all files attached by
+/// it has the same hash as at the moment of parsing. Hashes are checked on each lookup, so
+/// changes, which weren't reported, never give stale nodes. The owner of cache still can
+/// report changes with [`ParseCache::set_content`], [`ParseCache::remove_content`] and
+/// [`ParseCache::invalidate`] to drop affected entries early.
+#[derive(Debug, Default)]
+pub struct ParseCache {
+    entries: HashMap<PathBuf, Entry>,
+    contents: HashMap<PathBuf, String>,
+}
+
+pub type SharedParseCache = Arc<Mutex<ParseCache>>;
+
+impl ParseCache {
+    pub fn shared() -> SharedParseCache {
+        Arc::new(Mutex::new(ParseCache::default()))
+    }
+
+    /// Sets in-memory content of the file. Entries, which depend on the file, are dropped
+    /// if the content has been changed.
+    pub fn set_content<P: AsRef<Path>, S: ToString>(&mut self, filename: P, content: S) {
+        let filename = Self::canonicalize(filename);
+        let content = content.to_string();
+        if self.contents.get(&filename) == Some(&content) {
+            return;
+        }
+        self.drop_dependents(&filename);
+        self.contents.insert(filename, content);
+    }
+
+    /// Removes in-memory content of the file. Content on disk will be used.
+    pub fn remove_content<P: AsRef<Path>>(&mut self, filename: P) {
+        let filename = Self::canonicalize(filename);
+        if self.contents.remove(&filename).is_some() {
+            self.drop_dependents(&filename);
+        }
+    }
+
+    /// Drops entries, which depend on the file. Should be called if the file has been
+    /// changed on disk.
+    pub fn invalidate<P: AsRef<Path>>(&mut self, filename: P) {
+        self.drop_dependents(&Self::canonicalize(filename));
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub(crate) fn read<P: AsRef<Path>>(&self, filename: P) -> Result<String, io::Error> {
+        if let Some(content) = self.contents.get(filename.as_ref()) {
+            Ok(content.to_owned())
+        } else {
+            fs::read_to_string(filename)
+        }
+    }
+
+    /// Returns cached content of the file. Each item of list is a pair of uuid of source
+    /// and path to file. Returns `None` if the file or any of its dependencies has been
+    /// changed since parsing.
+    pub(crate) fn get(&self, filename: &Path) -> Option<(Vec<(Uuid, PathBuf)>, Attached)> {
+        let entry = self.entries.get(filename)?;
+        if entry
+            .deps
+            .iter()
+            .any(|dep| self.hash(&dep.canonical) != Some(dep.hash))
+        {
+            return None;
+        }
+        Some((
+            entry
+                .deps
+                .iter()
+                .map(|dep| (dep.src, dep.filename.clone()))
+                .collect(),
+            entry.attached.clone(),
+        ))
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        filename: PathBuf,
+        deps: Vec<(Uuid, PathBuf)>,
+        attached: Attached,
+    ) {
+        let mut checked = Vec::new();
+        for (src, filename) in deps {
+            let canonical = Self::canonicalize(&filename);
+            let Some(hash) = self.hash(&canonical) else {
+                return;
+            };
+            checked.push(Dependency {
+                src,
+                filename,
+                canonical,
+                hash,
+            });
+        }
+        self.entries.insert(
+            filename,
+            Entry {
+                deps: checked,
+                attached,
+            },
+        );
+    }
+
+    fn hash(&self, filename: &Path) -> Option<u64> {
+        let content = self.read(filename).ok()?;
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        Some(hasher.finish())
+    }
+
+    fn drop_dependents(&mut self, filename: &Path) {
+        self.entries
+            .retain(|_, entry| !entry.deps.iter().any(|dep| dep.canonical == filename));
+    }
+
+    fn canonicalize<P: AsRef<Path>>(filename: P) -> PathBuf {
+        fs::canonicalize(filename.as_ref()).unwrap_or_else(|_| filename.as_ref().to_path_buf())
+    }
+}
@@ -97,6 +97,7 @@ impl ErrorCode for E {
             Self::LexerError(..) => "00073",
 
             Self::Unlinked => "00074",
+            Self::MissedJoinOptions => "00075",
         }
     }
     fn src(&self) -> ErrorSource {
@@ -204,7 +205,8 @@ mod test {
                 EId::InvalidReturnValue => E::InvalidReturnValue,
 
                 EId::LexerError => E::LexerError(LexerError::InvalidNumber),
-                EId::Unlinked => E::Unlinked
+                EId::Unlinked => E::Unlinked,
+                EId::MissedJoinOptions => E::MissedJoinOptions
             }
         }
     }
@@ -199,6 +199,10 @@ pub enum E {
 
     #[error("Unlinked error: no tokens, no errors")]
     Unlinked,
+
+    /// Join
+    #[error("Missed options of join: join[limit, policy](...)")]
+    MissedJoinOptions,
 }
 
 impl From<LexerError> for E {
@@ -1,6 +1,8 @@
 mod ast;
+mod cache;
 mod error;
 
+pub use cache::{Attached, ParseCache, SharedParseCache};
 pub use error::E as ParserError;
 use error::*;
 mod conflict;
@@ -20,7 +22,7 @@ use diagnostics::*;
 use lexer::*;
 use std::{
     cell::{Cell, Ref, RefCell},
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::{self, Display},
     io,
     path::{Path, PathBuf},
@@ -41,6 +43,7 @@ pub struct Parser {
     end: usize,
     pos: Cell<usize>,
     resilience: bool,
+    cache: Option<SharedParseCache>,
 }
 
 impl Parser {
@@ -62,8 +65,26 @@ impl Parser {
             bindings: Rc::new(RefCell::new(HashMap::new())),
             end,
             resilience,
+            cache: None,
         }
     }
+    /// Creates a parser for in-memory content, which belongs to the given file. In comparison
+    /// with `unbound` such parser is able to resolve relative paths (`mod from`, `include from`)
+    /// because the location of the source is known.
+    pub fn buffered<S: AsRef<str>, P: AsRef<Path>>(
+        tokens: Vec<Token>,
+        src: &Uuid,
+        content: S,
+        filename: P,
+        resilience: bool,
+    ) -> Self {
+        let filename = std::fs::canonicalize(filename.as_ref())
+            .unwrap_or_else(|_| filename.as_ref().to_path_buf());
+        let mut parser = Self::unbound(tokens, src, content, resilience);
+        parser.cwd = filename.parent().map(|p| p.to_path_buf());
+        parser.filename = Some(filename);
+        parser
+    }
     pub fn new<P: AsRef<Path>>(filename: P, resilience: bool) -> Result<Self, E> {
         let (filename, cwd, tokens, src) = BoundLexer::new(filename.as_ref())?.inner();
         let end = tokens.len().saturating_sub(1);
@@ -78,10 +99,25 @@ impl Parser {
             cwd: Some(cwd),
             end,
             resilience,
+            cache: None,
         })
     }
     pub fn new_child<P: AsRef<Path>>(&self, filename: P) -> Result<Self, E> {
-        let (filename, cwd, tokens, src) = BoundLexer::new(filename.as_ref())?.inner();
+        let content = self.cache.as_ref().and_then(|cache| {
+            let filename = std::fs::canonicalize(filename.as_ref()).ok()?;
+            cache.lock().ok()?.read(filename).ok()
+        });
+        let (filename, cwd, tokens, src) = if let Some(content) = content {
+            let filename = filename.as_ref().to_path_buf();
+            let cwd = filename
+                .parent()
+                .map(|p| p.to_path_buf())
+                .unwrap_or_default();
+            let mut lx = Lexer::new(&content, 0);
+            (filename, cwd, lx.read()?.tokens, lx.uuid)
+        } else {
+            BoundLexer::new(filename.as_ref())?.inner()
+        };
         self.srcs.borrow_mut().add_file_src(&filename, &src)?;
         let end = tokens.len().saturating_sub(1);
         Ok(Self {
@@ -95,6 +131,7 @@ impl Parser {
             bindings: Rc::new(RefCell::new(HashMap::new())),
             end,
             resilience: self.resilience,
+            cache: self.cache.clone(),
         })
     }
 
@@ -102,6 +139,12 @@ impl Parser {
         self.resilience
     }
 
+    /// Sets cache of attached files. Parsed attached files will be stored in the cache and
+    /// reused while their content isn't changed.
+    pub fn set_cache(&mut self, cache: SharedParseCache) {
+        self.cache = Some(cache);
+    }
+
     pub fn from_node<N: GetFilename>(&self, node: &N) -> Result<Parser, E> {
         let mut filename = node.get_filename()?;
         if filename.is_relative() {
@@ -114,6 +157,10 @@ impl Parser {
     }
 
     pub fn from_file<P: AsRef<Path>>(&self, filename: P) -> Result<Parser, E> {
+        self.new_child(self.resolve_path(filename)?)
+    }
+
+    pub fn resolve_path<P: AsRef<Path>>(&self, filename: P) -> Result<PathBuf, E> {
         let mut filename = filename.as_ref().to_path_buf();
         if filename.is_relative() {
             filename = self.cwd.as_ref().ok_or(E::NoParentPath)?.join(filename);
@@ -121,7 +168,63 @@ impl Parser {
         if !filename.exists() {
             return Err(E::FileNotFound(filename.to_string_lossy().to_string()));
         }
-        self.new_child(filename)
+        Ok(filename)
+    }
+
+    /// Reads attached file (`mod from`, `include from`) with the given reader. If cache is
+    /// set and it has an entry of the file, the cached nodes are used instead.
+    pub fn read_attached<P, F>(
+        &self,
+        filename: P,
+        node: &LinkedNode,
+        reader: F,
+    ) -> Result<Attached, LinkedErr<E>>
+    where
+        P: AsRef<Path>,
+        F: FnOnce(&mut Parser) -> Result<Attached, LinkedErr<E>>,
+    {
+        let filename = self.resolve_path(filename).map_err(|err| err.link(node))?;
+        let Some(cache) = self.cache.as_ref() else {
+            return reader(&mut self.new_child(filename).map_err(|err| err.link(node))?);
+        };
+        let canonical =
+            std::fs::canonicalize(&filename).map_err(|err| E::IOError(err).link(node))?;
+        let cached = cache.lock().ok().and_then(|cache| cache.get(&canonical));
+        if let Some((deps, attached)) = cached {
+            let mut srcs = self.srcs.borrow_mut();
+            for (src, filename) in deps.iter() {
+                srcs.add_file_src(filename, src)
+                    .map_err(|err| E::IOError(err).link(node))?;
+            }
+            return Ok(attached);
+        }
+        let before = self
+            .srcs
+            .borrow()
+            .sources
+            .keys()
+            .copied()
+            .collect::<HashSet<Uuid>>();
+        let errs = self.errs.borrow().len();
+        let mut inner = self.new_child(filename).map_err(|err| err.link(node))?;
+        let attached = reader(&mut inner)?;
+        // Only files without errors are cached
+        if self.errs.borrow().len() == errs {
+            let mut deps = vec![(inner.src, canonical.clone())];
+            deps.extend(self.srcs.borrow().sources.iter().filter_map(|(src, cs)| {
+                if before.contains(src) || src == &inner.src {
+                    return None;
+                }
+                match cs {
+                    CodeSource::File(filename) => Some((*src, filename.to_owned())),
+                    CodeSource::Inline(..) => None,
+                }
+            }));
+            if let Ok(mut cache) = cache.lock() {
+                cache.insert(canonical, deps, attached.clone());
+            }
+        }
+        Ok(attached)
     }
 
     pub fn report_err<T: Display + ErrorCode>(&self, err: &LinkedErr<T>) -> Result<String, E> {
@@ -190,6 +293,42 @@ impl Parser {
         self.srcs.borrow().get_content(src.unwrap_or(&self.src))
     }
 
+    /// Returns the path of the file which is the source with the given uuid. For in-memory
+    /// root source returns the path of the file it belongs to (if it's known).
+    pub fn get_src_filename(&self, src: &Uuid) -> Option<PathBuf> {
+        if let Some(filename) = self.srcs.borrow().get_filename(src) {
+            return Some(filename);
+        }
+        if src == &self.src {
+            self.filename.clone()
+        } else {
+            None
+        }
+    }
+
+    /// Returns paths of all attached files
+    pub fn get_attached_files(&self) -> Vec<PathBuf> {
+        self.srcs
+            .borrow()
+            .sources
+            .iter()
+            .filter_map(|(src, cs)| match cs {
+                CodeSource::File(filename) if src != &self.src => Some(filename.to_owned()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns uuid of the source attached from the given file
+    pub fn get_src_by_filename<P: AsRef<Path>>(&self, filename: P) -> Option<Uuid> {
+        let filename = std::fs::canonicalize(filename.as_ref())
+            .unwrap_or_else(|_| filename.as_ref().to_path_buf());
+        if self.filename.as_ref() == Some(&filename) {
+            return Some(self.src);
+        }
+        self.srcs.borrow().get_src_by_filename(filename)
+    }
+
     fn add_binding(&self, from: usize, to: usize, uuid: &Uuid) {
         let mut bindings = self.bindings.borrow_mut();
         if bindings.contains_key(uuid) {
@@ -211,6 +350,7 @@ impl Parser {
             cwd: self.cwd.clone(),
             end: to.min(self.tokens.borrow().len() - 1),
             resilience: self.resilience,
+            cache: self.cache.clone(),
         }
     }
 
@@ -0,0 +1,114 @@
+use crate::*;
+
+/// Declared argument of function or task
+#[derive(Debug, Clone, Copy)]
+pub struct ArgSig<'a> {
+    /// Name of argument. Embedded functions might have arguments without names; such
+    /// arguments can be given only by position.
+    pub name: Option<&'a str>,
+    pub ty: &'a Ty,
+    /// Default value of argument (`ArgumentAssignedValue` node)
+    pub default: Option<&'a LinkedNode>,
+}
+
+/// Reason of mismatch between given and declared arguments
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgsMismatch {
+    /// More positional arguments are given than declared
+    TooMany,
+    /// Indexes of declared arguments, which are given neither by position nor by name and
+    /// don't have default value
+    Missed(Vec<usize>),
+    /// Named argument doesn't match any declared argument
+    Unknown(String),
+    /// Argument is given by position and by name or by name multiple times
+    Duplicated(String),
+    /// Positional argument follows named argument
+    PositionalAfterNamed,
+}
+
+/// Maps given arguments to declared arguments. Each given argument is a pair of optional
+/// name and value. Positional arguments fill declared arguments in order; named arguments
+/// fill declared arguments with the same name. Repeated argument takes all remaining
+/// positional arguments and cannot be given by name.
+///
+/// Returns values of each declared argument:
+/// * empty list - argument isn't given and default value should be used (or repeated
+///   argument without values);
+/// * single value - regular argument;
+/// * any number of values - repeated argument.
+pub fn bind_args<T>(
+    sigs: &[ArgSig],
+    given: Vec<(Option<String>, T)>,
+) -> Result<Vec<Vec<T>>, ArgsMismatch> {
+    let mut bound: Vec<Option<Vec<T>>> = sigs.iter().map(|_| None).collect();
+    let mut named = false;
+    let mut idx = 0;
+    for (name, value) in given.into_iter() {
+        if let Some(name) = name {
+            named = true;
+            let Some(pos) = sigs.iter().position(|sig| {
+                sig.name == Some(name.as_str()) && !matches!(sig.ty, Ty::Repeated(..))
+            }) else {
+                return Err(ArgsMismatch::Unknown(name));
+            };
+            if bound[pos].is_some() {
+                return Err(ArgsMismatch::Duplicated(name));
+            }
+            bound[pos] = Some(vec![value]);
+        } else {
+            if named {
+                return Err(ArgsMismatch::PositionalAfterNamed);
+            }
+            let Some(sig) = sigs.get(idx) else {
+                return Err(ArgsMismatch::TooMany);
+            };
+            if matches!(sig.ty, Ty::Repeated(..)) {
+                bound[idx].get_or_insert_with(Vec::new).push(value);
+            } else {
+                bound[idx] = Some(vec![value]);
+                idx += 1;
+            }
+        }
+    }
+    let missed = bound
+        .iter()
+        .zip(sigs.iter())
+        .enumerate()
+        .filter(|(_, (vl, sig))| {
+            vl.is_none() && sig.default.is_none() && !matches!(sig.ty, Ty::Repeated(..))
+        })
+        .map(|(idx, _)| idx)
+        .collect::<Vec<usize>>();
+    if !missed.is_empty() {
+        return Err(ArgsMismatch::Missed(missed));
+    }
+    Ok(bound.into_iter().map(Option::unwrap_or_default).collect())
+}
+
+/// Checks whether a value of type `ty` can be given into argument of type `arg_ty`. In
+/// addition to compatible types, `str` and `path` are accepted as values of each other.
+fn accepted(arg_ty: &DeterminedTy, ty: &DeterminedTy) -> bool {
+    match (arg_ty, ty) {
+        (DeterminedTy::PathBuf, DeterminedTy::Str) | (DeterminedTy::Str, DeterminedTy::PathBuf) => {
+            true
+        }
+        (DeterminedTy::Vec(Some(arg_ty)), DeterminedTy::Vec(Some(ty))) => accepted(arg_ty, ty),
+        _ => arg_ty.compatible(ty),
+    }
+}
+
+impl ArgSig<'_> {
+    /// Checks whether a value of the given type can be used as a value of argument. For
+    /// repeated argument checks a single value.
+    pub fn accepts(&self, ty: &DeterminedTy) -> bool {
+        match self.ty {
+            Ty::Determined(arg_ty)
+            | Ty::Variants(arg_ty)
+            | Ty::Optional(arg_ty)
+            | Ty::Repeated(arg_ty) => accepted(arg_ty, ty),
+            Ty::OneOf(arg_tys) => arg_tys.iter().any(|arg_ty| accepted(arg_ty, ty)),
+            Ty::Undefined | Ty::Indeterminate => false,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use crate::*;
+use std::future::Future;
+use tokio::task::{JoinError, JoinHandle};
+
+/// Aborts the task as soon as the handle is dropped
+struct AbortOnDrop<T>(JoinHandle<T>);
+
+impl<T> Drop for AbortOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Executes the body of function or closure as a separate task to start it with a fresh
+/// stack; otherwise recursion overflows the stack long before the limit of depth is
+/// reached. The task is bound to the caller: if the caller is dropped (for example,
+/// because of cancelled `join`), the body is aborted as well.
+pub(crate) async fn exec_body<F>(body: F) -> Result<F::Output, JoinError>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let mut handle = AbortOnDrop(spawn(body));
+    (&mut handle.0).await
+}
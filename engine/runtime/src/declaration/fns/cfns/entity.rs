@@ -68,6 +68,13 @@ impl ClosureFnEntity {
                 caller.into(),
             ));
         };
+        rt.limits()
+            .time()
+            .map_err(|err| LinkedErr::by_link(err, caller.into()))?;
+        let _call = cx
+            .calls()
+            .enter(rt.limits().depth())
+            .map_err(|err| LinkedErr::by_link(err, caller.into()))?;
         if let Err(err) = cx.location().enter(&self.uuid).await {
             return Err(LinkedErr::by_link(err, link.into()));
         }
@@ -102,7 +109,10 @@ impl ClosureFnEntity {
             }
             return Err(err);
         }
-        let result = exec(rt.clone(), cx.clone()).await;
+        let result = match exec_body(exec(rt.clone(), cx.clone())).await {
+            Ok(result) => result,
+            Err(err) => Err(LinkedErr::by_link(err.into(), link.into())),
+        };
         if let Err(err) = cx.location().leave().await {
             return Err(LinkedErr::by_link(err, link.into()));
         }
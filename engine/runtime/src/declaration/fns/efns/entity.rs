@@ -10,6 +10,16 @@ pub struct FnArgDesc {
     pub ty: Ty,
 }
 
+impl FnArgDesc {
+    pub fn sig(&self) -> ArgSig<'_> {
+        ArgSig {
+            name: self.name.as_deref(),
+            ty: &self.ty,
+            default: None,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct EmbeddedFnEntity {
     pub uuid: Uuid,
@@ -68,7 +78,11 @@ impl EmbeddedFnEntity {
     }
     pub fn compatible(&self, incomes: &[&Ty]) -> bool {
         FnEntity::args_compatible(
-            &self.args.iter().map(|arg| &arg.ty).collect::<Vec<&Ty>>(),
+            &self
+                .args
+                .iter()
+                .map(|arg| arg.sig())
+                .collect::<Vec<ArgSig>>(),
             incomes,
         )
     }
@@ -71,47 +71,24 @@ impl FnEntity<'_> {
         }
     }
 
-    pub fn args_compatible(args: &[&Ty], incomes: &[&Ty]) -> bool {
-        let mut incomes = incomes.iter();
-        let mut repeated = false;
-        for arg_ty in args.iter() {
-            if repeated {
-                return false;
-            }
-            match arg_ty {
-                Ty::Determined(..) | Ty::OneOf(..) | Ty::Variants(..) | Ty::Optional(..) => {
-                    let Some(in_ty) = incomes.next() else {
-                        return false;
-                    };
-                    let Some(in_ty) = in_ty.determined() else {
-                        return false;
-                    };
-                    if !match arg_ty {
-                        Ty::Determined(arg_ty) | Ty::Variants(arg_ty) | Ty::Optional(arg_ty) => {
-                            arg_ty.compatible(in_ty)
-                        }
-                        Ty::OneOf(arg_tys) => arg_tys.iter().any(|arg_ty| arg_ty.compatible(in_ty)),
-                        _ => true,
-                    } {
-                        return false;
-                    }
-                }
-                Ty::Repeated(arg_ty) => {
-                    repeated = true;
-                    for in_ty in incomes.by_ref() {
-                        let Some(in_ty) = in_ty.determined() else {
-                            return false;
-                        };
-                        if !arg_ty.compatible(in_ty) {
-                            return false;
-                        }
-                    }
-                }
-                Ty::Undefined | Ty::Indeterminate => {
-                    return false;
-                }
-            }
+    /// Returns declared arguments of function
+    pub fn args_sigs(&self) -> Vec<ArgSig<'_>> {
+        match self {
+            Self::UFn(en) => en.args.iter().map(|arg| arg.sig()).collect(),
+            Self::EFn(en) => en.args.iter().map(|arg| arg.sig()).collect(),
+            Self::CFn(en) => en.args.iter().map(|arg| arg.sig()).collect(),
         }
-        true
+    }
+
+    /// Checks whether given positional arguments match declared arguments. Arguments with
+    /// default values can be omitted.
+    pub fn args_compatible(sigs: &[ArgSig], incomes: &[&Ty]) -> bool {
+        let Ok(bound) = bind_args(sigs, incomes.iter().map(|ty| (None, *ty)).collect()) else {
+            return false;
+        };
+        sigs.iter().zip(bound).all(|(sig, tys)| {
+            tys.into_iter()
+                .all(|ty| ty.determined().is_some_and(|ty| sig.accepts(ty)))
+        })
     }
 }
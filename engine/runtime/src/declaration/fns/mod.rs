@@ -1,4 +1,6 @@
 mod arg;
+mod binding;
+mod body;
 mod cfns;
 mod efns;
 mod entity;
@@ -6,6 +8,8 @@ mod store;
 mod ufns;
 
 pub use arg::*;
+pub use binding::*;
+pub(crate) use body::*;
 pub use cfns::*;
 pub use efns::*;
 pub use entity::*;
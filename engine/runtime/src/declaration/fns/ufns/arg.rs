@@ -4,5 +4,17 @@ use crate::*;
 pub struct UserFnArgDeclaration {
     pub ty: Ty,
     pub ident: String,
+    /// Default value of argument (`ArgumentAssignedValue` node)
+    pub default: Option<LinkedNode>,
     pub link: SrcLink,
 }
+
+impl UserFnArgDeclaration {
+    pub fn sig(&self) -> ArgSig<'_> {
+        ArgSig {
+            name: Some(&self.ident),
+            ty: &self.ty,
+            default: self.default.as_ref(),
+        }
+    }
+}
@@ -85,6 +85,13 @@ impl UserFnEntity {
                 caller.into(),
             ));
         };
+        rt.limits()
+            .time()
+            .map_err(|err| LinkedErr::by_link(err, caller.into()))?;
+        let _call = cx
+            .calls()
+            .enter(rt.limits().depth())
+            .map_err(|err| LinkedErr::by_link(err, caller.into()))?;
         if let Err(err) = cx.location().enter(&self.uuid).await {
             return Err(LinkedErr::by_link(err, link.into()));
         }
@@ -131,7 +138,10 @@ impl UserFnEntity {
             }
             return Err(err);
         }
-        let result = exec(rt.clone(), cx.clone()).await;
+        let result = match exec_body(exec(rt.clone(), cx.clone())).await {
+            Ok(result) => result,
+            Err(err) => Err(LinkedErr::by_link(err.into(), link.into())),
+        };
         if let Err(err) = cx.location().leave().await {
             return Err(LinkedErr::by_link(err, link.into()));
         }
@@ -139,7 +149,11 @@ impl UserFnEntity {
     }
     pub fn compatible(&self, incomes: &[&Ty]) -> bool {
         FnEntity::args_compatible(
-            &self.args.iter().map(|arg| &arg.ty).collect::<Vec<&Ty>>(),
+            &self
+                .args
+                .iter()
+                .map(|arg| arg.sig())
+                .collect::<Vec<ArgSig>>(),
             incomes,
         )
     }
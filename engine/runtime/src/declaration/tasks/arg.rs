@@ -4,5 +4,20 @@ use crate::*;
 pub struct TaskArgDeclaration {
     pub ty: Ty,
     pub ident: String,
+    /// Default value of argument (`ArgumentAssignedValue` node)
+    pub default: Option<LinkedNode>,
+    /// Allowed values of argument declared with variants (`"dev" | "prod"`). Empty if
+    /// any value of type is allowed.
+    pub variants: Vec<RtValue>,
     pub link: SrcLink,
 }
+
+impl TaskArgDeclaration {
+    pub fn sig(&self) -> ArgSig<'_> {
+        ArgSig {
+            name: Some(&self.ident),
+            ty: &self.ty,
+            default: self.default.as_ref(),
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use crate::*;
+
+/// Returns type as it's written in the declaration of task
+fn ty_to_str(ty: &Ty) -> String {
+    match ty {
+        Ty::Determined(ty) | Ty::Variants(ty) | Ty::Optional(ty) => determined_ty_to_str(ty),
+        Ty::Repeated(ty) => format!("...{}", determined_ty_to_str(ty)),
+        Ty::OneOf(tys) => tys
+            .iter()
+            .map(determined_ty_to_str)
+            .collect::<Vec<String>>()
+            .join(" | "),
+        Ty::Undefined | Ty::Indeterminate => ty.to_string(),
+    }
+}
+
+fn determined_ty_to_str(ty: &DeterminedTy) -> String {
+    match ty {
+        DeterminedTy::Num => String::from("num"),
+        DeterminedTy::Bool => String::from("bool"),
+        DeterminedTy::Str => String::from("str"),
+        DeterminedTy::PathBuf => String::from("path"),
+        DeterminedTy::Vec(Some(ty)) => format!("Vec<{}>", determined_ty_to_str(ty)),
+        DeterminedTy::Vec(None) => String::from("Vec<str>"),
+        _ => ty.to_string(),
+    }
+}
+
+/// Converts a value of command line into a value of the given type
+fn convert(ty: &DeterminedTy, value: &str) -> Option<RtValue> {
+    match ty {
+        DeterminedTy::Num => value.trim().parse::<f64>().ok().map(RtValue::Num),
+        DeterminedTy::Bool => match value.trim() {
+            "true" => Some(RtValue::Bool(true)),
+            "false" => Some(RtValue::Bool(false)),
+            _ => None,
+        },
+        DeterminedTy::Str | DeterminedTy::Any => Some(RtValue::Str(value.to_owned())),
+        DeterminedTy::PathBuf => Some(RtValue::PathBuf(PathBuf::from(value))),
+        DeterminedTy::Vec(ty) => Some(RtValue::Vec(vec![convert(
+            ty.as_deref().unwrap_or(&DeterminedTy::Str),
+            value,
+        )?])),
+        _ => None,
+    }
+}
+
+impl TaskArgDeclaration {
+    /// Returns declaration of argument as it's written in the declaration of task
+    pub fn signature(&self) -> String {
+        if let Some(default) = self.default.as_ref() {
+            format!("{}: {} {default}", self.ident, self.expected())
+        } else {
+            format!("{}: {}", self.ident, self.expected())
+        }
+    }
+
+    /// Returns default value of argument as it's written in the declaration of task
+    pub fn default_value(&self) -> Option<String> {
+        let default = self.default.as_ref()?;
+        if let Node::Statement(Statement::ArgumentAssignedValue(vl)) = default.get_node() {
+            Some(vl.node.to_string())
+        } else {
+            Some(default.to_string())
+        }
+    }
+
+    /// Returns type or variants of argument
+    pub fn expected(&self) -> String {
+        if self.variants.is_empty() {
+            ty_to_str(&self.ty)
+        } else {
+            self.variants
+                .iter()
+                .map(|vl| match vl {
+                    RtValue::Str(vl) => format!("\"{vl}\""),
+                    RtValue::Num(vl) => vl.to_string(),
+                    vl => vl.to_string(),
+                })
+                .collect::<Vec<String>>()
+                .join(" | ")
+        }
+    }
+
+    /// Converts values of command line into value of argument. Multiple values are
+    /// allowed only for vectors. `None` stands for a flag without value (`--name`),
+    /// which is allowed only for boolean argument.
+    fn cli_value(&self, values: Vec<Option<String>>) -> Result<RtValue, E> {
+        let err = |value: &str| {
+            E::InvalidCliArgumentValue(self.ident.clone(), value.to_owned(), self.expected())
+        };
+        let converted = match &self.ty {
+            Ty::Determined(DeterminedTy::Vec(inner)) => {
+                let inner = inner.as_deref().unwrap_or(&DeterminedTy::Str);
+                let mut items = Vec::new();
+                for value in values.iter() {
+                    let value = value
+                        .as_deref()
+                        .ok_or(E::MissedCliArgumentValue(self.ident.clone()))?;
+                    items.push(convert(inner, value).ok_or_else(|| err(value))?);
+                }
+                RtValue::Vec(items)
+            }
+            ty => {
+                let [value] = values.as_slice() else {
+                    return Err(E::DuplicatedArgument(self.ident.clone()));
+                };
+                let Some(value) = value.as_deref() else {
+                    return if self.ty.compatible(&DeterminedTy::Bool.into()) {
+                        Ok(RtValue::Bool(true))
+                    } else {
+                        Err(E::MissedCliArgumentValue(self.ident.clone()))
+                    };
+                };
+                match ty {
+                    Ty::Determined(ty) | Ty::Variants(ty) | Ty::Optional(ty) | Ty::Repeated(ty) => {
+                        convert(ty, value)
+                    }
+                    // Strings accept any value, so they are checked last
+                    Ty::OneOf(tys) => {
+                        tys.iter()
+                            .filter(|ty| !matches!(ty, DeterminedTy::Str | DeterminedTy::PathBuf))
+                            .chain(tys.iter().filter(|ty| {
+                                matches!(ty, DeterminedTy::Str | DeterminedTy::PathBuf)
+                            }))
+                            .find_map(|ty| convert(ty, value))
+                    }
+                    Ty::Undefined | Ty::Indeterminate => None,
+                }
+                .filter(|vl| self.variants.is_empty() || self.variants.contains(vl))
+                .ok_or_else(|| err(value))?
+            }
+        };
+        Ok(converted)
+    }
+}
+
+impl TaskEntity {
+    /// Returns declaration of task as it's written in the scenario
+    pub fn signature(&self) -> String {
+        format!(
+            "{}({})",
+            self.name,
+            self.args
+                .iter()
+                .map(|arg| arg.signature())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+
+    /// Converts arguments of command line into arguments of task according to declared
+    /// types. Argument in the form `--name=value` is given by name; `--name` without value
+    /// gives `true` to boolean argument. Values of the same argument of type `Vec<..>` given
+    /// multiple times by name are collected into one vector. Arguments, which cannot be
+    /// matched with declared arguments, are kept as strings; binding reports them.
+    pub fn cli_args(&self, args: Vec<String>) -> Result<Vec<FnArgValue>, E> {
+        let mut given: Vec<(Option<String>, Vec<Option<String>>)> = Vec::new();
+        for arg in args.into_iter() {
+            let named = arg.strip_prefix("--").and_then(|arg| {
+                let (name, value) = match arg.split_once('=') {
+                    Some((name, value)) => (name, Some(value.to_owned())),
+                    None => (arg, None),
+                };
+                (!name.is_empty()).then(|| (name.to_owned(), value))
+            });
+            let Some((name, value)) = named else {
+                given.push((None, vec![Some(arg)]));
+                continue;
+            };
+            let is_vec = self.args.iter().any(|decl| {
+                decl.ident == name && matches!(decl.ty, Ty::Determined(DeterminedTy::Vec(..)))
+            });
+            if let Some((_, values)) = given
+                .iter_mut()
+                .find(|(n, _)| is_vec && n.as_deref() == Some(name.as_str()))
+            {
+                values.push(value);
+            } else {
+                given.push((Some(name), vec![value]));
+            }
+        }
+        let mut position = 0;
+        let mut converted = Vec::new();
+        for (name, values) in given.into_iter() {
+            let decl = if let Some(name) = name.as_ref() {
+                self.args.iter().find(|decl| &decl.ident == name)
+            } else {
+                let decl = self.args.get(position);
+                if !matches!(decl.map(|decl| &decl.ty), Some(Ty::Repeated(..))) {
+                    position += 1;
+                }
+                decl
+            };
+            let value = if let Some(decl) = decl {
+                decl.cli_value(values)?
+            } else {
+                RtValue::Str(
+                    values
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<String>>()
+                        .join(" "),
+                )
+            };
+            let value = if let Some(name) = name {
+                RtValue::NamedArgumentValue(name, Box::new(value))
+            } else {
+                value
+            };
+            converted.push(FnArgValue::new(value, SrcLink::default()));
+        }
+        Ok(converted)
+    }
+}
@@ -83,6 +83,16 @@ impl TaskEntity {
             .child(self.uuid, self.name.clone())
             .await
             .map_err(|err| LinkedErr::by_link(err, caller.into()))?;
+        let inherited = cx
+            .output()
+            .get()
+            .await
+            .map_err(|err| LinkedErr::by_link(err, caller.into()))?;
+        task_cx
+            .output()
+            .init(&self.master.uuid, inherited)
+            .await
+            .map_err(|err| LinkedErr::by_link(err, caller.into()))?;
         if let Err(err) = task_cx.location().enter(&self.uuid).await {
             return Err(LinkedErr::by_link(err, link.into()));
         }
@@ -118,6 +128,32 @@ impl TaskEntity {
             return Err(err);
         }
         let result = exec(rt.clone(), task_cx.clone()).await;
+        // Jobs are cancelled on timeout; the task reports the reason instead of own result
+        let result = match rt.limits().time() {
+            Err(err) if task_cx.job.cancel.is_cancelled() => {
+                Err(LinkedErr::by_link(err, link.into()))
+            }
+            _ => result,
+        };
+        task_cx.job.finish(match &result {
+            _ if task_cx.job.cancel.is_cancelled() => JobStatus::Cancelled,
+            Ok(RtValue::ExecuteResult(result)) if result.is_failed() => JobStatus::Failed,
+            Ok(..) => JobStatus::Success,
+            Err(..) => JobStatus::Failed,
+        });
+        // Locks which weren't released by task (because of error, cancellation or just
+        // missed release) would block other jobs forever
+        let released = rt
+            .locks()
+            .release_all(&task_cx.job)
+            .await
+            .map_err(|err| LinkedErr::by_link(err, link.into()))?;
+        if !released.is_empty() {
+            task_cx.job.journal.warn(format!(
+                "Locks have been released automatically: {}",
+                released.join(", ")
+            ));
+        }
         if let Err(err) = task_cx.location().leave().await {
             return Err(LinkedErr::by_link(err, link.into()));
         }
@@ -135,6 +171,11 @@ impl TaskEntity {
         self.args.iter().map(|arg| &arg.ty).collect::<Vec<&Ty>>()
     }
 
+    /// Returns declared arguments of task
+    pub fn args_sigs(&self) -> Vec<ArgSig<'_>> {
+        self.args.iter().map(|arg| arg.sig()).collect()
+    }
+
     pub fn fullname(&self) -> String {
         format!("{}:{}", self.master.name, self.name)
     }
@@ -1,4 +1,5 @@
 mod arg;
+mod cli;
 mod entity;
 mod parent;
 mod store;
@@ -34,7 +34,7 @@ impl Tasks {
         let name = self.link(name, caller)?;
         self.table.get(&name)
     }
-    pub(crate) fn lookup_by_caller(&self, caller: &Uuid) -> Option<&TaskEntity> {
+    pub fn lookup_by_caller(&self, caller: &Uuid) -> Option<&TaskEntity> {
         let name = self.links.get(caller)?;
         self.table.get(name)
     }
@@ -54,6 +54,12 @@ impl Tasks {
         };
         entity.execute(rt, cx, args, caller).await
     }
+    /// Returns task of component by name of task
+    pub fn find_by_name<S: AsRef<str>>(&self, master: &Uuid, name: S) -> Option<&TaskEntity> {
+        self.table
+            .values()
+            .find(|en| &en.master.uuid == master && en.name == name.as_ref())
+    }
     pub async fn execute_by_name<S: AsRef<str>>(
         &self,
         master: &Uuid,
@@ -63,11 +69,7 @@ impl Tasks {
         args: Vec<FnArgValue>,
         caller: &SrcLink,
     ) -> Result<RtValue, LinkedErr<E>> {
-        let Some(entity) = self
-            .table
-            .values()
-            .find(|en| &en.master.uuid == master && en.name == name.as_ref())
-        else {
+        let Some(entity) = self.find_by_name(master, name.as_ref()) else {
             return Err(LinkedErr::by_link(
                 E::TaskNotFound(name.as_ref().to_string(), master.to_string()),
                 caller.into(),
@@ -70,36 +70,68 @@ impl ErrorCode for E {
 
             Self::NoBreakSignalFor(..) => "00057",
             Self::BreakSignalAlreadyExist(..) => "00058",
-            Self::LoopAlreadyExist(..) => "00059",
-            Self::NoOpenLoopsToBreak => "00060",
-            Self::NoOpenLoopsToClose => "00061",
+            Self::NoOpenLoopsToBreak => "00059",
+            Self::NoOpenLoopsToClose => "00060",
 
-            Self::ReturnCXAlreadyExist(..) => "00062",
-            Self::NoOpenReturnCXToBreak => "00063",
-            Self::NoOpenReturnCXsToClose => "00064",
-            Self::ReturnValueAlreadyExist(..) => "00065",
+            Self::NoOpenReturnCXToBreak => "00061",
+            Self::NoOpenReturnCXsToClose => "00062",
+            Self::ReturnValueAlreadyExist(..) => "00063",
 
-            Self::RenderTemplateErr(..) => "00066",
-            Self::NoProgressForTask(..) => "00067",
+            Self::RenderTemplateErr(..) => "00064",
+            Self::NoProgressForTask(..) => "00065",
 
-            Self::SpawnSetup(..) => "00068",
-            Self::SpawnError(..) => "00069",
-            Self::SpawnFailed(..) => "00070",
+            Self::SpawnSetup(..) => "00066",
+            Self::SpawnError(..) => "00067",
+            Self::SpawnFailed(..) => "00068",
 
-            Self::Timestamp => "00071",
+            Self::Timestamp => "00069",
 
-            Self::JobAlreadyExists(..) => "00072",
-            Self::JobDoesNotExist(..) => "00073",
+            Self::JobAlreadyExists(..) => "00070",
+            Self::JobDoesNotExist(..) => "00071",
 
-            Self::JoinError(..) => "00074",
-            Self::FailToFindJoinResult(..) => "00075",
-            Self::SomeNodesHadSameUuid => "00076",
+            Self::JoinError(..) => "00072",
+            Self::FailToFindJoinResult(..) => "00073",
+            Self::SomeNodesHadSameUuid => "00074",
 
-            Self::MultipleSignalEmit(..) => "00077",
+            Self::MultipleSignalEmit(..) => "00075",
 
-            Self::Other(..) => "00078",
+            Self::Other(..) => "00076",
 
-            Self::Journal(..) => "00079",
+            Self::Journal(..) => "00077",
+
+            Self::IterationsLimit(..) => "00078",
+            Self::DepthLimit(..) => "00079",
+            Self::TimeLimit(..) => "00080",
+
+            Self::NoOpenLoopsToContinue => "00081",
+            Self::ContinueSignalAlreadyExist(..) => "00082",
+
+            Self::MissedArgument(..) => "00083",
+            Self::UnknownArgument(..) => "00084",
+            Self::DuplicatedArgument(..) => "00085",
+            Self::PositionalArgAfterNamed => "00086",
+            Self::TooManyArguments(..) => "00087",
+
+            Self::InvalidCliArgumentValue(..) => "00088",
+            Self::MissedCliArgumentValue(..) => "00089",
+            Self::InvalidTaskCliArguments(..) => "00090",
+
+            Self::InvalidRegex(..) => "00091",
+
+            Self::NotPathPrefix(..) => "00092",
+            Self::NoRelativePath(..) => "00093",
+            Self::InvalidGlobPattern(..) => "00094",
+            Self::NotDirectory(..) => "00095",
+            Self::InvalidOutputMode(..) => "00096",
+            Self::JobSlot(..) => "00097",
+            Self::InvalidJoinLimit(..) => "00098",
+            Self::Deadlock(..) => "00099",
+            Self::LockNotHeld(..) => "00100",
+            Self::InvalidLockCapacity(..) => "00101",
+            Self::LockWaitCancelled(..) => "00102",
+            Self::InvalidJoinPolicy(..) => "00103",
+            Self::JoinFailed(..) => "00104",
+            Self::InvalidProgressMode(..) => "00105",
         }
     }
     fn src(&self) -> ErrorSource {
@@ -109,7 +141,7 @@ impl ErrorCode for E {
 
 #[cfg(test)]
 mod test {
-    use std::{collections::HashSet, io};
+    use std::{collections::HashSet, io, time::Duration};
 
     use crate::*;
 
@@ -182,11 +214,9 @@ mod test {
 
                 EId::NoBreakSignalFor => E::NoBreakSignalFor(Uuid::new_v4()),
                 EId::BreakSignalAlreadyExist => E::BreakSignalAlreadyExist(Uuid::new_v4()),
-                EId::LoopAlreadyExist => E::LoopAlreadyExist(Uuid::new_v4()),
                 EId::NoOpenLoopsToBreak => E::NoOpenLoopsToBreak,
                 EId::NoOpenLoopsToClose => E::NoOpenLoopsToClose,
 
-                EId::ReturnCXAlreadyExist => E::ReturnCXAlreadyExist(Uuid::new_v4()),
                 EId::NoOpenReturnCXToBreak => E::NoOpenReturnCXToBreak,
                 EId::NoOpenReturnCXsToClose => E::NoOpenReturnCXsToClose,
                 EId::ReturnValueAlreadyExist => E::ReturnValueAlreadyExist(Uuid::new_v4()),
@@ -212,6 +242,44 @@ mod test {
                 EId::Other => E::Other(String::new()),
 
                 EId::Journal => E::Journal(String::new()),
+
+                EId::IterationsLimit => E::IterationsLimit(0),
+                EId::DepthLimit => E::DepthLimit(0),
+                EId::TimeLimit => E::TimeLimit(Duration::default()),
+
+                EId::NoOpenLoopsToContinue => E::NoOpenLoopsToContinue,
+                EId::ContinueSignalAlreadyExist => E::ContinueSignalAlreadyExist(Uuid::new_v4()),
+
+                EId::MissedArgument => E::MissedArgument(String::new()),
+                EId::UnknownArgument => E::UnknownArgument(String::new()),
+                EId::DuplicatedArgument => E::DuplicatedArgument(String::new()),
+                EId::PositionalArgAfterNamed => E::PositionalArgAfterNamed,
+                EId::TooManyArguments => E::TooManyArguments(0, 0),
+
+                EId::InvalidCliArgumentValue => {
+                    E::InvalidCliArgumentValue(String::new(), String::new(), String::new())
+                }
+                EId::MissedCliArgumentValue => E::MissedCliArgumentValue(String::new()),
+                EId::InvalidTaskCliArguments => {
+                    E::InvalidTaskCliArguments(String::new(), String::new())
+                }
+
+                EId::InvalidRegex => E::InvalidRegex(String::new(), String::new()),
+
+                EId::NotPathPrefix => E::NotPathPrefix(String::new(), String::new()),
+                EId::NoRelativePath => E::NoRelativePath(String::new(), String::new()),
+                EId::InvalidGlobPattern => E::InvalidGlobPattern(String::new(), String::new()),
+                EId::NotDirectory => E::NotDirectory(String::new()),
+                EId::InvalidOutputMode => E::InvalidOutputMode(String::new()),
+                EId::JobSlot => E::JobSlot(String::new()),
+                EId::InvalidJoinLimit => E::InvalidJoinLimit(String::new()),
+                EId::Deadlock => E::Deadlock(String::new()),
+                EId::LockNotHeld => E::LockNotHeld(String::new()),
+                EId::InvalidLockCapacity => E::InvalidLockCapacity(String::new()),
+                EId::LockWaitCancelled => E::LockWaitCancelled(String::new()),
+                EId::InvalidJoinPolicy => E::InvalidJoinPolicy(String::new()),
+                EId::JoinFailed => E::JoinFailed(0, String::new()),
+                EId::InvalidProgressMode => E::InvalidProgressMode(String::new()),
             }
         }
     }
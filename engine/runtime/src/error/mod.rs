@@ -2,7 +2,10 @@ mod codes;
 
 use crate::*;
 use enum_ids::enum_ids;
-use std::{io, time::SystemTimeError};
+use std::{
+    io,
+    time::{Duration, SystemTimeError},
+};
 use thiserror::Error;
 use tokio::task::JoinError;
 
@@ -133,15 +136,11 @@ pub enum E {
     NoBreakSignalFor(Uuid),
     #[error("Break signal for {0} already exist")]
     BreakSignalAlreadyExist(Uuid),
-    #[error("Loop {0} already exist")]
-    LoopAlreadyExist(Uuid),
     #[error("No open loops to break")]
     NoOpenLoopsToBreak,
     #[error("No open loops to close")]
     NoOpenLoopsToClose,
 
-    #[error("Return context {0} already exist")]
-    ReturnCXAlreadyExist(Uuid),
     #[error("No open return contexts to break")]
     NoOpenReturnCXToBreak,
     #[error("No open return contexts to close")]
@@ -184,6 +183,70 @@ pub enum E {
 
     #[error("Journal: ")]
     Journal(String),
+
+    #[error("Loop has exceeded the limit of {0} iterations")]
+    IterationsLimit(usize),
+    #[error("Calls have exceeded the limit of depth {0}")]
+    DepthLimit(usize),
+    #[error("Execution has exceeded the time limit of {0:?}")]
+    TimeLimit(Duration),
+
+    #[error("No open loops to continue")]
+    NoOpenLoopsToContinue,
+    #[error("Continue signal for {0} already exist")]
+    ContinueSignalAlreadyExist(Uuid),
+
+    #[error("Argument \"{0}\" isn't given and doesn't have default value")]
+    MissedArgument(String),
+    #[error("Unknown argument \"{0}\"")]
+    UnknownArgument(String),
+    #[error("Argument \"{0}\" is given multiple times")]
+    DuplicatedArgument(String),
+    #[error("Positional argument cannot follow named argument")]
+    PositionalArgAfterNamed,
+    #[error("Too many arguments: expected {0}, given {1}")]
+    TooManyArguments(usize, usize),
+
+    #[error("Invalid value \"{1}\" of argument \"{0}\"; expected: {2}")]
+    InvalidCliArgumentValue(String, String, String),
+    #[error("Argument \"{0}\" requires value: --{0}=value")]
+    MissedCliArgumentValue(String),
+    #[error("{0}\nTask signature: {1}")]
+    InvalidTaskCliArguments(String, String),
+
+    #[error("Invalid regular expression \"{0}\": {1}")]
+    InvalidRegex(String, String),
+
+    #[error("\"{0}\" isn't a prefix of \"{1}\"")]
+    NotPathPrefix(String, String),
+    #[error("Fail to get path \"{0}\" relative to \"{1}\"")]
+    NoRelativePath(String, String),
+    #[error("Invalid glob pattern \"{0}\": {1}")]
+    InvalidGlobPattern(String, String),
+    #[error("\"{0}\" isn't a directory")]
+    NotDirectory(String),
+    #[error("Invalid output mode \"{0}\"; expected stream, inherit, interactive, capture, silent, tee:<path> or tail:<lines>")]
+    InvalidOutputMode(String),
+    #[error("Fail to get job slot: {0}")]
+    JobSlot(String),
+    #[error("Limit of join should be a number greater than 0; given: {0}")]
+    InvalidJoinLimit(String),
+    #[error("Deadlock on lock \"{0}\": lock is held by a job, which waits for the current job")]
+    Deadlock(String),
+    #[error("Lock \"{0}\" isn't held by the current job")]
+    LockNotHeld(String),
+    #[error("Capacity of lock \"{0}\" should be greater than 0")]
+    InvalidLockCapacity(String),
+    #[error("Waiting for lock \"{0}\" has been cancelled")]
+    LockWaitCancelled(String),
+    #[error(
+        "Invalid policy of join \"{0}\"; expected fail-fast, wait-all, collect or allow:<number>"
+    )]
+    InvalidJoinPolicy(String),
+    #[error("{0} branch(es) of join failed:\n{1}")]
+    JoinFailed(usize, String),
+    #[error("Invalid progress mode \"{0}\"; expected bars, plain, quiet or github")]
+    InvalidProgressMode(String),
 }
 
 impl From<indicatif::style::TemplateError> for E {
@@ -228,6 +291,12 @@ impl<T> From<tokio::sync::mpsc::error::SendError<T>> for E {
     }
 }
 
+impl From<tokio::sync::watch::error::RecvError> for E {
+    fn from(_: tokio::sync::watch::error::RecvError) -> Self {
+        E::RecvError
+    }
+}
+
 impl From<brec::Error> for E {
     fn from(err: brec::Error) -> Self {
         Self::Journal(err.to_string())
@@ -1,13 +1,23 @@
-use tokio_util::sync::CancellationToken;
+use tokio::sync::watch;
 
 use crate::*;
 
 #[derive(Debug)]
 #[enum_ids::enum_ids(display)]
 pub enum Demand {
-    EmitSignal(String, oneshot::Sender<Result<(), E>>),
-    WaitSignal(String, oneshot::Sender<Option<CancellationToken>>),
+    EmitSignal(String, RtValue, oneshot::Sender<Result<(), E>>),
+    WaitSignal(String, oneshot::Sender<watch::Receiver<Option<RtValue>>>),
     WaitersSignal(String, oneshot::Sender<usize>),
+    ResetSignal(String, oneshot::Sender<()>),
+    AcquireLock(
+        String,
+        Uuid,
+        Vec<Uuid>,
+        oneshot::Sender<Result<Option<oneshot::Receiver<Result<(), E>>>, E>>,
+    ),
+    ReleaseLock(String, Uuid, oneshot::Sender<Result<(), E>>),
+    ReleaseLocks(Uuid, oneshot::Sender<Vec<String>>),
+    SetLockCapacity(String, usize, oneshot::Sender<Result<(), E>>),
     GetRtParameters(oneshot::Sender<RtParameters>),
     CreateContext(
         Uuid,
@@ -15,5 +25,6 @@ pub enum Demand {
         Option<Uuid>,
         oneshot::Sender<Result<Context, E>>,
     ),
+    GetTimings(oneshot::Sender<Result<Timings, E>>),
     Destroy(oneshot::Sender<()>),
 }
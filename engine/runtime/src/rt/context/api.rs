@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{spawner::OutputMode, *};
 
 #[derive(Debug)]
 #[enum_ids::enum_ids(display)]
@@ -14,9 +14,15 @@ pub enum DemandCommand {
     UpdateVariableValue(String, RtValue, oneshot::Sender<Result<(), E>>),
     GetVariableValue(String, oneshot::Sender<Result<Option<Arc<RtValue>>, E>>),
     OpenLoop(Uuid, oneshot::Sender<Result<(), E>>),
-    CloseLoop(oneshot::Sender<Result<(), E>>),
+    CloseLoop(oneshot::Sender<Result<Option<RtValue>, E>>),
     IsLoopStopped(oneshot::Sender<bool>),
-    SetBreakSignal(oneshot::Sender<Result<(), E>>),
+    SetBreakSignal(
+        Option<Uuid>,
+        Option<RtValue>,
+        oneshot::Sender<Result<(), E>>,
+    ),
+    SetContinueSignal(Option<Uuid>, oneshot::Sender<Result<(), E>>),
+    NextIteration(oneshot::Sender<bool>),
     OpenReturnContext(Uuid, oneshot::Sender<Result<(), E>>),
     CloseReturnContext(oneshot::Sender<Result<(), E>>),
     SetReturnValue(RtValue, oneshot::Sender<Result<(), E>>),
@@ -24,6 +30,12 @@ pub enum DemandCommand {
     GetCwd(oneshot::Sender<PathBuf>),
     SetCwd(PathBuf, oneshot::Sender<()>),
     GetRootCwd(oneshot::Sender<PathBuf>),
+    InitOutput(Uuid, Option<OutputMode>, oneshot::Sender<()>),
+    GetOutput(oneshot::Sender<Option<OutputMode>>),
+    SetOutput(OutputMode, oneshot::Sender<()>),
+    SetNextOutput(OutputMode, oneshot::Sender<()>),
+    SetComponentOutput(OutputMode, oneshot::Sender<()>),
+    TakeOutput(oneshot::Sender<OutputMode>),
     CloseContext(Uuid, oneshot::Sender<()>),
 }
 
@@ -5,12 +5,13 @@ mod parent;
 mod scope;
 mod store;
 
-use crate::*;
+use crate::{spawner::OutputMode, *};
 use api::*;
 pub use owned::*;
 pub use params::*;
 pub use parent::*;
 pub(crate) use scope::*;
+use std::collections::hash_map::Entry;
 use store::*;
 
 #[derive(Debug, Clone)]
@@ -25,6 +26,8 @@ impl RtContext {
         let cwd = cwd.clone();
         spawn(async move {
             let mut stores: HashMap<Uuid, Store> = HashMap::new();
+            // Modes of output defined for components
+            let mut outputs: HashMap<Uuid, OutputMode> = HashMap::new();
             tracing::info!("init demand's listener");
             while let Some(demand) = rx.recv().await {
                 match demand {
@@ -89,20 +92,15 @@ impl RtContext {
                                 );
                             }
                             DemandCommand::OpenLoop(uuid, tx) => {
-                                if store.loops.contains(&uuid) {
-                                    chk_send_err!(
-                                        { tx.send(Err(E::LoopAlreadyExist(uuid))) },
-                                        DemandCommandId::OpenLoop
-                                    );
-                                } else {
-                                    store.loops.push(uuid);
-                                    chk_send_err!({ tx.send(Ok(())) }, DemandCommandId::OpenLoop);
-                                }
+                                // The same loop can be opened again by recursive call
+                                store.loops.push(uuid);
+                                chk_send_err!({ tx.send(Ok(())) }, DemandCommandId::OpenLoop);
                             }
                             DemandCommand::CloseLoop(tx) => {
                                 if let Some(uuid) = store.loops.pop() {
-                                    store.breaks.remove(&uuid);
-                                    chk_send_err!({ tx.send(Ok(())) }, DemandCommandId::CloseLoop);
+                                    let vl = store.breaks.remove(&uuid).flatten();
+                                    store.continues.remove(&uuid);
+                                    chk_send_err!({ tx.send(Ok(vl)) }, DemandCommandId::CloseLoop);
                                 } else {
                                     chk_send_err!(
                                         { tx.send(Err(E::NoOpenLoopsToClose)) },
@@ -110,41 +108,56 @@ impl RtContext {
                                     );
                                 }
                             }
-                            DemandCommand::SetBreakSignal(tx) => {
-                                let Some(target) = store.loops.last() else {
+                            DemandCommand::SetBreakSignal(target, vl, tx) => {
+                                let Some(target) = store.loop_target(target) else {
                                     chk_send_err!(
                                         { tx.send(Err(E::NoOpenLoopsToBreak)) },
                                         DemandCommandId::SetBreakSignal
                                     );
                                     continue;
                                 };
-                                if store.breaks.contains(target) {
+                                if let Entry::Vacant(entry) = store.breaks.entry(target) {
+                                    entry.insert(vl);
                                     chk_send_err!(
-                                        { tx.send(Err(E::BreakSignalAlreadyExist(*target))) },
+                                        { tx.send(Ok(())) },
                                         DemandCommandId::SetBreakSignal
                                     );
                                 } else {
-                                    store.breaks.insert(*target);
                                     chk_send_err!(
-                                        { tx.send(Ok(())) },
+                                        { tx.send(Err(E::BreakSignalAlreadyExist(target))) },
                                         DemandCommandId::SetBreakSignal
                                     );
                                 }
                             }
-                            DemandCommand::OpenReturnContext(uuid, tx) => {
-                                if store.rcx.contains(&uuid) {
+                            DemandCommand::SetContinueSignal(target, tx) => {
+                                let Some(target) = store.loop_target(target) else {
                                     chk_send_err!(
-                                        { tx.send(Err(E::ReturnCXAlreadyExist(uuid))) },
-                                        DemandCommandId::OpenReturnContext
+                                        { tx.send(Err(E::NoOpenLoopsToContinue)) },
+                                        DemandCommandId::SetContinueSignal
+                                    );
+                                    continue;
+                                };
+                                if store.continues.contains(&target) {
+                                    chk_send_err!(
+                                        { tx.send(Err(E::ContinueSignalAlreadyExist(target))) },
+                                        DemandCommandId::SetContinueSignal
                                     );
                                 } else {
-                                    store.rcx.push(uuid);
+                                    store.continues.insert(target);
                                     chk_send_err!(
                                         { tx.send(Ok(())) },
-                                        DemandCommandId::OpenReturnContext
+                                        DemandCommandId::SetContinueSignal
                                     );
                                 }
                             }
+                            DemandCommand::OpenReturnContext(uuid, tx) => {
+                                // The same context can be opened again by recursive call
+                                store.rcx.push(uuid);
+                                chk_send_err!(
+                                    { tx.send(Ok(())) },
+                                    DemandCommandId::OpenReturnContext
+                                );
+                            }
                             DemandCommand::CloseReturnContext(tx) => {
                                 if let Some(uuid) = store.rcx.pop() {
                                     store.returns.remove(&uuid);
@@ -188,23 +201,16 @@ impl RtContext {
                             }
                             DemandCommand::IsLoopStopped(tx) => {
                                 chk_send_err!(
-                                    {
-                                        tx.send(
-                                            store
-                                                .loops
-                                                .last()
-                                                .map(|uuid| store.breaks.contains(uuid))
-                                                .unwrap_or(false)
-                                                || store
-                                                    .rcx
-                                                    .last()
-                                                    .map(|uuid| store.returns.contains_key(uuid))
-                                                    .unwrap_or(false),
-                                        )
-                                    },
+                                    { tx.send(store.is_loop_stopped()) },
                                     DemandCommandId::IsLoopStopped
                                 );
                             }
+                            DemandCommand::NextIteration(tx) => {
+                                chk_send_err!(
+                                    { tx.send(store.next_iteration()) },
+                                    DemandCommandId::NextIteration
+                                );
+                            }
                             DemandCommand::GetCwd(tx) => {
                                 chk_send_err!(
                                     { tx.send(store.cwd.clone()) },
@@ -221,6 +227,46 @@ impl RtContext {
                                     DemandCommandId::GetRootCwd
                                 );
                             }
+                            DemandCommand::InitOutput(component, inherited, tx) => {
+                                store.output = outputs.get(&component).cloned().or(inherited);
+                                store.component = Some(component);
+                                chk_send_err!(tx.send(()), DemandCommandId::InitOutput);
+                            }
+                            DemandCommand::GetOutput(tx) => {
+                                chk_send_err!(
+                                    { tx.send(store.output.clone()) },
+                                    DemandCommandId::GetOutput
+                                );
+                            }
+                            DemandCommand::SetOutput(mode, tx) => {
+                                store.output = Some(mode);
+                                chk_send_err!(tx.send(()), DemandCommandId::SetOutput);
+                            }
+                            DemandCommand::SetNextOutput(mode, tx) => {
+                                store.next_output = Some(mode);
+                                chk_send_err!(tx.send(()), DemandCommandId::SetNextOutput);
+                            }
+                            DemandCommand::SetComponentOutput(mode, tx) => {
+                                if let Some(component) = store.component {
+                                    outputs.insert(component, mode.clone());
+                                }
+                                store.output = Some(mode);
+                                chk_send_err!(tx.send(()), DemandCommandId::SetComponentOutput);
+                            }
+                            DemandCommand::TakeOutput(tx) => {
+                                chk_send_err!(
+                                    {
+                                        tx.send(
+                                            store
+                                                .next_output
+                                                .take()
+                                                .or_else(|| store.output.clone())
+                                                .unwrap_or_default(),
+                                        )
+                                    },
+                                    DemandCommandId::TakeOutput
+                                );
+                            }
                             DemandCommand::CloseContext(uuid, tx) => {
                                 stores.remove(&uuid);
                                 chk_send_err!(tx.send(()), DemandCommandId::CloseContext);
@@ -344,20 +390,43 @@ impl RtContext {
         rx.await?
     }
 
-    pub(crate) async fn close_loop(&self, owner: Uuid) -> Result<(), E> {
+    pub(crate) async fn close_loop(&self, owner: Uuid) -> Result<Option<RtValue>, E> {
         let (tx, rx) = oneshot::channel();
         self.tx
             .send(Demand::Command(owner, DemandCommand::CloseLoop(tx)))?;
         rx.await?
     }
 
-    pub(crate) async fn set_break(&self, owner: Uuid) -> Result<(), E> {
+    pub(crate) async fn set_break(
+        &self,
+        owner: Uuid,
+        target: Option<Uuid>,
+        vl: Option<RtValue>,
+    ) -> Result<(), E> {
         let (tx, rx) = oneshot::channel();
-        self.tx
-            .send(Demand::Command(owner, DemandCommand::SetBreakSignal(tx)))?;
+        self.tx.send(Demand::Command(
+            owner,
+            DemandCommand::SetBreakSignal(target, vl, tx),
+        ))?;
+        rx.await?
+    }
+
+    pub(crate) async fn set_continue(&self, owner: Uuid, target: Option<Uuid>) -> Result<(), E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::Command(
+            owner,
+            DemandCommand::SetContinueSignal(target, tx),
+        ))?;
         rx.await?
     }
 
+    pub(crate) async fn next_iteration(&self, owner: Uuid) -> Result<bool, E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx
+            .send(Demand::Command(owner, DemandCommand::NextIteration(tx)))?;
+        Ok(rx.await?)
+    }
+
     pub(crate) async fn open_return_cx(&self, owner: Uuid, uuid: &Uuid) -> Result<(), E> {
         let (tx, rx) = oneshot::channel();
         self.tx.send(Demand::Command(
@@ -426,6 +495,63 @@ impl RtContext {
         rx.await.map_err(|e| e.into())
     }
 
+    pub(crate) async fn init_output(
+        &self,
+        owner: Uuid,
+        component: Uuid,
+        inherited: Option<OutputMode>,
+    ) -> Result<(), E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::Command(
+            owner,
+            DemandCommand::InitOutput(component, inherited, tx),
+        ))?;
+        rx.await.map_err(|e| e.into())
+    }
+
+    pub(crate) async fn get_output(&self, owner: Uuid) -> Result<Option<OutputMode>, E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx
+            .send(Demand::Command(owner, DemandCommand::GetOutput(tx)))?;
+        rx.await.map_err(|e| e.into())
+    }
+
+    pub(crate) async fn set_output(&self, owner: Uuid, mode: OutputMode) -> Result<(), E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx
+            .send(Demand::Command(owner, DemandCommand::SetOutput(mode, tx)))?;
+        rx.await.map_err(|e| e.into())
+    }
+
+    pub(crate) async fn set_next_output(&self, owner: Uuid, mode: OutputMode) -> Result<(), E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::Command(
+            owner,
+            DemandCommand::SetNextOutput(mode, tx),
+        ))?;
+        rx.await.map_err(|e| e.into())
+    }
+
+    pub(crate) async fn set_component_output(
+        &self,
+        owner: Uuid,
+        mode: OutputMode,
+    ) -> Result<(), E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::Command(
+            owner,
+            DemandCommand::SetComponentOutput(mode, tx),
+        ))?;
+        rx.await.map_err(|e| e.into())
+    }
+
+    pub(crate) async fn take_output(&self, owner: Uuid) -> Result<OutputMode, E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx
+            .send(Demand::Command(owner, DemandCommand::TakeOutput(tx)))?;
+        rx.await.map_err(|e| e.into())
+    }
+
     pub(crate) async fn close_cx(&self, owner: Uuid) -> Result<(), E> {
         let (tx, rx) = oneshot::channel();
         self.tx.send(Demand::Command(
@@ -1,6 +1,8 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
 use bstorage::Storage;
 
-use crate::*;
+use crate::{spawner::OutputMode, *};
 
 pub struct ContextValues<'a> {
     owner: &'a Uuid,
@@ -66,17 +68,33 @@ impl ContextLoop<'_> {
         self.rt.open_loop(*self.owner, uuid).await
     }
 
-    pub async fn close(&self) -> Result<(), E> {
+    /// Closes the current loop and returns the value of break (`break value;`) if
+    /// it was set.
+    pub async fn close(&self) -> Result<Option<RtValue>, E> {
         self.rt.close_loop(*self.owner).await
     }
 
-    pub async fn set_break(&self) -> Result<(), E> {
-        self.rt.set_break(*self.owner).await
+    /// Sets break signal for the given loop or for the current loop if the target
+    /// isn't defined.
+    pub async fn set_break(&self, target: Option<&Uuid>, vl: Option<RtValue>) -> Result<(), E> {
+        self.rt.set_break(*self.owner, target.copied(), vl).await
+    }
+
+    /// Sets continue signal for the given loop or for the current loop if the target
+    /// isn't defined.
+    pub async fn set_continue(&self, target: Option<&Uuid>) -> Result<(), E> {
+        self.rt.set_continue(*self.owner, target.copied()).await
     }
 
     pub async fn is_stopped(&self) -> Result<bool, E> {
         self.rt.is_loop_stopped(*self.owner).await
     }
+
+    /// Should be called before each iteration of loop. Drops the continue signal and
+    /// returns `true` if the loop should be stopped.
+    pub async fn next(&self) -> Result<bool, E> {
+        self.rt.next_iteration(*self.owner).await
+    }
 }
 
 pub struct ContextReturns<'a> {
@@ -121,16 +139,99 @@ impl ContextCwd<'_> {
     }
 }
 
+/// Modes of output of commands. The mode of the next command takes precedence over the
+/// mode of context; without both commands use `OutputMode::Stream`.
+pub struct ContextOutput<'a> {
+    owner: &'a Uuid,
+    rt: &'a RtContext,
+}
+
+impl ContextOutput<'_> {
+    /// Binds context with the component of task. Context gets the mode of component if
+    /// it's defined or the given mode inherited from the caller.
+    pub async fn init(&self, component: &Uuid, inherited: Option<OutputMode>) -> Result<(), E> {
+        self.rt
+            .init_output(*self.owner, *component, inherited)
+            .await
+    }
+
+    pub async fn get(&self) -> Result<Option<OutputMode>, E> {
+        self.rt.get_output(*self.owner).await
+    }
+
+    /// Sets mode for all following commands of context
+    pub async fn set(&self, mode: OutputMode) -> Result<(), E> {
+        self.rt.set_output(*self.owner, mode).await
+    }
+
+    /// Sets mode for the next command only
+    pub async fn next(&self, mode: OutputMode) -> Result<(), E> {
+        self.rt.set_next_output(*self.owner, mode).await
+    }
+
+    /// Sets mode for all following commands of context and for tasks of the same
+    /// component, which will be executed later
+    pub async fn component(&self, mode: OutputMode) -> Result<(), E> {
+        self.rt.set_component_output(*self.owner, mode).await
+    }
+
+    /// Returns mode for the command, which is going to be spawned
+    pub async fn take(&self) -> Result<OutputMode, E> {
+        self.rt.take_output(*self.owner).await
+    }
+}
+
+/// Keeps the depth of calls while the call is running
+pub struct CallGuard {
+    depth: Arc<AtomicUsize>,
+}
+
+impl Drop for CallGuard {
+    fn drop(&mut self) {
+        self.depth.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+pub struct ContextCalls<'a> {
+    depth: &'a Arc<AtomicUsize>,
+}
+
+impl ContextCalls<'_> {
+    /// Increases the depth of calls until the returned guard is dropped. Fails if
+    /// the depth exceeds the given limit.
+    pub fn enter(&self, limit: Option<usize>) -> Result<CallGuard, E> {
+        let guard = CallGuard {
+            depth: self.depth.clone(),
+        };
+        let depth = self.depth.fetch_add(1, Ordering::SeqCst) + 1;
+        match limit {
+            Some(limit) if depth > limit => Err(E::DepthLimit(limit)),
+            _ => Ok(guard),
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth.load(Ordering::SeqCst)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Context {
     owner: Uuid,
     rt: RtContext,
+    /// Depth of calls of user functions and closures
+    depth: Arc<AtomicUsize>,
     pub job: Job,
 }
 
 impl Context {
     pub fn new(owner: Uuid, rt: RtContext, job: Job) -> Self {
-        Self { owner, rt, job }
+        Self {
+            owner,
+            rt,
+            depth: Arc::new(AtomicUsize::new(0)),
+            job,
+        }
     }
     pub fn loops(&self) -> ContextLoop<'_> {
         ContextLoop {
@@ -156,12 +257,21 @@ impl Context {
             rt: &self.rt,
         }
     }
+    pub fn calls(&self) -> ContextCalls<'_> {
+        ContextCalls { depth: &self.depth }
+    }
     pub fn cwd(&self) -> ContextCwd<'_> {
         ContextCwd {
             owner: &self.owner,
             rt: &self.rt,
         }
     }
+    pub fn output(&self) -> ContextOutput<'_> {
+        ContextOutput {
+            owner: &self.owner,
+            rt: &self.rt,
+        }
+    }
     pub async fn storage(&self) -> Result<Storage, E> {
         Ok(Storage::create(
             self.cwd()
@@ -172,7 +282,10 @@ impl Context {
         )?)
     }
     pub(crate) async fn child<S: ToString>(&self, owner: Uuid, alias: S) -> Result<Context, E> {
-        Ok(self.rt.create(owner, self.job.child(owner, alias).await?))
+        let mut cx = self.rt.create(owner, self.job.child(owner, alias).await?);
+        // Child context continues the chain of calls of parent
+        cx.depth = Arc::new(AtomicUsize::new(self.calls().depth()));
+        Ok(cx)
     }
     pub async fn close(&self) -> Result<(), E> {
         self.rt.close_cx(self.owner).await?;
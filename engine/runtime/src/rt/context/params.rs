@@ -1,6 +1,45 @@
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
-use crate::E;
+use crate::{ProgressMode, E};
+
+/// Default max depth of calls of user functions and closures
+pub const DEFAULT_DEPTH_LIMIT: usize = 512;
+
+/// Default max number of processes running at the same time; equal to number of CPUs
+pub fn default_jobs_limit() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Limits of execution. `None` means no limit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RtLimits {
+    /// Max number of iterations of a single loop (`loop`, `while`, `for`). There is no limit
+    /// by default: `for` always iterates over a finite collection and endless `loop` or
+    /// `while` are stopped by `timeout`.
+    pub iterations: Option<usize>,
+    /// Max depth of calls of user functions and closures
+    pub depth: Option<usize>,
+    /// Max duration of the whole execution
+    pub timeout: Option<Duration>,
+    /// Max number of processes running at the same time across all `join`s
+    pub jobs: Option<usize>,
+}
+
+impl Default for RtLimits {
+    fn default() -> Self {
+        Self {
+            iterations: None,
+            depth: Some(DEFAULT_DEPTH_LIMIT),
+            timeout: None,
+            jobs: Some(default_jobs_limit()),
+        }
+    }
+}
 
 #[derive(Debug, Default, Clone)]
 pub struct RtParameters {
@@ -8,6 +47,9 @@ pub struct RtParameters {
     pub cwd: PathBuf,
     pub component: String,
     pub task: String,
+    pub limits: RtLimits,
+    /// Mode of progress rendering; detected by environment if it isn't defined
+    pub progress: Option<ProgressMode>,
 }
 
 impl RtParameters {
@@ -22,9 +64,21 @@ impl RtParameters {
             cwd: cwd.as_ref().to_path_buf(),
             component: component.as_ref().to_owned(),
             task: task.as_ref().to_owned(),
+            limits: RtLimits::default(),
+            progress: None,
         }
     }
 
+    pub fn with_limits(mut self, limits: RtLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    pub fn with_progress(mut self, progress: Option<ProgressMode>) -> Self {
+        self.progress = progress;
+        self
+    }
+
     pub fn default_from_cwd() -> Result<Self, E> {
         Ok(Self {
             cwd: std::env::current_dir()?,
@@ -1,16 +1,24 @@
 use std::sync::Arc;
 
-use crate::*;
+use crate::{spawner::OutputMode, *};
 
 #[derive(Debug)]
 pub struct Store {
     pub(crate) scopes: HashMap<Uuid, VlContext>,
     pub(crate) location: Vec<Uuid>,
-    pub(crate) breaks: HashSet<Uuid>,
+    /// Break signals of loops with values (`break value;`)
+    pub(crate) breaks: HashMap<Uuid, Option<RtValue>>,
+    pub(crate) continues: HashSet<Uuid>,
     pub(crate) loops: Vec<Uuid>,
     pub(crate) rcx: Vec<Uuid>,
     pub(crate) returns: HashMap<Uuid, RtValue>,
     pub(crate) cwd: PathBuf,
+    /// Mode of output of commands spawned in the context
+    pub(crate) output: Option<OutputMode>,
+    /// Mode of output of the next spawned command only
+    pub(crate) next_output: Option<OutputMode>,
+    /// Component of the task, which owns the context
+    pub(crate) component: Option<Uuid>,
 }
 
 impl Store {
@@ -22,10 +30,14 @@ impl Store {
             scopes,
             location: vec![root],
             loops: Vec::new(),
-            breaks: HashSet::new(),
+            breaks: HashMap::new(),
+            continues: HashSet::new(),
             rcx: Vec::new(),
             returns: HashMap::new(),
             cwd,
+            output: None,
+            next_output: None,
+            component: None,
         }
     }
     pub fn open(&mut self, uuid: &Uuid) {
@@ -40,6 +52,36 @@ impl Store {
             Err(E::AttemptToLeaveGlobalContext)
         }
     }
+    /// Returns `true` if the current iteration of the current loop should be stopped.
+    /// Signal of any open loop stops the current one, because labeled `break` and
+    /// `continue` can target outer loop.
+    pub fn is_loop_stopped(&self) -> bool {
+        self.loops
+            .iter()
+            .any(|uuid| self.breaks.contains_key(uuid) || self.continues.contains(uuid))
+            || self
+                .rcx
+                .last()
+                .map(|uuid| self.returns.contains_key(uuid))
+                .unwrap_or(false)
+    }
+    /// Drops the continue signal of the current loop and returns `true` if the loop
+    /// should be stopped.
+    pub fn next_iteration(&mut self) -> bool {
+        if let Some(uuid) = self.loops.last() {
+            self.continues.remove(uuid);
+        }
+        self.is_loop_stopped()
+    }
+    /// Returns the target of loop's signal: the given loop (if it's open) or the
+    /// current loop.
+    pub fn loop_target(&self, target: Option<Uuid>) -> Option<Uuid> {
+        if let Some(target) = target {
+            self.loops.contains(&target).then_some(target)
+        } else {
+            self.loops.last().copied()
+        }
+    }
     pub fn set_parent_vl(&mut self, vl: ParentValue) -> Result<(), E> {
         self.get_mut()?.parent.set(vl);
         Ok(())
@@ -3,6 +3,15 @@ use crate::*;
 #[derive(Debug)]
 #[enum_ids::enum_ids(display)]
 pub enum Demand {
-    Create(Uuid, String, Option<Uuid>, oneshot::Sender<Result<Job, E>>),
+    Create(
+        Uuid,
+        String,
+        Option<Uuid>,
+        Vec<Uuid>,
+        oneshot::Sender<Result<Job, E>>,
+    ),
+    Finish(Uuid, JobStatus),
+    Timings(oneshot::Sender<Timings>),
+    Journal(String, oneshot::Sender<Journal>),
     Destroy(oneshot::Sender<()>),
 }
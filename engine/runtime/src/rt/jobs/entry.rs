@@ -54,6 +54,8 @@ impl JobEntry {
             self.parent,
             journal,
             progress,
+            // Cancelling of root cancels all jobs
+            self.cancel.child_token(),
             rt,
         )
     }
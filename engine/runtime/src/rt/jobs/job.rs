@@ -13,6 +13,7 @@ impl Done<'_> {
             self.job.journal.debug(msg.to_string());
         }
         self.job.progress.success(msg);
+        self.job.finish(JobStatus::Success);
         self.job.close();
     }
     pub fn failed<S: ToString>(&self, msg: Option<S>) {
@@ -20,6 +21,7 @@ impl Done<'_> {
             self.job.journal.err(msg.to_string());
         }
         self.job.progress.failed(msg);
+        self.job.finish(JobStatus::Failed);
         self.job.close();
     }
 }
@@ -35,6 +37,7 @@ impl Cancel<'_> {
             self.job.journal.debug(msg.to_string());
         }
         self.job.progress.cancelled(msg);
+        self.job.finish(JobStatus::Cancelled);
     }
     pub fn failed<S: ToString>(&self, msg: Option<S>) {
         if let Some(msg) = msg.as_ref() {
@@ -43,6 +46,7 @@ impl Cancel<'_> {
                 .err(format!("Cancelled with error: {}", msg.to_string()));
         }
         self.job.progress.cancelled(msg);
+        self.job.finish(JobStatus::Cancelled);
     }
 }
 
@@ -53,6 +57,10 @@ pub struct Job {
     pub(crate) owner: Uuid,
     pub(crate) parent: Option<Uuid>,
     pub(crate) alias: String,
+    /// Unique id of job; unlike `owner` it's different for each run of the same task
+    pub(crate) uuid: Uuid,
+    /// Ids of parent jobs from the root
+    pub(crate) ancestors: Vec<Uuid>,
     pub cancel: CancellationToken,
     rt: RtJobs,
 }
@@ -64,6 +72,7 @@ impl Job {
         parent: Option<Uuid>,
         journal: Journal,
         progress: Progress,
+        cancel: CancellationToken,
         rt: RtJobs,
     ) -> Self {
         Self {
@@ -72,7 +81,9 @@ impl Job {
             owner,
             parent,
             alias: alias.to_string(),
-            cancel: CancellationToken::new(),
+            uuid: Uuid::new_v4(),
+            ancestors: Vec::new(),
+            cancel,
             rt,
         }
     }
@@ -86,9 +97,20 @@ impl Job {
     }
 
     pub async fn child<S: ToString>(&self, owner: Uuid, alias: S) -> Result<Job, E> {
-        self.rt
-            .create(owner, alias.to_string(), Some(self.owner))
-            .await
+        let mut ancestors = self.ancestors.clone();
+        ancestors.push(self.uuid);
+        let mut job = self
+            .rt
+            .create(owner, alias.to_string(), Some(self.owner), ancestors)
+            .await?;
+        // Cancelling of job cancels all its descendants
+        job.cancel = self.cancel.child_token();
+        Ok(job)
+    }
+
+    /// Sets the end of job in timings. Only the first call takes effect.
+    pub fn finish(&self, status: JobStatus) {
+        self.rt.finish(&self.uuid, status);
     }
 
     pub fn close(&self) {
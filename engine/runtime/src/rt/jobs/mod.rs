@@ -1,11 +1,13 @@
 mod api;
 mod entry;
 mod job;
+mod timings;
 
 use crate::{rt::journal, *};
 use api::*;
 use entry::*;
 pub use job::*;
+pub use timings::*;
 use tokio_util::sync::CancellationToken;
 
 #[derive(Clone, Debug)]
@@ -15,26 +17,38 @@ pub struct RtJobs {
 
 impl RtJobs {
     #[tracing::instrument]
-    pub fn new(root: &PathBuf) -> Result<Self, E> {
+    pub fn new(root: &PathBuf, mode: ProgressMode, cancel: CancellationToken) -> Result<Self, E> {
         let (tx, mut rx) = unbounded_channel();
         let instance = Self { tx };
-        let progress = RtProgress::new()?;
+        let progress = RtProgress::new(mode)?;
         let journal = RtJournal::new(root)?;
         let inner = instance.clone();
         spawn(async move {
             tracing::info!("init demand's listener");
-            let mut root: JobEntry =
-                JobEntry::new("root", Uuid::new_v4(), None, CancellationToken::new());
+            let mut root: JobEntry = JobEntry::new("root", Uuid::new_v4(), None, cancel);
+            let mut timings = Timings::default();
             while let Some(demand) = rx.recv().await {
                 match demand {
                     Demand::Destroy(tx) => {
                         tracing::info!("got shutdown signal");
+                        root.cancel.cancel();
                         chk_send_err!(journal.destroy().await, DemandId::Destroy);
                         chk_send_err!(progress.destroy().await, DemandId::Destroy);
                         chk_send_err!(tx.send(()), DemandId::Destroy);
                         break;
                     }
-                    Demand::Create(owner, alias, parent, tx) => {
+                    Demand::Journal(alias, tx) => {
+                        let journal = journal.create(Uuid::new_v4(), None);
+                        journal.job_open(alias);
+                        chk_send_err!(tx.send(journal), DemandId::Journal);
+                    }
+                    Demand::Finish(uuid, status) => {
+                        timings.finish(&uuid, status);
+                    }
+                    Demand::Timings(tx) => {
+                        chk_send_err!(tx.send(timings.snapshot()), DemandId::Timings);
+                    }
+                    Demand::Create(owner, alias, parent, ancestors, tx) => {
                         let job = JobEntry::new(&alias, owner, parent, root.cancel.clone());
                         if let Some(parent_uuid) = parent {
                             let Some(parent_entry) = root.find(&parent_uuid) else {
@@ -60,11 +74,11 @@ impl RtJobs {
                             }
                         };
                         let journal = journal.create(owner, parent);
-                        journal.job_open(alias);
-                        chk_send_err!(
-                            tx.send(Ok(job.as_job(journal, progress, inner.clone()))),
-                            DemandId::Create
-                        );
+                        journal.job_open(&alias);
+                        let mut job = job.as_job(journal, progress, inner.clone());
+                        timings.start(job.uuid, ancestors.last().copied(), alias);
+                        job.ancestors = ancestors;
+                        chk_send_err!(tx.send(Ok(job)), DemandId::Create);
                     }
                 }
             }
@@ -78,13 +92,41 @@ impl RtJobs {
         owner: Uuid,
         alias: S,
         parent: Option<Uuid>,
+        ancestors: Vec<Uuid>,
     ) -> Result<Job, E> {
         let (tx, rx) = oneshot::channel();
-        self.tx
-            .send(Demand::Create(owner, alias.to_string(), parent, tx))?;
+        self.tx.send(Demand::Create(
+            owner,
+            alias.to_string(),
+            parent,
+            ancestors,
+            tx,
+        ))?;
         rx.await?
     }
 
+    pub(crate) fn finish(&self, uuid: &Uuid, status: JobStatus) {
+        chk_send_err!(
+            self.tx.send(Demand::Finish(*uuid, status)),
+            DemandId::Finish
+        );
+    }
+
+    /// Returns time frames of all jobs created so far
+    pub async fn timings(&self) -> Result<Timings, E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::Timings(tx))?;
+        Ok(rx.await?)
+    }
+
+    /// Returns a journal, which isn't bound to any job; it's used for records of runtime
+    /// itself
+    pub(crate) async fn journal<S: ToString>(&self, alias: S) -> Result<Journal, E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::Journal(alias.to_string(), tx))?;
+        Ok(rx.await?)
+    }
+
     pub async fn destroy(&self) -> Result<(), E> {
         let (tx, rx) = oneshot::channel();
         self.tx.send(Demand::Destroy(tx))?;
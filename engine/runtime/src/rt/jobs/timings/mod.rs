@@ -0,0 +1,238 @@
+mod summary;
+mod trace;
+
+use crate::*;
+use indexmap::IndexMap;
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// Final status of job
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JobStatus {
+    Running,
+    Success,
+    Failed,
+    Cancelled,
+}
+
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Running => "running",
+                Self::Success => "done",
+                Self::Failed => "failed",
+                Self::Cancelled => "cancelled",
+            }
+        )
+    }
+}
+
+/// Time frame of job. Time points are relative to the start of runtime.
+#[derive(Debug, Clone)]
+pub struct JobTiming {
+    pub uuid: Uuid,
+    /// Id of parent job
+    pub parent: Option<Uuid>,
+    pub alias: String,
+    pub start: Duration,
+    /// `None` if job is still running
+    pub end: Option<Duration>,
+    pub status: JobStatus,
+}
+
+impl JobTiming {
+    /// Returns the end of job; for running job it's the given time point
+    pub fn end_or(&self, now: Duration) -> Duration {
+        self.end.unwrap_or(now)
+    }
+}
+
+/// Collects time frames of all jobs of runtime
+#[derive(Debug, Clone)]
+pub struct Timings {
+    origin: Instant,
+    /// Jobs in order of creation
+    jobs: IndexMap<Uuid, JobTiming>,
+    /// Time point of the snapshot; running jobs are considered as finished at this point
+    now: Duration,
+}
+
+impl Default for Timings {
+    fn default() -> Self {
+        Self {
+            origin: Instant::now(),
+            jobs: IndexMap::new(),
+            now: Duration::ZERO,
+        }
+    }
+}
+
+impl Timings {
+    pub(crate) fn start<S: ToString>(&mut self, uuid: Uuid, parent: Option<Uuid>, alias: S) {
+        self.jobs.insert(
+            uuid,
+            JobTiming {
+                uuid,
+                parent,
+                alias: alias.to_string(),
+                start: self.origin.elapsed(),
+                end: None,
+                status: JobStatus::Running,
+            },
+        );
+    }
+
+    /// Sets the end of job. Only the first call takes effect, so the status of failed
+    /// job isn't overwritten by later closing of it.
+    pub(crate) fn finish(&mut self, uuid: &Uuid, status: JobStatus) {
+        let end = self.origin.elapsed();
+        if let Some(job) = self.jobs.get_mut(uuid) {
+            if job.end.is_none() {
+                job.end = Some(end);
+                job.status = status;
+            }
+        }
+    }
+
+    /// Returns a copy of timings at the current time point
+    pub(crate) fn snapshot(&self) -> Self {
+        let mut snapshot = self.clone();
+        snapshot.now = self.origin.elapsed();
+        snapshot
+    }
+
+    /// Returns jobs in order of creation
+    pub fn jobs(&self) -> impl Iterator<Item = &JobTiming> {
+        self.jobs.values()
+    }
+
+    /// Returns duration of job; running jobs are measured up to the time of snapshot
+    pub fn duration(&self, job: &JobTiming) -> Duration {
+        job.end_or(self.now).saturating_sub(job.start)
+    }
+
+    /// Returns the whole duration of execution
+    pub fn total(&self) -> Duration {
+        self.jobs
+            .values()
+            .map(|job| job.end_or(self.now))
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn childs(&self, parent: Option<&Uuid>) -> Vec<&JobTiming> {
+        self.jobs
+            .values()
+            .filter(|job| job.parent.as_ref() == parent)
+            .collect()
+    }
+
+    /// Returns the chain of jobs, which defines the duration of execution. On each level
+    /// the path goes through the child finished last and through children, which
+    /// finished before it had started; so for parallel branches (`join`) only the
+    /// slowest one is included.
+    pub fn critical_path(&self) -> Vec<&JobTiming> {
+        let mut path = Vec::new();
+        let roots = self.childs(None);
+        let end = roots
+            .iter()
+            .map(|job| job.end_or(self.now))
+            .max()
+            .unwrap_or_default();
+        self.chain(roots, end, &mut path);
+        path
+    }
+
+    fn chain<'a>(
+        &'a self,
+        mut jobs: Vec<&'a JobTiming>,
+        end: Duration,
+        path: &mut Vec<&'a JobTiming>,
+    ) {
+        let mut limit = end;
+        let mut chain = Vec::new();
+        while let Some(pos) = jobs
+            .iter()
+            .enumerate()
+            .filter(|(_, job)| job.end_or(self.now) <= limit)
+            .max_by_key(|(_, job)| job.end_or(self.now))
+            .map(|(pos, _)| pos)
+        {
+            let job = jobs.remove(pos);
+            limit = job.start;
+            chain.push(job);
+        }
+        for job in chain.into_iter().rev() {
+            path.push(job);
+            self.chain(self.childs(Some(&job.uuid)), job.end_or(self.now), path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Adds finished job; time points are given in milliseconds
+    pub(super) fn job(
+        timings: &mut Timings,
+        parent: Option<Uuid>,
+        alias: &str,
+        start: u64,
+        end: u64,
+    ) -> Uuid {
+        let uuid = Uuid::new_v4();
+        timings.jobs.insert(
+            uuid,
+            JobTiming {
+                uuid,
+                parent,
+                alias: alias.to_owned(),
+                start: Duration::from_millis(start),
+                end: Some(Duration::from_millis(end)),
+                status: JobStatus::Success,
+            },
+        );
+        uuid
+    }
+
+    fn aliases(timings: &Timings) -> Vec<&str> {
+        timings
+            .critical_path()
+            .into_iter()
+            .map(|job| job.alias.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn sequential() {
+        let mut timings = Timings::default();
+        let root = job(&mut timings, None, "root", 0, 30);
+        job(&mut timings, Some(root), "a", 0, 10);
+        job(&mut timings, Some(root), "b", 10, 30);
+        assert_eq!(aliases(&timings), vec!["root", "a", "b"]);
+        assert_eq!(timings.total(), Duration::from_millis(30));
+    }
+
+    #[test]
+    fn join() {
+        let mut timings = Timings::default();
+        let root = job(&mut timings, None, "root", 0, 50);
+        job(&mut timings, Some(root), "before", 0, 5);
+        let join = job(&mut timings, Some(root), "join", 5, 40);
+        let slow = job(&mut timings, Some(join), "slow", 5, 40);
+        job(&mut timings, Some(slow), "slow_inner", 10, 35);
+        let fast = job(&mut timings, Some(join), "fast", 5, 15);
+        job(&mut timings, Some(fast), "fast_inner", 5, 15);
+        job(&mut timings, Some(root), "after", 40, 50);
+        assert_eq!(
+            aliases(&timings),
+            vec!["root", "before", "join", "slow", "slow_inner", "after"]
+        );
+    }
+}
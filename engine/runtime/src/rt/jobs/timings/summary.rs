@@ -0,0 +1,41 @@
+use super::*;
+
+impl Timings {
+    /// Returns the table of jobs with status and wall time; nested jobs are indented.
+    /// The table is followed by the critical path of execution.
+    pub fn summary(&self) -> String {
+        let mut rows = Vec::new();
+        self.rows(None, 0, &mut rows);
+        let mut output = vec![format!("{:<10} {:>10}  job", "status", "time")];
+        output.extend(rows);
+        let path = self.critical_path();
+        if !path.is_empty() {
+            output.push(format!(
+                "critical path ({}): {}",
+                secs(self.total()),
+                path.iter()
+                    .map(|job| format!("{} [{}]", job.alias, secs(self.duration(job))))
+                    .collect::<Vec<String>>()
+                    .join(" > ")
+            ));
+        }
+        output.join("\n")
+    }
+
+    fn rows(&self, parent: Option<&Uuid>, deep: usize, rows: &mut Vec<String>) {
+        for job in self.childs(parent) {
+            rows.push(format!(
+                "{:<10} {:>10}  {}{}",
+                job.status.to_string(),
+                secs(self.duration(job)),
+                " ".repeat(deep * 2),
+                job.alias
+            ));
+            self.rows(Some(&job.uuid), deep + 1, rows);
+        }
+    }
+}
+
+fn secs(duration: Duration) -> String {
+    format!("{:.3}s", duration.as_secs_f64())
+}
@@ -0,0 +1,100 @@
+use super::*;
+
+/// Escapes string to be used as JSON string
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+impl Timings {
+    /// Returns jobs as Chrome trace events (JSON), which can be opened in a trace viewer
+    /// (`chrome://tracing`, Perfetto). Complete events of one thread have to be nested
+    /// properly, so jobs running in parallel are placed on separate threads.
+    pub fn trace(&self) -> String {
+        // Each lane keeps the stack of ends of jobs placed on it
+        let mut lanes: Vec<Vec<Duration>> = Vec::new();
+        let mut jobs = self.jobs.values().collect::<Vec<&JobTiming>>();
+        jobs.sort_by_key(|job| job.start);
+        let events = jobs
+            .into_iter()
+            .map(|job| {
+                let end = job.end_or(self.now);
+                let lane = lanes
+                    .iter_mut()
+                    .position(|stack| {
+                        while stack.last().is_some_and(|last| *last <= job.start) {
+                            stack.pop();
+                        }
+                        stack.last().is_none_or(|last| *last >= end)
+                    })
+                    .unwrap_or_else(|| {
+                        lanes.push(Vec::new());
+                        lanes.len() - 1
+                    });
+                lanes[lane].push(end);
+                format!(
+                    r#"{{"name":"{}","cat":"job","ph":"X","ts":{},"dur":{},"pid":1,"tid":{},"args":{{"status":"{}"}}}}"#,
+                    escape(&job.alias),
+                    job.start.as_micros(),
+                    self.duration(job).as_micros(),
+                    lane + 1,
+                    job.status
+                )
+            })
+            .collect::<Vec<String>>();
+        format!(
+            "{{\"traceEvents\":[\n{}\n],\"displayTimeUnit\":\"ms\"}}\n",
+            events.join(",\n")
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rt::jobs::timings::test::job;
+
+    /// Returns thread id of the event of job
+    fn tid(trace: &str, alias: &str) -> usize {
+        let event = trace
+            .lines()
+            .find(|ln| ln.contains(&format!(r#""name":"{alias}""#)))
+            .unwrap();
+        let tid = &event[event.find(r#""tid":"#).unwrap() + 6..];
+        tid[..tid.find(',').unwrap()].parse().unwrap()
+    }
+
+    #[test]
+    fn threads() {
+        let mut timings = Timings::default();
+        let root = job(&mut timings, None, "root", 0, 100);
+        let nested = job(&mut timings, Some(root), "nested", 0, 20);
+        job(&mut timings, Some(nested), "nested_inner", 5, 10);
+        job(&mut timings, Some(root), "a", 20, 60);
+        job(&mut timings, Some(root), "b", 30, 80);
+        job(&mut timings, Some(root), "c", 70, 90);
+        let trace = timings.trace();
+        assert_eq!(tid(&trace, "root"), tid(&trace, "nested"));
+        assert_eq!(tid(&trace, "nested"), tid(&trace, "nested_inner"));
+        assert_eq!(tid(&trace, "root"), tid(&trace, "a"));
+        assert_ne!(tid(&trace, "a"), tid(&trace, "b"));
+        assert_ne!(tid(&trace, "b"), tid(&trace, "c"));
+        assert_eq!(tid(&trace, "root"), tid(&trace, "c"));
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(escape("a \"b\"\n\\c"), r#"a \"b\"\n\\c"#);
+    }
+}
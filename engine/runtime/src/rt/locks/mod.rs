@@ -0,0 +1,150 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::*;
+
+/// Named lock. Lock with capacity greater than 1 works as counting semaphore.
+#[derive(Debug)]
+struct Lock {
+    capacity: usize,
+    /// Jobs holding the lock and the number of taken slots
+    holders: HashMap<Uuid, usize>,
+    /// Jobs waiting for a free slot
+    queue: VecDeque<(Uuid, oneshot::Sender<Result<(), E>>)>,
+}
+
+impl Lock {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            holders: HashMap::new(),
+            queue: VecDeque::new(),
+        }
+    }
+    fn taken(&self) -> usize {
+        self.holders.values().sum()
+    }
+    fn waits(&self, job: &Uuid) -> bool {
+        self.queue.iter().any(|(uuid, _)| uuid == job)
+    }
+    /// Gives free slots to waiting jobs in order of requests
+    fn grant(&mut self) {
+        while self.taken() < self.capacity {
+            let Some((job, tx)) = self.queue.pop_front() else {
+                break;
+            };
+            // Waiter could be gone (cancelled)
+            if tx.send(Ok(())).is_ok() {
+                *self.holders.entry(job).or_insert(0) += 1;
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Locks {
+    locks: HashMap<String, Lock>,
+    /// Ancestors of jobs, which use locks
+    ancestors: HashMap<Uuid, Vec<Uuid>>,
+}
+
+impl Locks {
+    /// Sets the number of jobs, which can hold a lock at the same time
+    pub fn capacity(&mut self, key: String, capacity: usize) -> Result<(), E> {
+        if capacity == 0 {
+            return Err(E::InvalidLockCapacity(key));
+        }
+        let lock = self.locks.entry(key).or_insert_with(|| Lock::new(capacity));
+        lock.capacity = capacity;
+        lock.grant();
+        Ok(())
+    }
+
+    /// Takes a slot of lock. Returns `None` if the slot is taken immediately; otherwise
+    /// returns a receiver, which resolves as soon as the slot becomes free.
+    pub fn acquire(
+        &mut self,
+        key: String,
+        job: Uuid,
+        ancestors: Vec<Uuid>,
+    ) -> Result<Option<oneshot::Receiver<Result<(), E>>>, E> {
+        self.ancestors.insert(job, ancestors);
+        let lock = self
+            .locks
+            .entry(key.clone())
+            .or_insert_with(|| Lock::new(1));
+        if lock.taken() < lock.capacity {
+            *lock.holders.entry(job).or_insert(0) += 1;
+            return Ok(None);
+        }
+        if self.deadlock(&key, &job) {
+            return Err(E::Deadlock(key));
+        }
+        let (tx, rx) = oneshot::channel();
+        if let Some(lock) = self.locks.get_mut(&key) {
+            lock.queue.push_back((job, tx));
+        }
+        Ok(Some(rx))
+    }
+
+    pub fn release(&mut self, key: String, job: Uuid) -> Result<(), E> {
+        let Some(lock) = self.locks.get_mut(&key) else {
+            return Err(E::LockNotHeld(key));
+        };
+        let Some(taken) = lock.holders.get_mut(&job) else {
+            return Err(E::LockNotHeld(key));
+        };
+        *taken -= 1;
+        if *taken == 0 {
+            lock.holders.remove(&job);
+        }
+        lock.grant();
+        Ok(())
+    }
+
+    /// Releases all locks held by job and removes it from all queues. Returns keys of
+    /// released locks.
+    pub fn release_all(&mut self, job: Uuid) -> Vec<String> {
+        let mut released = Vec::new();
+        for (key, lock) in self.locks.iter_mut() {
+            lock.queue.retain(|(uuid, _)| uuid != &job);
+            if lock.holders.remove(&job).is_some() {
+                released.push(key.to_owned());
+            }
+            lock.grant();
+        }
+        self.ancestors.remove(&job);
+        released.sort();
+        released
+    }
+
+    /// Checks whether the job would wait forever for the lock. It happens if a holder of
+    /// lock (directly or through other locks) waits for the job itself or for one of its
+    /// ancestors. A parent job is considered as waiting for all its descendants.
+    fn deadlock(&self, key: &str, job: &Uuid) -> bool {
+        let Some(lock) = self.locks.get(key) else {
+            return false;
+        };
+        let ancestors = self.ancestors.get(job).cloned().unwrap_or_default();
+        let mut pending: Vec<Uuid> = lock.holders.keys().cloned().collect();
+        let mut visited = HashSet::new();
+        while let Some(uuid) = pending.pop() {
+            if !visited.insert(uuid) {
+                continue;
+            }
+            if &uuid == job || ancestors.contains(&uuid) {
+                return true;
+            }
+            // Holders of locks, which this job is waiting for
+            self.locks
+                .values()
+                .filter(|lock| lock.waits(&uuid))
+                .for_each(|lock| pending.extend(lock.holders.keys().cloned()));
+            // Descendants; job cannot be done until they are done
+            self.ancestors
+                .iter()
+                .filter(|(_, ancestors)| ancestors.contains(&uuid))
+                .for_each(|(descendant, _)| pending.push(*descendant));
+        }
+        false
+    }
+}
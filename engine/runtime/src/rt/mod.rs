@@ -2,6 +2,8 @@ mod api;
 mod context;
 mod jobs;
 mod journal;
+mod locks;
+mod policy;
 mod progressor;
 mod signals;
 
@@ -10,10 +12,20 @@ use api::*;
 pub use context::*;
 pub use jobs::*;
 pub use journal::*;
+pub use locks::*;
+pub use policy::*;
 pub use progressor::*;
 pub use signals::*;
 
-use std::{future::Future, pin::Pin};
+use std::{
+    future::Future,
+    pin::Pin,
+    time::{Duration, Instant},
+};
+use tokio::{
+    select,
+    sync::{watch, OwnedSemaphorePermit, Semaphore},
+};
 use tokio_util::sync::CancellationToken;
 
 pub type RtPinnedResult<'a, E> = Pin<Box<dyn Future<Output = RtResult<E>> + 'a + Send>>;
@@ -27,17 +39,137 @@ pub struct SignalsGroup<'a> {
 }
 
 impl SignalsGroup<'_> {
-    pub async fn emit_signal<S: ToString>(&self, key: S) -> Result<(), E> {
-        self.rt.emit_signal(key).await
+    pub async fn emit_signal<S: ToString>(&self, key: S, payload: RtValue) -> Result<(), E> {
+        self.rt.emit_signal(key, payload).await
     }
 
-    pub async fn wait_signal<S: ToString>(&self, key: S) -> Result<Option<CancellationToken>, E> {
-        self.rt.wait_signal(key).await
+    /// Waits for signal and returns its payload
+    pub async fn wait_signal<S: ToString>(&self, key: S) -> Result<RtValue, E> {
+        let mut rx = self.rt.wait_signal(key).await?;
+        let payload = rx.wait_for(|payload| payload.is_some()).await?;
+        Ok(payload.clone().unwrap_or(RtValue::Void))
+    }
+
+    /// Waits for signal during the given time. Returns `None` if the signal hasn't been
+    /// emitted in time.
+    pub async fn wait_signal_timeout<S: ToString>(
+        &self,
+        key: S,
+        timeout: Duration,
+    ) -> Result<Option<RtValue>, E> {
+        match tokio::time::timeout(timeout, self.wait_signal(key)).await {
+            Ok(payload) => payload.map(Some),
+            Err(_) => Ok(None),
+        }
     }
 
     pub async fn waiters_signal<S: ToString>(&self, key: S) -> Result<usize, E> {
         self.rt.waiters_signal(key).await
     }
+
+    /// Allows emitting of signal again
+    pub async fn reset_signal<S: ToString>(&self, key: S) -> Result<(), E> {
+        self.rt.reset_signal(key).await
+    }
+}
+
+pub struct LocksGroup<'a> {
+    rt: &'a Runtime,
+}
+
+impl LocksGroup<'_> {
+    /// Waits for a free slot of named lock. Waiting is interrupted if the job is cancelled.
+    pub async fn acquire<S: ToString>(&self, key: S, job: &Job) -> Result<(), E> {
+        let key = key.to_string();
+        let Some(rx) = self.rt.acquire_lock(&key, job).await? else {
+            return Ok(());
+        };
+        job.progress
+            .pending(Some(format!("waiting for lock \"{key}\"")));
+        select! {
+            res = rx => {
+                job.progress.working::<&str>(None);
+                res?
+            }
+            _ = job.cancel.cancelled() => {
+                self.rt.release_locks(job).await?;
+                Err(E::LockWaitCancelled(key))
+            }
+        }
+    }
+
+    pub async fn release<S: ToString>(&self, key: S, job: &Job) -> Result<(), E> {
+        self.rt.release_lock(key, job).await
+    }
+
+    /// Releases all locks held by job. Returns keys of released locks.
+    pub async fn release_all(&self, job: &Job) -> Result<Vec<String>, E> {
+        self.rt.release_locks(job).await
+    }
+
+    /// Sets the number of jobs, which can hold a lock at the same time
+    pub async fn capacity<S: ToString>(&self, key: S, capacity: usize) -> Result<(), E> {
+        self.rt.set_lock_capacity(key, capacity).await
+    }
+}
+
+pub struct LimitsGroup<'a> {
+    rt: &'a Runtime,
+}
+
+impl LimitsGroup<'_> {
+    /// Max depth of calls of user functions and closures
+    pub fn depth(&self) -> Option<usize> {
+        self.rt.limits.depth
+    }
+
+    /// Checks the number of done iterations of a loop and the total time of execution
+    pub fn iteration(&self, done: usize) -> Result<(), E> {
+        if let Some(limit) = self.rt.limits.iterations {
+            if done >= limit {
+                return Err(E::IterationsLimit(limit));
+            }
+        }
+        self.time()
+    }
+
+    /// Max number of processes running at the same time
+    pub fn jobs(&self) -> Option<usize> {
+        self.rt.limits.jobs
+    }
+
+    /// Tries to take a free job slot without waiting. Returns `None` if all slots are busy.
+    /// If there is no limit of jobs, a permit is always given.
+    pub fn try_slot(&self) -> Option<Option<OwnedSemaphorePermit>> {
+        match &self.rt.slots {
+            Some(slots) => slots.clone().try_acquire_owned().ok().map(Some),
+            None => Some(None),
+        }
+    }
+
+    /// Waits for a free job slot. The slot is released as soon as the permit is dropped.
+    pub async fn slot(&self) -> Result<Option<OwnedSemaphorePermit>, E> {
+        match &self.rt.slots {
+            Some(slots) => Ok(Some(
+                slots
+                    .clone()
+                    .acquire_owned()
+                    .await
+                    .map_err(|err| E::JobSlot(err.to_string()))?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Checks the total time of execution
+    pub fn time(&self) -> Result<(), E> {
+        if let Some(timeout) = self.rt.limits.timeout {
+            if self.rt.started.elapsed() >= timeout {
+                return Err(E::TimeLimit(timeout));
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -45,6 +177,11 @@ pub struct Runtime {
     pub tys: Arc<TypesTable>,
     pub fns: Arc<Fns>,
     pub tasks: Arc<Tasks>,
+    limits: RtLimits,
+    slots: Option<Arc<Semaphore>>,
+    started: Instant,
+    /// Token of root job; cancelling of it cancels all jobs
+    cancel: CancellationToken,
     tx: UnboundedSender<Demand>,
 }
 
@@ -52,15 +189,41 @@ impl Runtime {
     #[tracing::instrument]
     pub fn new(params: RtParameters, tys: TypesTable, fns: Fns, tasks: Tasks) -> Result<Self, E> {
         let (tx, mut rx) = unbounded_channel();
+        let cancel = CancellationToken::new();
         let inst = Self {
             tx,
             tys: Arc::new(tys),
             fns: Arc::new(fns),
             tasks: Arc::new(tasks),
+            limits: params.limits,
+            slots: params
+                .limits
+                .jobs
+                .map(|jobs| Arc::new(Semaphore::new(jobs.max(1)))),
+            started: Instant::now(),
+            cancel: cancel.clone(),
         };
         let cx = RtContext::new(&params.cwd);
-        let jobs = RtJobs::new(&params.cwd)?;
+        let jobs = RtJobs::new(
+            &params.cwd,
+            params.progress.unwrap_or_else(ProgressMode::detect),
+            cancel.clone(),
+        )?;
+        if let Some(timeout) = params.limits.timeout {
+            // Timer cancels all jobs; so running processes are killed and waitings are
+            // interrupted, even if no loop or call checks the time of execution
+            spawn(async move {
+                select! {
+                    _ = tokio::time::sleep(timeout) => {
+                        tracing::warn!("time limit {timeout:?} has been reached");
+                        cancel.cancel();
+                    }
+                    _ = cancel.cancelled() => {}
+                }
+            });
+        }
         let mut signals = Signals::default();
+        let mut locks = Locks::default();
         spawn(async move {
             tracing::info!("init demand's listener");
             while let Some(demand) = rx.recv().await {
@@ -69,7 +232,7 @@ impl Runtime {
                         chk_send_err!(tx.send(params.clone()), DemandId::GetRtParameters);
                     }
                     Demand::CreateContext(owner, alias, parent, tx) => {
-                        let job = match jobs.create(owner, alias, parent).await {
+                        let job = match jobs.create(owner, alias, parent, Vec::new()).await {
                             Ok(job) => job,
                             Err(err) => {
                                 chk_send_err!(tx.send(Err(err)), DemandId::CreateContext);
@@ -78,8 +241,8 @@ impl Runtime {
                         };
                         chk_send_err!(tx.send(Ok(cx.create(owner, job))), DemandId::CreateContext);
                     }
-                    Demand::EmitSignal(key, tx) => {
-                        chk_send_err!(tx.send(signals.emit(key)), DemandId::EmitSignal);
+                    Demand::EmitSignal(key, payload, tx) => {
+                        chk_send_err!(tx.send(signals.emit(key, payload)), DemandId::EmitSignal);
                     }
                     Demand::WaitSignal(key, tx) => {
                         chk_send_err!(tx.send(signals.wait(key)), DemandId::WaitSignal);
@@ -87,8 +250,49 @@ impl Runtime {
                     Demand::WaitersSignal(key, tx) => {
                         chk_send_err!(tx.send(signals.waiters(key)), DemandId::WaitersSignal);
                     }
+                    Demand::ResetSignal(key, tx) => {
+                        signals.reset(key);
+                        chk_send_err!(tx.send(()), DemandId::ResetSignal);
+                    }
+                    Demand::AcquireLock(key, job, ancestors, tx) => {
+                        chk_send_err!(
+                            tx.send(locks.acquire(key, job, ancestors)),
+                            DemandId::AcquireLock
+                        );
+                    }
+                    Demand::ReleaseLock(key, job, tx) => {
+                        chk_send_err!(tx.send(locks.release(key, job)), DemandId::ReleaseLock);
+                    }
+                    Demand::ReleaseLocks(job, tx) => {
+                        chk_send_err!(tx.send(locks.release_all(job)), DemandId::ReleaseLocks);
+                    }
+                    Demand::SetLockCapacity(key, capacity, tx) => {
+                        chk_send_err!(
+                            tx.send(locks.capacity(key, capacity)),
+                            DemandId::SetLockCapacity
+                        );
+                    }
+                    Demand::GetTimings(tx) => {
+                        chk_send_err!(tx.send(jobs.timings().await), DemandId::GetTimings);
+                    }
                     Demand::Destroy(tx) => {
                         tracing::info!("got shutdown signal");
+                        let unresolved = signals.unresolved();
+                        if !unresolved.is_empty() {
+                            match jobs.journal("signals").await {
+                                Ok(journal) => {
+                                    unresolved.into_iter().for_each(|(key, waiters)| {
+                                        journal.warn(format!(
+                                            "Signal \"{key}\" has been awaited by {waiters} waiter(s), but never emitted"
+                                        ));
+                                    });
+                                    journal.job_close();
+                                }
+                                Err(err) => {
+                                    tracing::error!("Fail to get journal: {err}");
+                                }
+                            }
+                        }
                         chk_err!(cx.destroy().await);
                         chk_err!(jobs.destroy().await);
                         chk_send_err!(tx.send(()), DemandId::Destroy);
@@ -123,16 +327,64 @@ impl Runtime {
         SignalsGroup { rt: self }
     }
 
-    pub(crate) async fn emit_signal<S: ToString>(&self, key: S) -> Result<(), E> {
+    pub fn limits(&self) -> LimitsGroup<'_> {
+        LimitsGroup { rt: self }
+    }
+
+    pub fn locks(&self) -> LocksGroup<'_> {
+        LocksGroup { rt: self }
+    }
+
+    pub(crate) async fn acquire_lock(
+        &self,
+        key: &str,
+        job: &Job,
+    ) -> Result<Option<oneshot::Receiver<Result<(), E>>>, E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::AcquireLock(
+            key.to_owned(),
+            job.uuid,
+            job.ancestors.clone(),
+            tx,
+        ))?;
+        rx.await?
+    }
+
+    pub(crate) async fn release_lock<S: ToString>(&self, key: S, job: &Job) -> Result<(), E> {
         let (tx, rx) = oneshot::channel();
-        self.tx.send(Demand::EmitSignal(key.to_string(), tx))?;
+        self.tx
+            .send(Demand::ReleaseLock(key.to_string(), job.uuid, tx))?;
+        rx.await?
+    }
+
+    pub(crate) async fn release_locks(&self, job: &Job) -> Result<Vec<String>, E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::ReleaseLocks(job.uuid, tx))?;
+        Ok(rx.await?)
+    }
+
+    pub(crate) async fn set_lock_capacity<S: ToString>(
+        &self,
+        key: S,
+        capacity: usize,
+    ) -> Result<(), E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx
+            .send(Demand::SetLockCapacity(key.to_string(), capacity, tx))?;
+        rx.await?
+    }
+
+    pub(crate) async fn emit_signal<S: ToString>(&self, key: S, payload: RtValue) -> Result<(), E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx
+            .send(Demand::EmitSignal(key.to_string(), payload, tx))?;
         rx.await?
     }
 
     pub(crate) async fn wait_signal<S: ToString>(
         &self,
         key: S,
-    ) -> Result<Option<CancellationToken>, E> {
+    ) -> Result<watch::Receiver<Option<RtValue>>, E> {
         let (tx, rx) = oneshot::channel();
         self.tx.send(Demand::WaitSignal(key.to_string(), tx))?;
         Ok(rx.await?)
@@ -144,6 +396,30 @@ impl Runtime {
         Ok(rx.await?)
     }
 
+    pub(crate) async fn reset_signal<S: ToString>(&self, key: S) -> Result<(), E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::ResetSignal(key.to_string(), tx))?;
+        Ok(rx.await?)
+    }
+
+    /// Cancels all jobs; running processes are killed and waitings are interrupted
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Returns `true` if all jobs have been cancelled (by [`Runtime::cancel`] or by the
+    /// time limit)
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel.is_cancelled()
+    }
+
+    /// Returns time frames of all jobs; it should be called before `destroy`
+    pub async fn timings(&self) -> Result<Timings, E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::GetTimings(tx))?;
+        rx.await?
+    }
+
     pub async fn destroy(&self) -> Result<(), E> {
         let (tx, rx) = oneshot::channel();
         self.tx.send(Demand::Destroy(tx))?;
@@ -0,0 +1,55 @@
+use crate::*;
+
+/// Policy of handling failed branches of `join`. A branch is failed if it returns an error
+/// or a failed `ExecuteResult`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum JoinPolicy {
+    /// The first failed branch cancels all others (default)
+    #[default]
+    FailFast,
+    /// All branches are done; failures are returned as results of branches
+    WaitAll,
+    /// All branches are done; if some of them failed, join fails with an error listing
+    /// all failures
+    Collect,
+    /// Like `WaitAll` until the number of failed branches exceeds the given limit; after
+    /// that other branches are cancelled and join fails with an error listing all failures
+    Allow(usize),
+}
+
+impl JoinPolicy {
+    /// Returns true if the given number of failed branches requires cancelling of others
+    pub fn cancels(&self, failures: usize) -> bool {
+        match self {
+            Self::FailFast => failures > 0,
+            Self::WaitAll | Self::Collect => false,
+            Self::Allow(allowed) => failures > *allowed,
+        }
+    }
+    /// Returns true if the given number of failed branches makes join failed
+    pub fn fails(&self, failures: usize) -> bool {
+        match self {
+            Self::FailFast | Self::WaitAll => false,
+            Self::Collect => failures > 0,
+            Self::Allow(allowed) => failures > *allowed,
+        }
+    }
+}
+
+impl TryFrom<&str> for JoinPolicy {
+    type Error = E;
+
+    /// Parses policy written as `fail-fast`, `wait-all`, `collect` or `allow:<failures>`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "fail-fast" => Ok(Self::FailFast),
+            "wait-all" => Ok(Self::WaitAll),
+            "collect" => Ok(Self::Collect),
+            other => other
+                .strip_prefix("allow:")
+                .and_then(|n| n.trim().parse::<usize>().ok())
+                .map(Self::Allow)
+                .ok_or(E::InvalidJoinPolicy(value.to_owned())),
+        }
+    }
+}
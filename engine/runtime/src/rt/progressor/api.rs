@@ -11,5 +11,7 @@ pub enum Demand {
     ),
     SetState(Uuid, ProgressState),
     SetMsg(Uuid, String),
+    Suspend(oneshot::Sender<()>),
+    Resume(oneshot::Sender<()>),
     Destroy(oneshot::Sender<()>),
 }
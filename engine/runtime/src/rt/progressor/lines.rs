@@ -0,0 +1,197 @@
+use crate::*;
+use std::time::Instant;
+
+struct LineRef {
+    alias: String,
+    ts: Instant,
+    /// Depth of job in the tree of jobs; root jobs have depth 0
+    deep: usize,
+    /// Last printed state; used to skip repeated lines
+    last: Option<String>,
+}
+
+impl LineRef {
+    fn prefix(&self) -> String {
+        format!("[{} +{:.1}s]", self.alias, self.ts.elapsed().as_secs_f64())
+    }
+}
+
+/// Escapes data of GitHub Actions workflow command
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escapes value of property of GitHub Actions workflow command
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Line oriented render of progress, which doesn't require terminal. Used in `Plain`,
+/// `Quiet` and `Github` modes.
+pub struct LinesRender {
+    mode: ProgressMode,
+    jobs: HashMap<Uuid, LineRef>,
+    /// Job, which has opened the current group of lines (`Github` mode only). GitHub
+    /// Actions doesn't support nested groups, so only one group can be opened.
+    group: Option<Uuid>,
+    /// Lines posted while rendering is suspended
+    buffer: Vec<String>,
+    /// Number of active suspensions
+    suspended: usize,
+}
+
+impl LinesRender {
+    pub fn new(mode: ProgressMode) -> Self {
+        Self {
+            mode,
+            jobs: HashMap::new(),
+            group: None,
+            buffer: Vec::new(),
+            suspended: 0,
+        }
+    }
+
+    pub fn add(&mut self, progress: &Progress) -> Result<(), E> {
+        let deep = if let Some(parent) = progress.parent.as_ref() {
+            self.jobs
+                .get(parent)
+                .ok_or(E::NoProgressForTask(*parent))?
+                .deep
+                + 1
+        } else {
+            0
+        };
+        let lref = LineRef {
+            alias: progress.alias.clone(),
+            ts: progress.ts,
+            deep,
+            last: None,
+        };
+        // Each task called by the root job gets own group
+        if matches!(self.mode, ProgressMode::Github) && deep == 1 && self.group.is_none() {
+            self.group = Some(progress.owner);
+            self.write(format!("::group::{}", escape_data(&lref.alias)));
+        }
+        if !matches!(self.mode, ProgressMode::Quiet) {
+            self.write(format!("{} started", lref.prefix()));
+        }
+        self.jobs.insert(progress.owner, lref);
+        Ok(())
+    }
+
+    pub fn set_state(&mut self, uuid: Uuid, state: ProgressState) {
+        let Some(lref) = self.jobs.get_mut(&uuid) else {
+            tracing::error!("Fail to find progress for job: {uuid}");
+            return;
+        };
+        let line = match state.get_msg() {
+            Some(msg) => format!("{}: {msg}", state.label()),
+            None => state.label(),
+        };
+        if lref.last.as_ref() == Some(&line) {
+            return;
+        }
+        lref.last = Some(line.clone());
+        let line = format!("{} {line}", lref.prefix());
+        let failed = matches!(state, ProgressState::Failed(..));
+        let error = format!(
+            "::error title={}::{}",
+            escape_property(&lref.alias),
+            escape_data(&state.get_msg().unwrap_or_else(|| state.label()))
+        );
+        match self.mode {
+            ProgressMode::Quiet if failed => self.write(line),
+            ProgressMode::Quiet | ProgressMode::Bars => {}
+            ProgressMode::Plain => self.write(line),
+            ProgressMode::Github => {
+                self.write(line);
+                if failed {
+                    self.write(error);
+                }
+            }
+        }
+        if state.is_finished() {
+            self.jobs.remove(&uuid);
+            if self.group == Some(uuid) {
+                self.group = None;
+                self.write("::endgroup::".to_owned());
+            }
+        }
+    }
+
+    pub fn set_msg(&mut self, uuid: Uuid, msg: String) {
+        if matches!(self.mode, ProgressMode::Quiet) {
+            return;
+        }
+        let Some(lref) = self.jobs.get(&uuid) else {
+            tracing::error!("Fail to find progress for job: {uuid}");
+            return;
+        };
+        self.write(format!("{} {msg}", lref.prefix()));
+    }
+
+    pub fn destroy(&mut self) {
+        self.suspended = 0;
+        self.flush();
+        if self.group.take().is_some() {
+            self.write("::endgroup::".to_owned());
+        }
+        self.jobs.clear();
+    }
+
+    pub fn suspend(&mut self) {
+        self.suspended += 1;
+    }
+
+    pub fn resume(&mut self) {
+        if self.suspended == 0 {
+            return;
+        }
+        self.suspended -= 1;
+        if self.suspended == 0 {
+            self.flush();
+        }
+    }
+
+    fn write(&mut self, line: String) {
+        if self.suspended > 0 {
+            self.buffer.push(line);
+        } else {
+            eprintln!("{line}");
+        }
+    }
+
+    fn flush(&mut self) {
+        self.buffer.drain(..).for_each(|line| eprintln!("{line}"));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escaping() {
+        assert_eq!(escape_data("50%\r\nnext: a, b"), "50%25%0D%0Anext: a, b");
+        assert_eq!(
+            escape_property("50%\r\nnext: a, b"),
+            "50%25%0D%0Anext%3A a%2C b"
+        );
+    }
+
+    #[tokio::test]
+    async fn finished_jobs() {
+        let progressor = RtProgress::new(ProgressMode::Quiet).expect("Progressor created");
+        let mut render = LinesRender::new(ProgressMode::Quiet);
+        let job = Progress::new(Uuid::new_v4(), "job", None, progressor.clone());
+        render.add(&job).expect("Job added");
+        render.set_state(job.owner, ProgressState::Working(None));
+        assert_eq!(render.jobs.len(), 1);
+        render.set_state(job.owner, ProgressState::Success(None));
+        assert!(render.jobs.is_empty());
+        progressor.destroy().await.expect("Progressor destroyed");
+    }
+}
@@ -1,13 +1,17 @@
 mod api;
+mod lines;
+mod mode;
 mod progress;
 mod render;
 mod state;
 
+pub use mode::*;
 pub(crate) use progress::*;
 pub(crate) use state::*;
 
 use crate::*;
 use api::*;
+use lines::*;
 use render::*;
 
 use tokio::time::{self, Duration};
@@ -17,6 +21,65 @@ enum NextTick {
     Exit,
 }
 
+/// Render of progress defined by `ProgressMode`
+enum Render {
+    Bars(ProgressRender),
+    Lines(LinesRender),
+}
+
+impl Render {
+    fn new(mode: ProgressMode) -> Result<Self, E> {
+        Ok(match mode {
+            ProgressMode::Bars => Self::Bars(ProgressRender::new()?),
+            ProgressMode::Plain | ProgressMode::Quiet | ProgressMode::Github => {
+                Self::Lines(LinesRender::new(mode))
+            }
+        })
+    }
+    fn add(&mut self, progress: &Progress) -> Result<(), E> {
+        match self {
+            Self::Bars(render) => render.add(progress),
+            Self::Lines(render) => render.add(progress),
+        }
+    }
+    fn set_state(&mut self, uuid: Uuid, state: ProgressState) {
+        match self {
+            Self::Bars(render) => render.set_state(uuid, state),
+            Self::Lines(render) => render.set_state(uuid, state),
+        }
+    }
+    fn set_msg(&mut self, uuid: Uuid, msg: String) {
+        match self {
+            Self::Bars(render) => render.set_msg(uuid, msg),
+            Self::Lines(render) => render.set_msg(uuid, msg),
+        }
+    }
+    /// Redraws bars; lines are printed immediately, so there is nothing to do for them
+    fn print(&self) {
+        if let Self::Bars(render) = self {
+            render.print();
+        }
+    }
+    fn suspend(&mut self) {
+        match self {
+            Self::Bars(render) => render.suspend(),
+            Self::Lines(render) => render.suspend(),
+        }
+    }
+    fn resume(&mut self) {
+        match self {
+            Self::Bars(render) => render.resume(),
+            Self::Lines(render) => render.resume(),
+        }
+    }
+    fn destroy(&mut self) {
+        match self {
+            Self::Bars(render) => render.destroy(),
+            Self::Lines(render) => render.destroy(),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct RtProgress {
     tx: UnboundedSender<Demand>,
@@ -24,9 +87,9 @@ pub struct RtProgress {
 
 impl RtProgress {
     #[tracing::instrument]
-    pub fn new() -> Result<Self, E> {
+    pub fn new(mode: ProgressMode) -> Result<Self, E> {
         let (tx, mut rx) = unbounded_channel();
-        let mut render: ProgressRender = ProgressRender::new()?;
+        let mut render = Render::new(mode)?;
         let instance = Self { tx };
         let this = instance.clone();
         spawn(async move {
@@ -61,6 +124,14 @@ impl RtProgress {
                         Demand::SetMsg(uuid, msg) => {
                             render.set_msg(uuid, msg);
                         }
+                        Demand::Suspend(tx) => {
+                            render.suspend();
+                            chk_send_err!(tx.send(()), DemandId::Suspend);
+                        }
+                        Demand::Resume(tx) => {
+                            render.resume();
+                            chk_send_err!(tx.send(()), DemandId::Resume);
+                        }
                         Demand::Destroy(tx) => {
                             render.destroy();
                             tracing::info!("got shutdown signal");
@@ -107,6 +178,20 @@ impl RtProgress {
         );
     }
 
+    /// Hides progress bars until `resume` is called. Calls can be nested: rendering is
+    /// resumed after the last `resume`.
+    pub async fn suspend(&self) -> Result<(), E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::Suspend(tx))?;
+        Ok(rx.await?)
+    }
+
+    pub async fn resume(&self) -> Result<(), E> {
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(Demand::Resume(tx))?;
+        Ok(rx.await?)
+    }
+
     pub async fn destroy(&self) -> Result<(), E> {
         let (tx, rx) = oneshot::channel();
         self.tx.send(Demand::Destroy(tx))?;
@@ -118,7 +203,7 @@ impl RtProgress {
 #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
 async fn visual_test() {
     use tokio::time::{self, Duration};
-    let progressor = RtProgress::new().expect("RtProgress has been created");
+    let progressor = RtProgress::new(ProgressMode::Bars).expect("RtProgress has been created");
     let mut jobs = Vec::new();
     for job in ["a", "b", "c", "d"] {
         let master = progressor
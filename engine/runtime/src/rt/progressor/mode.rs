@@ -0,0 +1,51 @@
+use std::io::IsTerminal;
+
+use crate::*;
+
+/// Defines how the progress of jobs is rendered
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ProgressMode {
+    /// Animated bars; requires terminal
+    #[default]
+    Bars,
+    /// Line by line output; each line is prefixed with the alias of job and elapsed time
+    Plain,
+    /// Only failures are printed
+    Quiet,
+    /// Same as `Plain`, but nested tasks are folded with GitHub Actions group markers
+    Github,
+}
+
+impl ProgressMode {
+    /// Selects mode by environment: `Github` on GitHub Actions, `Plain` on any other CI
+    /// (`CI` env variable is set) or if stderr isn't a terminal; `Bars` in all other cases.
+    pub fn detect() -> Self {
+        let enabled = |name: &str| {
+            std::env::var(name)
+                .map(|vl| !matches!(vl.trim(), "" | "0" | "false"))
+                .unwrap_or(false)
+        };
+        if enabled("GITHUB_ACTIONS") {
+            Self::Github
+        } else if enabled("CI") || !std::io::stderr().is_terminal() {
+            Self::Plain
+        } else {
+            Self::Bars
+        }
+    }
+}
+
+impl TryFrom<&str> for ProgressMode {
+    type Error = E;
+
+    /// Parses mode written as `bars`, `plain`, `quiet` or `github`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "bars" => Ok(Self::Bars),
+            "plain" => Ok(Self::Plain),
+            "quiet" => Ok(Self::Quiet),
+            "github" => Ok(Self::Github),
+            _ => Err(E::InvalidProgressMode(value.to_owned())),
+        }
+    }
+}
@@ -30,6 +30,15 @@ impl Progress {
         self.progressor.set_msg(&self.owner, msg);
     }
 
+    /// Hides progress bars while the terminal is used by another process
+    pub async fn suspend(&self) -> Result<(), E> {
+        self.progressor.suspend().await
+    }
+
+    pub async fn resume(&self) -> Result<(), E> {
+        self.progressor.resume().await
+    }
+
     pub fn progress(&self, done: u64, total: u64) {
         self.progressor
             .set_state(&self.owner, ProgressState::Progress(None, done, total));
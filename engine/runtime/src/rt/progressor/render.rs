@@ -1,6 +1,6 @@
 use crate::*;
 use indexmap::IndexMap;
-use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
 
 struct ProgressRef {
     pub alias: String,
@@ -93,6 +93,8 @@ pub struct ProgressRender {
     mp: MultiProgress,
     tree: IndexMap<Uuid, ProgressRef>,
     styles: Styles,
+    /// Number of active suspensions. Progress isn't rendered while it's suspended.
+    suspended: usize,
 }
 
 impl ProgressRender {
@@ -101,6 +103,7 @@ impl ProgressRender {
             mp: MultiProgress::new(),
             tree: IndexMap::new(),
             styles: Styles::new()?,
+            suspended: 0,
         })
     }
     pub fn add(&mut self, progress: &Progress) -> Result<(), E> {
@@ -143,6 +146,9 @@ impl ProgressRender {
     }
 
     pub fn print(&self) {
+        if self.suspended > 0 {
+            return;
+        }
         self.tree
             .values()
             .enumerate()
@@ -156,7 +162,31 @@ impl ProgressRender {
         self.tree.clear();
     }
 
+    pub fn suspend(&mut self) {
+        self.suspended += 1;
+        if self.suspended == 1 {
+            if let Err(err) = self.mp.clear() {
+                tracing::error!("Fail clear progress bars: {err}");
+            }
+            self.mp.set_draw_target(ProgressDrawTarget::hidden());
+        }
+    }
+
+    pub fn resume(&mut self) {
+        if self.suspended == 0 {
+            return;
+        }
+        self.suspended -= 1;
+        if self.suspended == 0 {
+            self.mp.set_draw_target(ProgressDrawTarget::stderr());
+            self.mount();
+        }
+    }
+
     fn mount(&mut self) {
+        if self.suspended > 0 {
+            return;
+        }
         if let Err(err) = self.mp.clear() {
             tracing::error!("Fail drop current progress bars: {err}. Will recreate it.");
             self.mp = MultiProgress::new();
@@ -28,6 +28,26 @@ impl ProgressState {
             | Self::Cancelled(inner, ..) => inner.replace(msg.to_string()),
         };
     }
+    /// Returns the name of state without styles
+    pub fn label(&self) -> String {
+        match self {
+            Self::Success(..) => "done".to_owned(),
+            Self::Failed(..) => "failed".to_owned(),
+            Self::Progress(.., done, total) => {
+                format!("{}%", (((*done as f64) / (*total as f64)) * 100.0) as u64)
+            }
+            Self::Pending(..) => "wait".to_owned(),
+            Self::Working(..) => "work".to_owned(),
+            Self::Cancelled(..) => "cancelled".to_owned(),
+        }
+    }
+    /// Returns `true` if job is finished
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            Self::Success(..) | Self::Failed(..) | Self::Cancelled(..)
+        )
+    }
     pub fn get_msg(&self) -> Option<String> {
         match self {
             Self::Success(inner, ..)
@@ -41,21 +61,16 @@ impl ProgressState {
 }
 impl fmt::Display for ProgressState {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = style(self.label()).bold();
         write!(
             f,
             "{}",
             match self {
-                ProgressState::Success(..) => style("done".to_owned()).bold().green(),
-                ProgressState::Failed(..) => style("failed".to_owned()).bold().red(),
-                ProgressState::Progress(.., done, total) => style(format!(
-                    "{}%",
-                    (((*done as f64) / (*total as f64)) * 100.0) as u64
-                ))
-                .bold()
-                .green(),
-                ProgressState::Pending(..) => style("wait".to_owned()).bold().blue(),
-                ProgressState::Working(..) => style("work".to_owned()).bold().green(),
-                ProgressState::Cancelled(..) => style("cancelled".to_owned()).bold().yellow(),
+                ProgressState::Success(..) | ProgressState::Progress(..) => label.green(),
+                ProgressState::Failed(..) => label.red(),
+                ProgressState::Pending(..) => label.blue(),
+                ProgressState::Working(..) => label.green(),
+                ProgressState::Cancelled(..) => label.yellow(),
             }
         )
     }
@@ -1,34 +1,66 @@
-use tokio_util::sync::CancellationToken;
+use tokio::sync::watch;
 
 use crate::*;
 
-#[derive(Debug, Clone, Default)]
+/// Named signal. Payload is `None` until the signal is emitted. All waiters get the same
+/// payload; after `reset` the signal can be emitted again.
+#[derive(Debug)]
+struct Signal {
+    tx: watch::Sender<Option<RtValue>>,
+    /// Total number of waiters of signal
+    waiters: usize,
+}
+
+impl Default for Signal {
+    fn default() -> Self {
+        Self {
+            tx: watch::Sender::new(None),
+            waiters: 0,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
 pub struct Signals {
-    signals: HashMap<String, Option<CancellationToken>>,
-    waiter: HashMap<String, usize>,
+    signals: HashMap<String, Signal>,
 }
 
 impl Signals {
-    pub fn emit(&mut self, key: String) -> Result<(), E> {
-        let entry = self
-            .signals
-            .entry(key.clone())
-            .or_insert(Some(CancellationToken::new()));
-        if let Some(tk) = entry.take() {
-            tk.cancel();
-            Ok(())
-        } else {
-            Err(E::MultipleSignalEmit(key))
+    pub fn emit(&mut self, key: String, payload: RtValue) -> Result<(), E> {
+        let signal = self.signals.entry(key.clone()).or_default();
+        if signal.tx.borrow().is_some() {
+            return Err(E::MultipleSignalEmit(key));
         }
+        signal.tx.send_replace(Some(payload));
+        Ok(())
     }
-    pub fn wait(&mut self, key: String) -> Option<CancellationToken> {
-        *self.waiter.entry(key.clone()).or_insert(0) += 1;
-        self.signals
-            .entry(key)
-            .or_insert(Some(CancellationToken::new()))
-            .clone()
+    pub fn wait(&mut self, key: String) -> watch::Receiver<Option<RtValue>> {
+        let signal = self.signals.entry(key).or_default();
+        signal.waiters += 1;
+        signal.tx.subscribe()
     }
     pub fn waiters(&self, key: String) -> usize {
-        self.waiter.get(&key).copied().unwrap_or_default()
+        self.signals
+            .get(&key)
+            .map(|signal| signal.waiters)
+            .unwrap_or_default()
+    }
+    /// Drops the payload of emitted signal, so it can be emitted again. Waiters, which
+    /// haven't got the payload yet, keep waiting for the next emit.
+    pub fn reset(&mut self, key: String) {
+        if let Some(signal) = self.signals.get_mut(&key) {
+            signal.tx.send_replace(None);
+        }
+    }
+    /// Returns keys and number of waiters of signals, which were awaited, but not emitted
+    pub fn unresolved(&self) -> Vec<(String, usize)> {
+        let mut unresolved = self
+            .signals
+            .iter()
+            .filter(|(_, signal)| signal.waiters > 0 && signal.tx.borrow().is_none())
+            .map(|(key, signal)| (key.to_owned(), signal.waiters))
+            .collect::<Vec<(String, usize)>>();
+        unresolved.sort();
+        unresolved
     }
 }
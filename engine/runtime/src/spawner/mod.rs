@@ -1,22 +1,23 @@
+mod output;
 mod status;
 
-use std::{
-    path::Path,
-    process::{ExitStatus, Stdio},
-};
+use std::{path::Path, process::ExitStatus};
 use tokio::{
-    join,
+    io::AsyncRead,
     process::{Child, Command},
     select,
 };
 use tokio_stream::StreamExt;
-use tokio_util::codec::{self, LinesCodec, LinesCodecError};
+use tokio_util::codec::{FramedRead, LinesCodec, LinesCodecError};
 
 use crate::*;
+pub use output::OutputMode;
 pub use status::*;
 
+use output::Collector;
+
 #[cfg(windows)]
-fn setup<S: AsRef<str>, P: AsRef<Path>>(cmd: S, cwd: P) -> Result<Child, E> {
+fn setup<S: AsRef<str>, P: AsRef<Path>>(cmd: S, cwd: P, mode: &OutputMode) -> Result<Child, E> {
     let (cmd, args) = parse_command(cmd.as_ref());
     let cwd_str = cwd.as_ref().to_string_lossy().to_string();
     const CREATE_NO_WINDOW: u32 = 0x08000000;
@@ -24,24 +25,24 @@ fn setup<S: AsRef<str>, P: AsRef<Path>>(cmd: S, cwd: P) -> Result<Child, E> {
         .args(args)
         .current_dir(cwd)
         .creation_flags(CREATE_NO_WINDOW)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .stdin(Stdio::piped())
+        .stdout(mode.stdio())
+        .stderr(mode.stdio())
+        .stdin(mode.stdin())
         .kill_on_drop(true)
         .spawn()
         .map_err(|e| E::SpawnSetup(e.to_string(), cwd_str))
 }
 
 #[cfg(not(windows))]
-fn setup<S: AsRef<str>, P: AsRef<Path>>(cmd: S, cwd: P) -> Result<Child, E> {
+fn setup<S: AsRef<str>, P: AsRef<Path>>(cmd: S, cwd: P, mode: &OutputMode) -> Result<Child, E> {
     let (cmd, args) = parse_command(cmd.as_ref());
     let cwd_str = cwd.as_ref().to_string_lossy().to_string();
     Command::new(cmd)
         .args(args)
         .current_dir(cwd)
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped())
-        .stdin(Stdio::piped())
+        .stdout(mode.stdio())
+        .stderr(mode.stdio())
+        .stdin(mode.stdin())
         .kill_on_drop(true)
         .spawn()
         .map_err(|e| E::SpawnSetup(e.to_string(), cwd_str))
@@ -56,32 +57,32 @@ pub async fn spawn<S: AsRef<str>, P: AsRef<Path>>(
     cmd: S,
     cwd: P,
     _owner: Uuid,
+    rt: Runtime,
     cx: Context,
 ) -> Result<SpawnStatus, E> {
-    fn post_logs(
+    async fn post_logs(
         line: Result<String, LinesCodecError>,
-        output: &mut Vec<String>,
         stdout: bool,
+        collector: &mut Collector,
         job: &Job,
     ) {
         match line {
-            Ok(line) => {
-                let trimmed = line.trim_end();
-                job.progress.msg(trimmed);
-                if stdout {
-                    job.journal.stdout(trimmed);
-                } else {
-                    job.journal.stderr(trimmed);
-                }
-                output.push(trimmed.to_owned());
-            }
+            Ok(line) => collector.push(line.trim_end(), stdout, job).await,
             Err(err) => {
                 job.journal
                     .err(format!("Error during decoding cmd output: {err}",));
             }
         }
     }
-    fn get_status(status: ExitStatus, output: Vec<String>, job: &Job) -> SpawnStatus {
+    async fn next_line(
+        stream: &mut Option<FramedRead<impl AsyncRead + Unpin, LinesCodec>>,
+    ) -> Option<Result<String, LinesCodecError>> {
+        match stream {
+            Some(stream) => stream.next().await,
+            None => None,
+        }
+    }
+    fn get_status(status: ExitStatus, output: SpawnOutput, job: &Job) -> SpawnStatus {
         if status.success() {
             job.done().success::<&str>(None);
             SpawnStatus::Success(output)
@@ -97,69 +98,97 @@ pub async fn spawn<S: AsRef<str>, P: AsRef<Path>>(
         }
     }
     let cwd_str = cwd.as_ref().to_string_lossy().to_string();
-    let mut cstdout = Vec::new();
-    let mut cstderr = Vec::new();
+    let mode = cx.output().take().await?;
     let job = cx.job.child(Uuid::new_v4(), cmd.as_ref()).await?;
-    let mut child = match setup(cmd, cwd) {
-        Ok(child) => child,
+    // Slot is held until the process is done; it's released on drop
+    let limits = rt.limits();
+    let _slot = match limits.try_slot() {
+        Some(slot) => slot,
+        None => {
+            job.progress.pending(Some("waiting for a free job slot"));
+            select! {
+                slot = limits.slot() => {
+                    job.progress.working::<&str>(None);
+                    slot?
+                }
+                _ = job.cancel.cancelled() => {
+                    job.cancel().success(Some("Cancelled while waiting for a job slot"));
+                    return Ok(SpawnStatus::Cancelled);
+                }
+            }
+        }
+    };
+    let mut collector = match Collector::new(mode.clone(), cwd.as_ref()).await {
+        Ok(collector) => collector,
         Err(err) => {
             return Ok(SpawnStatus::RunError(err.to_string()));
         }
     };
-    let mut stdout = codec::FramedRead::new(
-        child.stdout.take().ok_or_else(|| {
-            E::SpawnSetup(String::from("Fail to get stdout handle"), cwd_str.clone())
-        })?,
-        LinesCodec::default(),
-    );
-    let mut stderr = codec::FramedRead::new(
-        child.stderr.take().ok_or_else(|| {
-            E::SpawnSetup(String::from("Fail to get stderr handle"), cwd_str.clone())
-        })?,
-        LinesCodec::default(),
-    );
-    let token = job.cancel.clone();
-    let status = select! {
-        res = async {
-            join!(
-                async {
-                    while let Some(line) = stdout.next().await {
-                        post_logs(line, &mut cstdout, true, &job)
-                    }
-                },
-                async {
-                    while let Some(line) = stderr.next().await {
-                        post_logs(line, &mut cstderr, false, &job)
+    let attached = mode.attached();
+    if attached {
+        // Progress bars would break the output of process, which uses the terminal
+        job.progress.suspend().await?;
+    }
+    let status = async {
+        let mut child = match setup(cmd, cwd, &mode) {
+            Ok(child) => child,
+            Err(err) => {
+                return Ok(SpawnStatus::RunError(err.to_string()));
+            }
+        };
+        // Streams aren't available if the output isn't piped
+        let mut stdout = child
+            .stdout
+            .take()
+            .map(|stdout| FramedRead::new(stdout, LinesCodec::default()));
+        let mut stderr = child
+            .stderr
+            .take()
+            .map(|stderr| FramedRead::new(stderr, LinesCodec::default()));
+        let token = job.cancel.clone();
+        let status = select! {
+            res = async {
+                // Both streams are read in one loop to keep the order of lines
+                loop {
+                    select! {
+                        Some(line) = next_line(&mut stdout) => post_logs(line, true, &mut collector, &job).await,
+                        Some(line) = next_line(&mut stderr) => post_logs(line, false, &mut collector, &job).await,
+                        else => break,
                     }
                 }
-            );
-            child.wait().await
-        } => {
-            res.map(|status| get_status(status, [cstdout, cstderr].concat(), &job))
-                .map_err(|err| E::SpawnError(err.to_string(), cwd_str))?
-        }
-        _ = async {
-            token.cancelled().await;
-        } => {
-            job.journal.debug("Cancel signal has been gotten");
-            match child.try_wait() {
-                Ok(Some(status)) => {
-                    get_status(status, [cstdout, cstderr].concat(), &job)
-                }
-                Ok(None) => {
-                    if let Err(err) = child.kill().await {
-                        job.cancel().failed(Some(err.to_string()));
-                    } else {
-                        job.cancel().success(Some("Process has been killed"));
+                child.wait().await
+            } => {
+                let output = collector.finish(&job).await;
+                res.map(|status| get_status(status, output, &job))
+                    .map_err(|err| E::SpawnError(err.to_string(), cwd_str))?
+            }
+            _ = async {
+                token.cancelled().await;
+            } => {
+                job.journal.debug("Cancel signal has been gotten");
+                let output = collector.finish(&job).await;
+                match child.try_wait() {
+                    Ok(Some(status)) => get_status(status, output, &job),
+                    Ok(None) => {
+                        if let Err(err) = child.kill().await {
+                            job.cancel().failed(Some(err.to_string()));
+                        } else {
+                            job.cancel().success(Some("Process has been killed"));
+                        }
+                        SpawnStatus::Cancelled
+                    }
+                    Err(err) => {
+                        job.cancel().failed(Some(format!("Fail to kill process: {err}")));
+                        SpawnStatus::Cancelled
                     }
-                    SpawnStatus::Cancelled
-                }
-                Err(err) => {
-                    job.cancel().failed(Some(format!("Fail to kill process: {err}")));
-                    SpawnStatus::Cancelled
                 }
             }
-        }
-    };
-    Ok(status)
+        };
+        Ok::<SpawnStatus, E>(status)
+    }
+    .await;
+    if attached {
+        job.progress.resume().await?;
+    }
+    status
 }
@@ -0,0 +1,150 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+    process::Stdio,
+};
+use tokio::{
+    fs::File,
+    io::{AsyncWriteExt, BufWriter},
+};
+
+use crate::*;
+use spawner::SpawnOutput;
+
+/// Defines how the output of spawned process is handled
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum OutputMode {
+    /// Output is posted into the progress and the journal and kept in memory
+    #[default]
+    Stream,
+    /// Process writes directly into the terminal; output isn't kept
+    Inherit,
+    /// Process is attached to the terminal (stdin, stdout and stderr), which is required
+    /// by interactive tools; output isn't kept
+    Interactive,
+    /// Output is kept in memory only
+    Capture,
+    /// Output is discarded
+    Silent,
+    /// Same as `Stream`, but output is also written into the file. Relative path is
+    /// resolved against the current working folder of context
+    Tee(PathBuf),
+    /// Same as `Stream`, but only the last N lines of output (stdout and stderr together)
+    /// are kept in memory
+    Tail(usize),
+}
+
+impl OutputMode {
+    /// Returns the configuration of stdout and stderr of process
+    pub(crate) fn stdio(&self) -> Stdio {
+        match self {
+            Self::Inherit | Self::Interactive => Stdio::inherit(),
+            Self::Silent => Stdio::null(),
+            Self::Stream | Self::Capture | Self::Tee(..) | Self::Tail(..) => Stdio::piped(),
+        }
+    }
+
+    /// Returns the configuration of stdin of process
+    pub(crate) fn stdin(&self) -> Stdio {
+        match self {
+            Self::Interactive => Stdio::inherit(),
+            _ => Stdio::piped(),
+        }
+    }
+
+    /// Returns `true` if process writes directly into the terminal. Rendering of progress
+    /// should be suspended while such process is running.
+    pub(crate) fn attached(&self) -> bool {
+        matches!(self, Self::Inherit | Self::Interactive)
+    }
+
+    fn streamed(&self) -> bool {
+        matches!(self, Self::Stream | Self::Tee(..) | Self::Tail(..))
+    }
+}
+
+impl TryFrom<&str> for OutputMode {
+    type Error = E;
+
+    /// Parses mode written as `stream`, `inherit`, `interactive`, `capture`, `silent`,
+    /// `tee:<path>` or `tail:<lines>`
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (mode, param) = match value.split_once(':') {
+            Some((mode, param)) => (mode, Some(param)),
+            None => (value, None),
+        };
+        match (mode.trim(), param) {
+            ("stream", None) => Ok(Self::Stream),
+            ("inherit", None) => Ok(Self::Inherit),
+            ("interactive", None) => Ok(Self::Interactive),
+            ("capture", None) => Ok(Self::Capture),
+            ("silent", None) => Ok(Self::Silent),
+            ("tee", Some(path)) if !path.trim().is_empty() => {
+                Ok(Self::Tee(PathBuf::from(path.trim())))
+            }
+            ("tail", Some(lines)) => lines
+                .trim()
+                .parse::<usize>()
+                .map(Self::Tail)
+                .map_err(|_| E::InvalidOutputMode(value.to_owned())),
+            _ => Err(E::InvalidOutputMode(value.to_owned())),
+        }
+    }
+}
+
+/// Collects the output of process according to the mode
+pub(crate) struct Collector {
+    mode: OutputMode,
+    file: Option<BufWriter<File>>,
+    output: SpawnOutput,
+}
+
+impl Collector {
+    pub async fn new(mode: OutputMode, cwd: &Path) -> Result<Self, io::Error> {
+        let file = if let OutputMode::Tee(path) = &mode {
+            Some(BufWriter::new(File::create(cwd.join(path)).await?))
+        } else {
+            None
+        };
+        Ok(Self {
+            mode,
+            file,
+            output: SpawnOutput::default(),
+        })
+    }
+
+    pub async fn push(&mut self, line: &str, stdout: bool, job: &Job) {
+        if self.mode.streamed() {
+            job.progress.msg(line);
+            if stdout {
+                job.journal.stdout(line);
+            } else {
+                job.journal.stderr(line);
+            }
+        }
+        if let Some(file) = self.file.as_mut() {
+            if let Err(err) = file.write_all(format!("{line}\n").as_bytes()).await {
+                job.journal
+                    .err(format!("Fail to write cmd output into file: {err}"));
+                self.file = None;
+            }
+        }
+        let limit = if let OutputMode::Tail(len) = self.mode {
+            Some(len)
+        } else {
+            None
+        };
+        self.output.push(line, stdout, limit);
+    }
+
+    /// Flushes the file (if it's used) and returns collected output
+    pub async fn finish(&mut self, job: &Job) -> SpawnOutput {
+        if let Some(mut file) = self.file.take() {
+            if let Err(err) = file.flush().await {
+                job.journal
+                    .err(format!("Fail to write cmd output into file: {err}"));
+            }
+        }
+        std::mem::take(&mut self.output)
+    }
+}
@@ -1,7 +1,59 @@
+use std::collections::VecDeque;
+
+/// Line of output of spawned process
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub struct OutputLine {
+    pub text: String,
+    /// `true` if line has been written into stdout; `false` - into stderr
+    pub stdout: bool,
+}
+
+/// Output of spawned process
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd)]
+pub struct SpawnOutput {
+    /// Lines of stdout and stderr in the order they have been received
+    lines: VecDeque<OutputLine>,
+}
+
+impl SpawnOutput {
+    /// Adds line; if the number of kept lines exceeds the limit, the oldest line is dropped
+    pub(crate) fn push(&mut self, text: &str, stdout: bool, limit: Option<usize>) {
+        if limit == Some(0) {
+            return;
+        }
+        if limit.is_some_and(|limit| self.lines.len() >= limit) {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(OutputLine {
+            text: text.to_owned(),
+            stdout,
+        });
+    }
+
+    pub fn stdout(&self) -> impl Iterator<Item = &str> {
+        self.lines
+            .iter()
+            .filter(|ln| ln.stdout)
+            .map(|ln| ln.text.as_str())
+    }
+
+    pub fn stderr(&self) -> impl Iterator<Item = &str> {
+        self.lines
+            .iter()
+            .filter(|ln| !ln.stdout)
+            .map(|ln| ln.text.as_str())
+    }
+
+    /// Returns lines of stdout and stderr in the order they have been received
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.lines.iter().map(|ln| ln.text.as_str())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, PartialOrd)]
 pub enum SpawnStatus {
-    Success(Vec<String>),
-    Failed(Option<i32>, Vec<String>),
+    Success(SpawnOutput),
+    Failed(Option<i32>, SpawnOutput),
     RunError(String),
     Cancelled,
 }
@@ -119,6 +119,15 @@ impl TryToRs<isize> for RtValue {
     }
 }
 
+impl TryToRs<f64> for RtValue {
+    fn try_to_rs(self) -> Result<f64, E> {
+        match self {
+            RtValue::Num(n) => Ok(n),
+            _ => Err(E::FailCovertToRsType(self.to_string(), "f64".to_owned())),
+        }
+    }
+}
+
 impl TryToRs<bool> for RtValue {
     fn try_to_rs(self) -> Result<bool, E> {
         match self {
@@ -132,6 +141,7 @@ impl TryToRs<String> for RtValue {
     fn try_to_rs(self) -> Result<String, E> {
         match self {
             RtValue::Str(n) => Ok(n),
+            RtValue::PathBuf(n) => Ok(n.to_string_lossy().to_string()),
             _ => Err(E::FailCovertToRsType(self.to_string(), "String".to_owned())),
         }
     }
@@ -222,6 +232,12 @@ impl TryToRtValue for usize {
     }
 }
 
+impl TryToRtValue for f64 {
+    fn try_to_rtv(self) -> Result<RtValue, E> {
+        Ok(RtValue::Num(self))
+    }
+}
+
 impl TryToRtValue for bool {
     fn try_to_rtv(self) -> Result<RtValue, E> {
         Ok(RtValue::Bool(self))
@@ -12,6 +12,7 @@ vec_try_to_rs!(u32);
 vec_try_to_rs!(u64);
 vec_try_to_rs!(u128);
 vec_try_to_rs!(usize);
+vec_try_to_rs!(f64);
 vec_try_to_rs!(bool);
 vec_try_to_rs!(String);
 vec_try_to_rs!(PathBuf);
@@ -28,6 +29,7 @@ vec_try_to_rt_value!(u32);
 vec_try_to_rt_value!(u64);
 vec_try_to_rt_value!(u128);
 vec_try_to_rt_value!(usize);
+vec_try_to_rt_value!(f64);
 vec_try_to_rt_value!(bool);
 vec_try_to_rt_value!(String);
 vec_try_to_rt_value!(PathBuf);
@@ -0,0 +1,54 @@
+use crate::*;
+
+/// Task is done; a command finished successfully or a task returned any value except
+/// the values listed below
+pub const EXIT_SUCCESS: i32 = 0;
+/// Task returned `false`, or a command failed without exit code (terminated by signal)
+/// or wasn't run at all
+pub const EXIT_FAILURE: i32 = 1;
+/// Execution has been stopped because of a runtime error
+pub const EXIT_RUNTIME_ERROR: i32 = 5;
+/// Execution has been stopped because the time limit has been exceeded
+pub const EXIT_TIMEOUT: i32 = 124;
+/// Command has been cancelled
+pub const EXIT_CANCELLED: i32 = 130;
+
+impl ExecuteResult {
+    /// Returns exit code, which represents the result of command. A failed command
+    /// gives its own exit code.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Success(..) => EXIT_SUCCESS,
+            Self::Failed(Some(code), ..) if *code != EXIT_SUCCESS => *code,
+            Self::Failed(..) | Self::RunError(..) => EXIT_FAILURE,
+            Self::Cancelled => EXIT_CANCELLED,
+        }
+    }
+}
+
+impl RtValue {
+    /// Returns exit code, which represents the value returned by task. For a list of
+    /// values (for example results of `join`) the first not successful code is used.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Bool(false) | Self::Error => EXIT_FAILURE,
+            Self::ExecuteResult(result) => result.exit_code(),
+            Self::Vec(values) => values
+                .iter()
+                .map(|vl| vl.exit_code())
+                .find(|code| *code != EXIT_SUCCESS)
+                .unwrap_or(EXIT_SUCCESS),
+            _ => EXIT_SUCCESS,
+        }
+    }
+}
+
+impl E {
+    /// Returns exit code, which represents the error of execution
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::TimeLimit(..) => EXIT_TIMEOUT,
+            _ => EXIT_RUNTIME_ERROR,
+        }
+    }
+}
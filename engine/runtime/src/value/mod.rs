@@ -1,15 +1,20 @@
 mod converting;
+mod exit;
 
 pub use converting::*;
+pub use exit::*;
 
-use crate::{spawner::SpawnStatus, *};
+use crate::{
+    spawner::{SpawnOutput, SpawnStatus},
+    *,
+};
 use std::fmt;
 
 #[enum_ids::enum_ids(display_variant)]
 #[derive(Debug, Clone, PartialEq)]
 pub enum ExecuteResult {
-    Success(Vec<RtValue>),
-    Failed(Option<i32>, Vec<RtValue>),
+    Success(SpawnOutput),
+    Failed(Option<i32>, SpawnOutput),
     RunError(String),
     Cancelled,
 }
@@ -24,6 +29,23 @@ impl ExecuteResult {
     pub fn is_cancelled(&self) -> bool {
         matches!(self, Self::Cancelled)
     }
+    /// Returns output of command. Command, which wasn't run or was cancelled, doesn't
+    /// have output.
+    pub fn output(&self) -> Option<&SpawnOutput> {
+        match self {
+            Self::Success(output) | Self::Failed(_, output) => Some(output),
+            Self::RunError(..) | Self::Cancelled => None,
+        }
+    }
+    /// Returns exit code of command. Code is unknown if command wasn't run, was cancelled
+    /// or was terminated by signal.
+    pub fn code(&self) -> Option<i32> {
+        match self {
+            Self::Success(..) => Some(0),
+            Self::Failed(code, ..) => *code,
+            Self::RunError(..) | Self::Cancelled => None,
+        }
+    }
 }
 
 impl fmt::Display for ExecuteResult {
@@ -49,12 +71,8 @@ impl fmt::Display for ExecuteResult {
 impl From<SpawnStatus> for ExecuteResult {
     fn from(status: SpawnStatus) -> Self {
         match status {
-            SpawnStatus::Success(output) => {
-                ExecuteResult::Success(output.into_iter().map(RtValue::Str).collect())
-            }
-            SpawnStatus::Failed(code, output) => {
-                ExecuteResult::Failed(code, output.into_iter().map(RtValue::Str).collect())
-            }
+            SpawnStatus::Success(output) => ExecuteResult::Success(output),
+            SpawnStatus::Failed(code, output) => ExecuteResult::Failed(code, output),
             SpawnStatus::RunError(err) => ExecuteResult::RunError(err),
             SpawnStatus::Cancelled => ExecuteResult::Cancelled,
         }
@@ -11,6 +11,16 @@ impl Initialize for ArgumentDeclaration {
         self.r#type.initialize(scx)?;
         if let Node::Declaration(Declaration::VariableName(variable)) = self.variable.get_node() {
             let ty = self.infer_type(scx)?;
+            if let Some(default) = self.default.as_ref() {
+                default.initialize(scx)?;
+                let default_ty = default.infer_type(scx)?;
+                if !ty.compatible(&default_ty) {
+                    return Err(LinkedErr::from(
+                        E::DismatchTypes(format!("{ty} and {default_ty}")),
+                        default,
+                    ));
+                }
+            }
             scx.tys
                 .insert(
                     &variable.ident,
@@ -31,6 +41,9 @@ impl Initialize for ArgumentDeclaration {
 impl Finalization for ArgumentDeclaration {
     fn finalize(&self, scx: &mut SemanticCx) -> Result<(), LinkedErr<E>> {
         self.r#type.finalize(scx)?;
+        if let Some(default) = self.default.as_ref() {
+            default.finalize(scx)?;
+        }
         self.variable.finalize(scx)
     }
 }
@@ -44,6 +57,9 @@ impl SemanticTokensGetter for ArgumentDeclaration {
             self.r#type
                 .get_semantic_tokens(SemanticTokenContext::ArgumentDeclaration),
         );
+        if let Some(default) = self.default.as_ref() {
+            tokens.extend(default.get_semantic_tokens(SemanticTokenContext::ArgumentDeclaration));
+        }
         tokens
     }
 }
@@ -34,6 +34,7 @@ impl Initialize for ClosureDeclaration {
             args.push(UserFnArgDeclaration {
                 ty,
                 ident,
+                default: arg_dec.default.as_deref().cloned(),
                 link: n_arg.get_md().link.clone(),
             });
         }
@@ -38,6 +38,7 @@ impl Initialize for FunctionDeclaration {
             args.push(UserFnArgDeclaration {
                 ty,
                 ident,
+                default: arg_dec.default.as_deref().cloned(),
                 link: n_arg.get_md().link.clone(),
             });
         }
@@ -3,6 +3,8 @@
 #[cfg(test)]
 mod tests;
 
+use lexer::LinkedPosition;
+
 use crate::*;
 
 impl InferType for VariableDeclaration {
@@ -90,6 +92,11 @@ impl Initialize for VariableDeclaration {
                     TypeEntity::new(*&self.uuid, self.get_position(), None, Some(Ty::Undefined)),
                 )
                 .map_err(|err| LinkedErr::from(err.into(), &self.variable))?;
+            let to = variable.token.pos.to;
+            scx.untyped.insert(
+                self.uuid,
+                (LinkedPosition::new(to, to, &variable.token.src), None),
+            );
         }
         Ok(())
     }
@@ -1,14 +1,39 @@
 #[cfg(test)]
 mod tests;
 
+use lexer::LinkedPosition;
+
 use crate::*;
 
-fn get_args_tys(node: &FunctionCall, scx: &mut SemanticCx) -> Result<Vec<Ty>, LinkedErr<E>> {
+/// Creates `FnNotFound` with suggestions of known functions and attaching of module
+fn not_found(node: &FunctionCall, name: String, scx: &SemanticCx) -> E {
+    let (Some((_, first)), Some((_, last))) = (node.reference.first(), node.reference.last())
+    else {
+        return E::FnNotFound(name, Vec::new());
+    };
+    let position: LinkedPosition = (first, last).into();
+    let mut fixes = scx
+        .similar_fns(&name)
+        .into_iter()
+        .map(|fullname| Fix::rename(fullname, position.clone()))
+        .collect::<Vec<Fix>>();
+    if node.reference.len() > 1 && !scx.is_known_mod(&node.reference[0].0) {
+        fixes.push(Fix::import(&node.reference[0].0, &first.src));
+    }
+    E::FnNotFound(name, fixes)
+}
+
+/// Returns types of arguments with names of named arguments. Value of parent (`value.fn()`)
+/// goes first as positional argument.
+fn get_args_tys(
+    node: &FunctionCall,
+    scx: &mut SemanticCx,
+) -> Result<Vec<(Option<String>, Ty)>, LinkedErr<E>> {
     let mut tys = node
         .args
         .iter()
-        .map(|n| n.infer_type(scx))
-        .collect::<Result<Vec<_>, _>>()?;
+        .map(|n| Ok((super::arg_name(n), n.infer_type(scx)?)))
+        .collect::<Result<Vec<_>, LinkedErr<E>>>()?;
     if let Some(ty) = scx
         .tys
         .get()
@@ -17,7 +42,7 @@ fn get_args_tys(node: &FunctionCall, scx: &mut SemanticCx) -> Result<Vec<Ty>, Li
         .get(&node.uuid)
         .cloned()
     {
-        tys.insert(0, ty);
+        tys.insert(0, (None, ty));
     }
     Ok(tys)
 }
@@ -33,12 +58,14 @@ impl InferType for FunctionCall {
         } else {
             let last_name: String = self.get_last_name();
             let tys = get_args_tys(self, scx)?;
-            if let Some(entity) =
-                scx.lookup_fn_by_inps(&last_name, &tys.iter().collect::<Vec<&Ty>>(), &self.uuid)
-            {
+            if let Some(entity) = scx.lookup_fn_by_inps(
+                &last_name,
+                &tys.iter().map(|(_, ty)| ty).collect::<Vec<&Ty>>(),
+                &self.uuid,
+            ) {
                 Ok(entity.result_ty())
             } else {
-                Err(LinkedErr::from(E::FnNotFound(name), self))
+                Err(LinkedErr::from(not_found(self, name, scx), self))
             }
         }
     }
@@ -63,67 +90,23 @@ impl Finalization for FunctionCall {
             Some(entity)
         } else {
             let last_name: String = self.get_last_name();
-            scx.lookup_fn_by_inps(&last_name, &tys.iter().collect::<Vec<&Ty>>(), &self.uuid)
+            scx.lookup_fn_by_inps(
+                &last_name,
+                &tys.iter().map(|(_, ty)| ty).collect::<Vec<&Ty>>(),
+                &self.uuid,
+            )
         }) else {
-            return Err(LinkedErr::from(E::FnNotFound(name), self));
+            return Err(LinkedErr::from(not_found(self, name, scx), self));
         };
-        let fn_args = entity.args_tys();
-        let mut vl_tys = tys.iter();
-        let mut repeated = false;
-        for fn_arg in fn_args.iter() {
-            if repeated {
-                return Err(LinkedErr::from(E::MultipleRepeatedFnArgs, self));
-            }
-            match fn_arg {
-                Ty::Determined(..) | Ty::OneOf(..) | Ty::Variants(..) | Ty::Optional(..) => {
-                    let vl_ty = vl_tys.next().ok_or(LinkedErr::from(
-                        E::FnArgsNumberDismatch(name.clone(), fn_args.len(), tys.len()),
-                        self,
-                    ))?;
-                    let vl_ty = vl_ty.determined().ok_or(LinkedErr::from(
-                        E::FailInferDeterminedType(vl_ty.clone()),
-                        self,
-                    ))?;
-                    if !match fn_arg {
-                        Ty::Determined(arg_ty) | Ty::Variants(arg_ty) | Ty::Optional(arg_ty) => {
-                            arg_ty.compatible(vl_ty)
-                        }
-                        Ty::OneOf(arg_tys) => arg_tys.iter().any(|arg_ty| arg_ty.compatible(vl_ty)),
-                        _ => true,
-                    } {
-                        return Err(LinkedErr::from(
-                            E::DismatchTypes(format!("{fn_arg} and {vl_ty}")),
-                            self,
-                        ));
-                    }
-                }
-                Ty::Repeated(arg_ty) => {
-                    repeated = true;
-                    for vl_ty in vl_tys.by_ref() {
-                        let vl_ty = vl_ty.determined().ok_or(LinkedErr::from(
-                            E::FailInferDeterminedType(vl_ty.clone()),
-                            self,
-                        ))?;
-                        if !arg_ty.compatible(vl_ty) {
-                            return Err(LinkedErr::from(
-                                E::DismatchTypes(format!("{fn_arg} and {vl_ty}")),
-                                self,
-                            ));
-                        }
-                    }
-                }
-                Ty::Undefined | Ty::Indeterminate => {
-                    return Err(LinkedErr::from(E::InvalidFnArg, self));
-                }
-            }
-        }
-        if vl_tys.next().is_some() {
-            return Err(LinkedErr::from(
-                E::FnArgsNumberDismatch(name, fn_args.len(), tys.len()),
-                self,
-            ));
-        }
-        Ok(())
+        super::check_call_args(
+            &name,
+            &entity.args_sigs(),
+            tys,
+            &self.close,
+            !self.args.is_empty(),
+            E::FnArgsNumberDismatch,
+        )
+        .map_err(|err| LinkedErr::from(err, self))
     }
 }
 
@@ -86,6 +86,45 @@ test_success!(
     "#
 );
 
+test_success!(
+    function_call_003,
+    Anchor,
+    r#"
+    mod test {
+        fn get_num(a: num, b: bool = false, c: str = "c") {
+            a;
+        };
+    };
+    component my_component() { 
+        task task_a() {
+            test::get_num(1);
+            test::get_num(1, true);
+            test::get_num(1, true, "test");
+        }
+    };
+    "#
+);
+
+test_success!(
+    function_call_004,
+    Anchor,
+    r#"
+    mod test {
+        fn get_num(a: num, b: bool = false, c: str = "c") {
+            a;
+        };
+    };
+    component my_component() { 
+        task task_a() {
+            test::get_num(1, c = "test");
+            test::get_num(a = 1, b = true);
+            test::get_num(c = "test", a = 1);
+            let r: num = test::get_num(1, b = 1 > 2, c = "test");
+        }
+    };
+    "#
+);
+
 test_success!(
     embedded_function_call_000,
     Block,
@@ -215,3 +254,122 @@ test_fail!(
     };
     "#
 );
+
+test_fail!(
+    function_call_004,
+    Anchor,
+    r#"
+    mod test {
+        fn get_num(a: num, b: bool = false) {
+            a;
+        };
+    };
+    component my_component() { 
+        task task_a() {
+            test::get_num(b = true);
+        }
+    };
+    "#
+);
+
+test_fail!(
+    function_call_005,
+    Anchor,
+    r#"
+    mod test {
+        fn get_num(a: num, b: bool = false) {
+            a;
+        };
+    };
+    component my_component() { 
+        task task_a() {
+            test::get_num(1, d = true);
+        }
+    };
+    "#
+);
+
+test_fail!(
+    function_call_006,
+    Anchor,
+    r#"
+    mod test {
+        fn get_num(a: num, b: bool = false) {
+            a;
+        };
+    };
+    component my_component() { 
+        task task_a() {
+            test::get_num(1, a = 2);
+        }
+    };
+    "#
+);
+
+test_fail!(
+    function_call_007,
+    Anchor,
+    r#"
+    mod test {
+        fn get_num(a: num, b: bool = false) {
+            a;
+        };
+    };
+    component my_component() { 
+        task task_a() {
+            test::get_num(b = true, 1);
+        }
+    };
+    "#
+);
+
+test_fail!(
+    function_call_008,
+    Anchor,
+    r#"
+    mod test {
+        fn get_num(a: num, b: bool = false) {
+            a;
+        };
+    };
+    component my_component() { 
+        task task_a() {
+            test::get_num(1, b = "true");
+        }
+    };
+    "#
+);
+
+test_fail!(
+    function_call_009,
+    Anchor,
+    r#"
+    mod test {
+        fn get_num(a: num, b: bool = "false") {
+            a;
+        };
+    };
+    component my_component() { 
+        task task_a() {
+            test::get_num(1);
+        }
+    };
+    "#
+);
+
+test_fail!(
+    function_call_010,
+    Anchor,
+    r#"
+    mod test {
+        fn get_num(a: num, b: bool = false) {
+            a;
+        };
+    };
+    component my_component() { 
+        task task_a() {
+            test::get_num(1, true, 2);
+        }
+    };
+    "#
+);
@@ -13,12 +13,92 @@ mod compound_assignments;
 mod compound_assignments_op;
 mod function_call;
 mod logical_op;
+mod named_argument;
 mod range;
 mod task_call;
 mod variable;
 
+use lexer::Token;
+
 use crate::*;
 
+/// Returns name of argument if it's given by name (`name = value`)
+fn arg_name(node: &LinkedNode) -> Option<String> {
+    if let Node::Expression(Expression::NamedArgument(arg)) = node.get_node() {
+        Some(arg.ident.clone())
+    } else {
+        None
+    }
+}
+
+/// Checks given arguments of function or task call against declared arguments. `given`
+/// includes types of arguments and names of named arguments; `mismatch` creates an error
+/// of mismatch of arguments number.
+pub(crate) fn check_call_args(
+    name: &str,
+    sigs: &[ArgSig],
+    given: Vec<(Option<String>, Ty)>,
+    close: &Token,
+    has_args: bool,
+    mismatch: fn(String, usize, usize, Vec<Fix>) -> E,
+) -> Result<(), E> {
+    if let Some(pos) = sigs
+        .iter()
+        .position(|sig| matches!(sig.ty, Ty::Repeated(..)))
+    {
+        if pos != sigs.len() - 1 {
+            return Err(E::MultipleRepeatedFnArgs);
+        }
+    }
+    let positional = given.iter().filter(|(name, _)| name.is_none()).count();
+    let named = given.len() != positional;
+    let given_len = given.len();
+    let bound = match bind_args(sigs, given) {
+        Ok(bound) => bound,
+        Err(ArgsMismatch::TooMany) => {
+            return Err(mismatch(name.to_owned(), sigs.len(), given_len, Vec::new()));
+        }
+        Err(ArgsMismatch::Missed(missed)) => {
+            // Placeholders can be suggested only if missed arguments directly follow
+            // given positional arguments
+            let fixes = if !named
+                && missed
+                    .iter()
+                    .enumerate()
+                    .all(|(n, idx)| *idx == positional + n)
+            {
+                Fix::arguments(
+                    &missed.iter().map(|idx| sigs[*idx].ty).collect::<Vec<&Ty>>(),
+                    close,
+                    has_args,
+                )
+                .into_iter()
+                .collect()
+            } else {
+                Vec::new()
+            };
+            return Err(mismatch(name.to_owned(), sigs.len(), given_len, fixes));
+        }
+        Err(ArgsMismatch::Unknown(arg)) => return Err(E::UnknownArgument(name.to_owned(), arg)),
+        Err(ArgsMismatch::Duplicated(arg)) => return Err(E::DuplicatedArgument(arg)),
+        Err(ArgsMismatch::PositionalAfterNamed) => return Err(E::PositionalArgAfterNamed),
+    };
+    for (sig, tys) in sigs.iter().zip(bound.iter()) {
+        if matches!(sig.ty, Ty::Undefined | Ty::Indeterminate) {
+            return Err(E::InvalidFnArg);
+        }
+        for vl_ty in tys.iter() {
+            let vl_ty = vl_ty
+                .determined()
+                .ok_or(E::FailInferDeterminedType(vl_ty.clone()))?;
+            if !sig.accepts(vl_ty) {
+                return Err(E::DismatchTypes(format!("{} and {vl_ty}", sig.ty)));
+            }
+        }
+    }
+    Ok(())
+}
+
 impl InferType for Expression {
     fn infer_type(&self, scx: &mut SemanticCx) -> Result<Ty, LinkedErr<E>> {
         match self {
@@ -37,6 +117,7 @@ impl InferType for Expression {
             Expression::CompoundAssignmentsOp(n) => n.infer_type(scx),
             Expression::FunctionCall(n) => n.infer_type(scx),
             Expression::LogicalOp(n) => n.infer_type(scx),
+            Expression::NamedArgument(n) => n.infer_type(scx),
             Expression::Range(n) => n.infer_type(scx),
             Expression::TaskCall(n) => n.infer_type(scx),
             Expression::Variable(n) => n.infer_type(scx),
@@ -62,6 +143,7 @@ impl Initialize for Expression {
             Expression::CompoundAssignmentsOp(n) => n.initialize(scx),
             Expression::FunctionCall(n) => n.initialize(scx),
             Expression::LogicalOp(n) => n.initialize(scx),
+            Expression::NamedArgument(n) => n.initialize(scx),
             Expression::Range(n) => n.initialize(scx),
             Expression::TaskCall(n) => n.initialize(scx),
             Expression::Variable(n) => n.initialize(scx),
@@ -87,6 +169,7 @@ impl Finalization for Expression {
             Expression::CompoundAssignmentsOp(n) => n.finalize(scx),
             Expression::FunctionCall(n) => n.finalize(scx),
             Expression::LogicalOp(n) => n.finalize(scx),
+            Expression::NamedArgument(n) => n.finalize(scx),
             Expression::Range(n) => n.finalize(scx),
             Expression::TaskCall(n) => n.finalize(scx),
             Expression::Variable(n) => n.finalize(scx),
@@ -112,6 +195,7 @@ impl SemanticTokensGetter for Expression {
             Expression::CompoundAssignmentsOp(n) => n.get_semantic_tokens(stcx),
             Expression::FunctionCall(n) => n.get_semantic_tokens(stcx),
             Expression::LogicalOp(n) => n.get_semantic_tokens(stcx),
+            Expression::NamedArgument(n) => n.get_semantic_tokens(stcx),
             Expression::Range(n) => n.get_semantic_tokens(stcx),
             Expression::TaskCall(n) => n.get_semantic_tokens(stcx),
             Expression::Variable(n) => n.get_semantic_tokens(stcx),
@@ -0,0 +1,30 @@
+use crate::*;
+
+impl InferType for NamedArgument {
+    fn infer_type(&self, scx: &mut SemanticCx) -> Result<Ty, LinkedErr<E>> {
+        self.value.infer_type(scx)
+    }
+}
+
+impl Initialize for NamedArgument {
+    fn initialize(&self, scx: &mut SemanticCx) -> Result<(), LinkedErr<E>> {
+        self.value.initialize(scx)
+    }
+}
+
+impl Finalization for NamedArgument {
+    fn finalize(&self, scx: &mut SemanticCx) -> Result<(), LinkedErr<E>> {
+        self.value.finalize(scx)
+    }
+}
+
+impl SemanticTokensGetter for NamedArgument {
+    fn get_semantic_tokens(&self, stcx: SemanticTokenContext) -> Vec<LinkedSemanticToken> {
+        let mut tokens = vec![
+            LinkedSemanticToken::from_token(&self.token, SemanticToken::Parameter),
+            LinkedSemanticToken::from_token(&self.equals, SemanticToken::Operator),
+        ];
+        tokens.extend(self.value.get_semantic_tokens(stcx));
+        tokens
+    }
+}
@@ -28,6 +28,24 @@ test_success!(
     "#
 );
 
+test_success!(
+    task_call_002,
+    Anchor,
+    r#"
+    component comp() { 
+        task task_a(a: num, release: bool = false, target: str = "debug") {
+            true;
+        }
+        task task_b() {
+            :comp:task_a(1);
+            :comp:task_a(1, true);
+            :comp:task_a(1, target = "release");
+            :comp:task_a(release = true, a = 1);
+        }
+    };
+    "#
+);
+
 test_fail!(
     task_call_000,
     Anchor,
@@ -39,3 +57,63 @@ test_fail!(
     };
     "#
 );
+
+test_fail!(
+    task_call_001,
+    Anchor,
+    r#"
+    component comp() { 
+        task task_a(a: num, release: bool = false) {
+            true;
+        }
+        task task_b() {
+            :comp:task_a(release = true);
+        }
+    };
+    "#
+);
+
+test_fail!(
+    task_call_002,
+    Anchor,
+    r#"
+    component comp() { 
+        task task_a(a: num, release: bool = false) {
+            true;
+        }
+        task task_b() {
+            :comp:task_a(1, debug = true);
+        }
+    };
+    "#
+);
+
+test_fail!(
+    task_call_003,
+    Anchor,
+    r#"
+    component comp() { 
+        task task_a(a: num, release: bool = false) {
+            true;
+        }
+        task task_b() {
+            :comp:task_a(release = true, 1);
+        }
+    };
+    "#
+);
+
+test_fail!(
+    task_call_004,
+    Anchor,
+    r#"
+    component comp() { 
+        task task_a(a: num, release: bool = false) {
+            true;
+        }
+        task task_b() {
+            :comp:task_a(1, release = "true");
+        }
+    };
+    "#
+);
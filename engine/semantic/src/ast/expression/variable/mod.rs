@@ -6,23 +6,26 @@ impl InferType for Variable {
             .tys
             .lookup(&self.ident)
             .map_err(|err| LinkedErr::from(err.into(), self))?
-            .ok_or(LinkedErr::from(
-                E::VariableIsNotDefined(self.ident.clone()),
+            .ok_or_else(|| LinkedErr::from(E::not_defined(&self.ident, &self.token, scx), self))?;
+        let Some(ty) = ety.assigned.as_ref() else {
+            // Variable is declared without type and value (`let a;`). If a value has been
+            // assigned to it somewhere before (for example, in a nested block), the type
+            // of this value can be suggested as annotation.
+            let annotation = scx
+                .untyped
+                .get(&ety.node)
+                .and_then(|(position, ty)| Fix::annotation(ty.as_ref()?, position.clone()));
+            return Err(LinkedErr::from(
+                E::VariableIsNotDefined(self.ident.clone(), annotation.into_iter().collect()),
                 self,
-            ))?;
-        if let Some(ty) = ety.assigned.as_ref() {
-            if let (Some(..), false) = (self.negation.as_ref(), ty.bool()) {
-                Err(LinkedErr::sfrom(E::NegationToNotBool, self))
-            } else {
-                let ty = ty.clone();
-                scx.link_ty_with_node(&self.uuid, ty.clone());
-                Ok(ty)
-            }
+            ));
+        };
+        if let (Some(..), false) = (self.negation.as_ref(), ty.bool()) {
+            Err(LinkedErr::sfrom(E::NegationToNotBool, self))
         } else {
-            Err(LinkedErr::from(
-                E::VariableIsNotDefined(self.ident.clone()),
-                self,
-            ))
+            let ty = ty.clone();
+            scx.link_ty_with_node(&self.uuid, ty.clone());
+            Ok(ty)
         }
     }
 }
@@ -32,10 +35,7 @@ impl Initialize for Variable {
         scx.tys
             .lookup(&self.ident)
             .map_err(|err| LinkedErr::from(err.into(), self))?
-            .ok_or(LinkedErr::from(
-                E::VariableIsNotDefined(self.ident.clone()),
-                self,
-            ))?;
+            .ok_or_else(|| LinkedErr::from(E::not_defined(&self.ident, &self.token, scx), self))?;
         Ok(())
     }
 }
@@ -118,7 +118,6 @@ impl Finalization for LinkedNode {
                     .set(ppm.uuid(), ty.to_owned());
                 ppm.initialize(scx)?;
                 ppm.finalize(scx)?;
-                scx.link_ty_with_node(ppm.uuid(), Ty::Indeterminate);
                 ty = ppm.infer_type(scx)?;
                 scx.link_ty_with_node(ppm.uuid(), ty.to_owned());
             }
@@ -3,6 +3,21 @@ mod tests;
 
 use crate::*;
 
+/// Returns values of variants if argument is declared with variants (`"dev" | "prod"`)
+fn variants(arg: &ArgumentDeclaration) -> Vec<RtValue> {
+    let Node::Declaration(Declaration::VariableVariants(ty)) = arg.r#type.get_node() else {
+        return Vec::new();
+    };
+    ty.variants
+        .iter()
+        .filter_map(|n| match n.get_node() {
+            Node::Value(Value::PrimitiveString(vl)) => Some(RtValue::Str(vl.inner.clone())),
+            Node::Value(Value::Number(vl)) => Some(RtValue::Num(vl.inner)),
+            _ => None,
+        })
+        .collect()
+}
+
 impl InferType for Task {
     fn infer_type(&self, scx: &mut SemanticCx) -> Result<Ty, LinkedErr<E>> {
         scx.tys
@@ -45,6 +60,8 @@ impl Initialize for Task {
             args.push(TaskArgDeclaration {
                 ty,
                 ident,
+                default: arg_dec.default.as_deref().cloned(),
+                variants: variants(arg_dec),
                 link: n_arg.get_md().link.clone(),
             });
         }
@@ -17,10 +17,12 @@ impl InferType for ArgumentAssignation {
             .lookup(&variable_name)
             .map_err(|err| LinkedErr::from(err.into(), &self.left))?
             .cloned()
-            .ok_or(LinkedErr::from(
-                E::VariableIsNotDefined(variable_name.clone()),
-                &self.left,
-            ))?;
+            .ok_or_else(|| {
+                LinkedErr::from(
+                    E::not_defined(&variable_name, &variable.token, scx),
+                    &self.left,
+                )
+            })?;
         let right = self.right.infer_type(scx)?;
         if matches!(right, Ty::Indeterminate) {
             return Err(LinkedErr::from(E::IndeterminateType, &self.right));
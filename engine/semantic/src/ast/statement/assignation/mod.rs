@@ -17,10 +17,12 @@ impl InferType for Assignation {
             .lookup(&variable_name)
             .map_err(|err| LinkedErr::from(err.into(), &self.left))?
             .cloned()
-            .ok_or(LinkedErr::from(
-                E::VariableIsNotDefined(variable_name.clone()),
-                &self.left,
-            ))?;
+            .ok_or_else(|| {
+                LinkedErr::from(
+                    E::not_defined(&variable_name, &variable.token, scx),
+                    &self.left,
+                )
+            })?;
         let right = self.right.infer_type(scx)?;
         if matches!(right, Ty::Indeterminate) {
             return Err(LinkedErr::from(E::IndeterminateType, &self.right));
@@ -29,11 +31,21 @@ impl InferType for Assignation {
             return Err(LinkedErr::from(E::IndeterminateType, &self.left));
         };
         if annot.reassignable(&right) {
+            // Variable is declared without type and value (`let a;`). Keep the link to the
+            // declaration and remember the type to suggest it as annotation.
+            let node = if matches!(annot, Ty::Undefined) {
+                if let Some((_, ty @ None)) = scx.untyped.get_mut(&left.node) {
+                    *ty = Some(right.clone());
+                }
+                left.node
+            } else {
+                self.uuid
+            };
             scx.tys
                 .insert(
                     variable_name,
                     TypeEntity::new(
-                        *&self.uuid,
+                        node,
                         self.get_position(),
                         Some(right),
                         Some(annot.to_owned()),
@@ -7,22 +7,45 @@ impl InferType for Break {
 }
 
 impl Initialize for Break {
-    fn initialize(&self, _scx: &mut SemanticCx) -> Result<(), LinkedErr<E>> {
+    fn initialize(&self, scx: &mut SemanticCx) -> Result<(), LinkedErr<E>> {
+        if let (Some(label), false) = (self.label.as_ref(), self.is_assigned()) {
+            return Err(LinkedErr::from(
+                E::UnknownLoopLabel(label.to_string()),
+                self,
+            ));
+        }
+        if let Some(value) = self.value.as_ref() {
+            value.initialize(scx)?;
+            // Type of value is saved to be used by loop, which could be out of the
+            // scope of value
+            let ty = value.infer_type(scx)?;
+            scx.link_ty_with_node(&self.uuid, ty);
+        }
         Ok(())
     }
 }
 
 impl Finalization for Break {
-    fn finalize(&self, _scx: &mut SemanticCx) -> Result<(), LinkedErr<E>> {
+    fn finalize(&self, scx: &mut SemanticCx) -> Result<(), LinkedErr<E>> {
+        if let Some(value) = self.value.as_ref() {
+            value.finalize(scx)?;
+        }
         Ok(())
     }
 }
 
 impl SemanticTokensGetter for Break {
-    fn get_semantic_tokens(&self, _stcx: SemanticTokenContext) -> Vec<LinkedSemanticToken> {
-        vec![LinkedSemanticToken::from_token(
+    fn get_semantic_tokens(&self, stcx: SemanticTokenContext) -> Vec<LinkedSemanticToken> {
+        let mut tokens = vec![LinkedSemanticToken::from_token(
             &self.token,
             SemanticToken::Keyword,
-        )]
+        )];
+        if let Some(label) = self.label.as_ref() {
+            tokens.push(LinkedSemanticToken::from_token(label, SemanticToken::Label));
+        }
+        if let Some(value) = self.value.as_ref() {
+            tokens.extend(value.get_semantic_tokens(stcx));
+        }
+        tokens
     }
 }
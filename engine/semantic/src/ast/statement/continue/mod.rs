@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests;
+
+use crate::*;
+
+impl InferType for Continue {
+    fn infer_type(&self, _scx: &mut SemanticCx) -> Result<Ty, LinkedErr<E>> {
+        Ok(DeterminedTy::Void.into())
+    }
+}
+
+impl Initialize for Continue {
+    fn initialize(&self, _scx: &mut SemanticCx) -> Result<(), LinkedErr<E>> {
+        if !self.is_assigned() {
+            return Err(LinkedErr::from(
+                self.label
+                    .as_ref()
+                    .map(|label| E::UnknownLoopLabel(label.to_string()))
+                    .unwrap_or(E::NotAssignedContinue),
+                self,
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Finalization for Continue {
+    fn finalize(&self, _scx: &mut SemanticCx) -> Result<(), LinkedErr<E>> {
+        Ok(())
+    }
+}
+
+impl SemanticTokensGetter for Continue {
+    fn get_semantic_tokens(&self, _stcx: SemanticTokenContext) -> Vec<LinkedSemanticToken> {
+        let mut tokens = vec![LinkedSemanticToken::from_token(
+            &self.token,
+            SemanticToken::Keyword,
+        )];
+        if let Some(label) = self.label.as_ref() {
+            tokens.push(LinkedSemanticToken::from_token(label, SemanticToken::Label));
+        }
+        tokens
+    }
+}
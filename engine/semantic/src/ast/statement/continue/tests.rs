@@ -0,0 +1,58 @@
+use crate::*;
+
+test_success!(
+    success_continue_000,
+    Anchor,
+    r#"
+    component comp() {
+        task task_a(a: num) {
+            for n in 0..10 {
+                if n == 5 {
+                    continue;
+                }
+                a += n;
+            }
+            while a < 100 {
+                a += 1;
+                a > 50 => continue;
+            }
+            loop {
+                a += 1;
+                if a < 200 {
+                    continue;
+                }
+                break;
+            }
+        }
+    };
+    "#
+);
+
+test_fail!(
+    fail_continue_000,
+    Anchor,
+    r#"
+    component comp() {
+        task task_a(a: num) {
+            if a > 10 {
+                continue;
+            }
+        }
+    };
+    "#
+);
+
+test_fail!(
+    fail_continue_001,
+    Anchor,
+    r#"
+    component comp() {
+        task task_a(a: num) {
+            loop {
+                a += 1;
+                continue;
+            }
+        }
+    };
+    "#
+);
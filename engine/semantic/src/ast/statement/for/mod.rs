@@ -85,6 +85,9 @@ impl Initialize for For {
                     if !node.is_assigned() {
                         return Err(LinkedErr::from(E::NotAssignedBreak, node));
                     }
+                    if node.value.is_some() && node.is_target(&self.uuid) {
+                        return Err(LinkedErr::from(E::NotAllowedBreakValue, node));
+                    }
                 }
                 Node::Statement(Statement::Return(node)) => {
                     if !node.is_assigned() {
@@ -118,6 +121,9 @@ impl SemanticTokensGetter for For {
             LinkedSemanticToken::from_token(&self.token_for, SemanticToken::Keyword),
             LinkedSemanticToken::from_token(&self.token_in, SemanticToken::Keyword),
         ];
+        if let Some(label) = self.label.as_ref() {
+            tokens.push(LinkedSemanticToken::from_token(label, SemanticToken::Label));
+        }
         self.index
             .as_ref()
             .map(|n| tokens.extend(n.get_semantic_tokens(stcx)));
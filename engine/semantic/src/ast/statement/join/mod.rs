@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod tests;
+
 use crate::*;
 
 impl InferType for Join {
@@ -8,6 +11,26 @@ impl InferType for Join {
 
 impl Initialize for Join {
     fn initialize(&self, scx: &mut SemanticCx) -> Result<(), LinkedErr<E>> {
+        for option in self.options.iter() {
+            option.initialize(scx)?;
+            // Number is a limit of running branches; string is a policy of failures
+            let ty = option.infer_type(scx)?;
+            if !ty.numeric() && !matches!(ty, Ty::Determined(DeterminedTy::Str)) {
+                return Err(LinkedErr::from(
+                    E::DismatchTypes(format!(
+                        "{} or {} and {ty}",
+                        DeterminedTy::Num,
+                        DeterminedTy::Str
+                    )),
+                    option,
+                ));
+            }
+            // Policy given as literal is checked before execution
+            if let Node::Value(Value::PrimitiveString(policy)) = option.get_node() {
+                JoinPolicy::try_from(policy.inner.as_str())
+                    .map_err(|err| LinkedErr::from(err.into(), option))?;
+            }
+        }
         self.commands.iter().try_for_each(|n| n.initialize(scx))?;
         Ok(())
     }
@@ -15,6 +38,7 @@ impl Initialize for Join {
 
 impl Finalization for Join {
     fn finalize(&self, scx: &mut SemanticCx) -> Result<(), LinkedErr<E>> {
+        self.options.iter().try_for_each(|n| n.finalize(scx))?;
         self.commands.iter().try_for_each(|n| n.finalize(scx))?;
         Ok(())
     }
@@ -26,6 +50,11 @@ impl SemanticTokensGetter for Join {
             &self.token,
             SemanticToken::Keyword,
         )];
+        tokens.extend(
+            self.options
+                .iter()
+                .flat_map(|n| n.get_semantic_tokens(stcx)),
+        );
         tokens.extend(
             self.commands
                 .iter()
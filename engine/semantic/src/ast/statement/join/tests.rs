@@ -0,0 +1,40 @@
+use crate::*;
+
+test_success!(
+    success_join_000,
+    Anchor,
+    r#"
+    component comp() {
+        task task_a() {
+            let policy = "never";
+            join[2, "allow:1"](`echo a`, `echo b`);
+            join["wait-all"](`echo a`, `echo b`);
+            join[policy](`echo a`, `echo b`);
+        }
+    };
+    "#
+);
+
+test_fail!(
+    fail_join_000,
+    Anchor,
+    r#"
+    component comp() {
+        task task_a() {
+            join["never"](`echo a`, `echo b`);
+        }
+    };
+    "#
+);
+
+test_fail!(
+    fail_join_001,
+    Anchor,
+    r#"
+    component comp() {
+        task task_a() {
+            join["allow:many"](`echo a`, `echo b`);
+        }
+    };
+    "#
+);
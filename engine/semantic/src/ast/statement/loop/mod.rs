@@ -4,8 +4,45 @@ mod tests;
 use crate::*;
 
 impl InferType for Loop {
-    fn infer_type(&self, _scx: &mut SemanticCx) -> Result<Ty, LinkedErr<E>> {
-        Ok(DeterminedTy::Void.into())
+    fn infer_type(&self, scx: &mut SemanticCx) -> Result<Ty, LinkedErr<E>> {
+        let breaks = self
+            .block
+            .lookup(&[NodeTarget::Statement(&[StatementId::Break])])
+            .into_iter()
+            .filter_map(|found| {
+                if let Node::Statement(Statement::Break(node)) = found.node.get_node() {
+                    node.is_target(&self.uuid).then_some(node)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<&Break>>();
+        if breaks.iter().all(|node| node.value.is_none()) {
+            return Ok(DeterminedTy::Void.into());
+        }
+        let mut loop_ty: Option<Ty> = None;
+        for node in breaks.into_iter() {
+            let ty = if let Some(value) = node.value.as_ref() {
+                if let Some(ty) = scx.find_linked_ty(&node.uuid) {
+                    ty
+                } else {
+                    value.infer_type(scx)?
+                }
+            } else {
+                DeterminedTy::Void.into()
+            };
+            if let Some(loop_ty) = loop_ty.as_ref() {
+                if !loop_ty.compatible(&ty) {
+                    return Err(LinkedErr::from(
+                        E::DismatchTypes(format!("{loop_ty} and {ty}")),
+                        node,
+                    ));
+                }
+            } else {
+                loop_ty = Some(ty);
+            }
+        }
+        Ok(loop_ty.unwrap_or_else(|| DeterminedTy::Void.into()))
     }
 }
 
@@ -28,9 +65,26 @@ impl Initialize for Loop {
         if nodes.is_empty() {
             return Err(LinkedErr::from(E::NotBreakableLoop, self));
         }
+        // Labeled break of nested loop can target this loop or any outer loop
+        let nested = self
+            .block
+            .lookup(&[NodeTarget::Statement(&[
+                StatementId::Loop,
+                StatementId::While,
+                StatementId::For,
+            ])])
+            .into_iter()
+            .map(|found| *found.node.uuid())
+            .collect::<Vec<Uuid>>();
         for found in nodes.iter() {
             match found.node.get_node() {
                 Node::Statement(Statement::Break(node)) => {
+                    if let (Some(label), false) = (node.label.as_ref(), node.is_assigned()) {
+                        return Err(LinkedErr::from(
+                            E::UnknownLoopLabel(label.to_string()),
+                            node,
+                        ));
+                    }
                     if !node.is_assigned() {
                         return Err(LinkedErr::from(E::NotAssignedBreak, node));
                     }
@@ -48,6 +102,11 @@ impl Initialize for Loop {
         if !nodes.iter().any(|found| {
             if let Node::Statement(Statement::Break(node)) = found.node.get_node() {
                 node.is_target(&self.uuid)
+                    || node
+                        .target
+                        .as_ref()
+                        .map(|target| !nested.contains(target))
+                        .unwrap_or(false)
             } else if let Node::Statement(Statement::Return(node)) = found.node.get_node() {
                 node.is_target_included(&self.uuid)
             } else {
@@ -73,6 +132,9 @@ impl SemanticTokensGetter for Loop {
             &self.token,
             SemanticToken::Keyword,
         )];
+        if let Some(label) = self.label.as_ref() {
+            tokens.push(LinkedSemanticToken::from_token(label, SemanticToken::Label));
+        }
         tokens.extend(self.block.get_semantic_tokens(stcx));
         tokens
     }
@@ -82,6 +82,48 @@ test_success!(
     "#
 );
 
+test_success!(
+    success_loop_004,
+    Anchor,
+    r#"
+    component comp() {
+        task task_a(a: num) {
+            'outer: loop {
+                a += 1;
+                loop {
+                    if a > 10 {
+                        break 'outer a;
+                    }
+                    a += 1;
+                }
+            };
+        }
+    };
+    "#
+);
+
+test_success!(
+    success_loop_005,
+    Anchor,
+    r#"
+    component comp() {
+        task task_a(a: num) {
+            'outer: for el in 0..10 {
+                'inner: while a < 10 {
+                    a += 1;
+                    if a == el {
+                        continue 'outer;
+                    }
+                    if a > el {
+                        break 'inner;
+                    }
+                }
+            }
+        }
+    };
+    "#
+);
+
 test_fail!(
     fail_loop_000,
     Anchor,
@@ -157,3 +199,57 @@ test_fail!(
     };
     "#
 );
+
+test_fail!(
+    fail_loop_004,
+    Anchor,
+    r#"
+    component comp() {
+        task task_a(a: num) {
+            loop {
+                a += 1;
+                if a > 10 {
+                    break 'outer;
+                }
+            }
+        }
+    };
+    "#
+);
+
+test_fail!(
+    fail_loop_005,
+    Anchor,
+    r#"
+    component comp() {
+        task task_a(a: num) {
+            while a < 10 {
+                a += 1;
+                if a > 5 {
+                    break a;
+                }
+            }
+        }
+    };
+    "#
+);
+
+test_fail!(
+    fail_loop_006,
+    Anchor,
+    r#"
+    component comp() {
+        task task_a(a: num) {
+            let b = loop {
+                a += 1;
+                if a > 5 {
+                    break a;
+                }
+                if a < 0 {
+                    break "negative";
+                }
+            };
+        }
+    };
+    "#
+);
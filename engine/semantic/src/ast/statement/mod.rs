@@ -4,6 +4,7 @@ mod assignation;
 mod assigned_value;
 mod block;
 mod r#break;
+mod r#continue;
 mod r#for;
 mod r#if;
 mod join;
@@ -24,6 +25,7 @@ impl InferType for Statement {
             Statement::ArgumentAssignedValue(n) => n.infer_type(scx),
             Statement::Block(n) => n.infer_type(scx),
             Statement::Break(n) => n.infer_type(scx),
+            Statement::Continue(n) => n.infer_type(scx),
             Statement::For(n) => n.infer_type(scx),
             Statement::If(n) => n.infer_type(scx),
             Statement::Join(n) => n.infer_type(scx),
@@ -45,6 +47,7 @@ impl Initialize for Statement {
             Statement::ArgumentAssignedValue(n) => n.initialize(scx),
             Statement::Block(n) => n.initialize(scx),
             Statement::Break(n) => n.initialize(scx),
+            Statement::Continue(n) => n.initialize(scx),
             Statement::For(n) => n.initialize(scx),
             Statement::If(n) => n.initialize(scx),
             Statement::Join(n) => n.initialize(scx),
@@ -66,6 +69,7 @@ impl Finalization for Statement {
             Statement::ArgumentAssignedValue(n) => n.finalize(scx),
             Statement::Block(n) => n.finalize(scx),
             Statement::Break(n) => n.finalize(scx),
+            Statement::Continue(n) => n.finalize(scx),
             Statement::For(n) => n.finalize(scx),
             Statement::If(n) => n.finalize(scx),
             Statement::Join(n) => n.finalize(scx),
@@ -87,6 +91,7 @@ impl SemanticTokensGetter for Statement {
             Statement::ArgumentAssignedValue(n) => n.get_semantic_tokens(stcx),
             Statement::Block(n) => n.get_semantic_tokens(stcx),
             Statement::Break(n) => n.get_semantic_tokens(stcx),
+            Statement::Continue(n) => n.get_semantic_tokens(stcx),
             Statement::For(n) => n.get_semantic_tokens(stcx),
             Statement::If(n) => n.get_semantic_tokens(stcx),
             Statement::Join(n) => n.get_semantic_tokens(stcx),
@@ -46,6 +46,9 @@ impl Initialize for While {
                     if !node.is_assigned() {
                         return Err(LinkedErr::from(E::NotAssignedBreak, node));
                     }
+                    if node.value.is_some() && node.is_target(&self.uuid) {
+                        return Err(LinkedErr::from(E::NotAllowedBreakValue, node));
+                    }
                 }
                 Node::Statement(Statement::Return(node)) => {
                     if !node.is_assigned() {
@@ -79,6 +82,9 @@ impl SemanticTokensGetter for While {
             &self.token,
             SemanticToken::Keyword,
         )];
+        if let Some(label) = self.label.as_ref() {
+            tokens.push(LinkedSemanticToken::from_token(label, SemanticToken::Label));
+        }
         tokens.extend(self.comparison.get_semantic_tokens(stcx));
         tokens.extend(self.block.get_semantic_tokens(stcx));
         tokens
@@ -1,3 +1,6 @@
+use lexer::LinkedPosition;
+use std::collections::HashMap;
+
 use crate::*;
 
 #[derive(Debug)]
@@ -7,6 +10,10 @@ pub struct SemanticCx {
     pub tasks: Tasks,
     pub table: TypesTable,
     pub errs: Errors<E>,
+    /// Variables declared without type annotation and value (`let a;`). Key is the uuid
+    /// of variable's declaration; value is the position right after the name of variable
+    /// and the type of the first value assigned to it. Used to suggest type annotation.
+    pub untyped: HashMap<Uuid, (LinkedPosition, Option<Ty>)>,
     resilience: bool,
 }
 
@@ -18,6 +25,7 @@ impl SemanticCx {
             tasks: Tasks::default(),
             table: TypesTable::default(),
             errs: Errors::default(),
+            untyped: HashMap::new(),
             resilience,
         }
     }
@@ -57,6 +65,68 @@ impl SemanticCx {
     pub fn lookup_task<S: AsRef<str>>(&mut self, name: S, caller: &Uuid) -> Option<&TaskEntity> {
         self.tasks.lookup(name.as_ref(), caller)
     }
+    /// Returns names of visible variables, which are similar to the given name
+    pub fn similar_variables<S: AsRef<str>>(&self, name: S) -> Vec<String> {
+        let Ok(scope) = self.tys.get() else {
+            return Vec::new();
+        };
+        similar(
+            name,
+            scope
+                .get_all_variables(&scope.location)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(name, _)| name.as_str()),
+        )
+    }
+    /// Returns names of known functions, which could be meant by the given name: functions
+    /// with the same name declared in other modules or functions with similar names.
+    pub fn similar_fns<S: AsRef<str>>(&self, name: S) -> Vec<String> {
+        fn last(fullname: &str) -> &str {
+            fullname.rsplit("::").next().unwrap_or(fullname)
+        }
+        let name = name.as_ref();
+        let mut fullnames = self
+            .fns
+            .ufns
+            .funcs
+            .keys()
+            .chain(self.fns.efns.funcs.keys())
+            .map(|fullname| fullname.as_str())
+            .filter(|fullname| fullname != &name)
+            .collect::<Vec<&str>>();
+        fullnames.sort();
+        let found = fullnames
+            .iter()
+            .filter(|fullname| last(fullname) == last(name))
+            .map(|fullname| fullname.to_string())
+            .collect::<Vec<String>>();
+        if !found.is_empty() {
+            return found;
+        }
+        let mut found = Vec::new();
+        for similar in similar(last(name), fullnames.iter().map(|fullname| last(fullname))) {
+            for fullname in fullnames
+                .iter()
+                .filter(|fullname| last(fullname) == similar)
+            {
+                if !found.iter().any(|name| name == fullname) {
+                    found.push(fullname.to_string());
+                }
+            }
+        }
+        found
+    }
+    /// Checks whether any function is declared in the module with the given path
+    pub fn is_known_mod<S: AsRef<str>>(&self, path: S) -> bool {
+        let prefix = format!("{}::", path.as_ref());
+        self.fns
+            .ufns
+            .funcs
+            .keys()
+            .chain(self.fns.efns.funcs.keys())
+            .any(|fullname| fullname.starts_with(&prefix))
+    }
     pub fn by_node<N: InferType + Identification>(&mut self, node: &N) -> Result<(), LinkedErr<E>> {
         if self.table.has(node.uuid()) {
             // It's PPM and it's already registred
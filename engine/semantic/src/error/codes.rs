@@ -48,6 +48,12 @@ impl ErrorCode for E {
             Self::TaskArgsNumberDismatch(..) => "00042",
             Self::TypeCannotUsedInContext => "00043",
             Self::InvalidIterationSource => "00044",
+            Self::NotAssignedContinue => "00045",
+            Self::UnknownLoopLabel(..) => "00046",
+            Self::NotAllowedBreakValue => "00047",
+            Self::UnknownArgument(..) => "00048",
+            Self::DuplicatedArgument(..) => "00049",
+            Self::PositionalArgAfterNamed => "00050",
             Self::RtError(err) => err.code(),
         }
     }
@@ -96,7 +102,13 @@ impl ErrorCode for E {
             | Self::TaskNotFound(..)
             | Self::TaskArgsNumberDismatch(..)
             | Self::TypeCannotUsedInContext
-            | Self::InvalidIterationSource => ErrorSource::Semantic,
+            | Self::InvalidIterationSource
+            | Self::NotAssignedContinue
+            | Self::UnknownLoopLabel(..)
+            | Self::NotAllowedBreakValue
+            | Self::UnknownArgument(..)
+            | Self::DuplicatedArgument(..)
+            | Self::PositionalArgAfterNamed => ErrorSource::Semantic,
             Self::RtError(err) => err.src(),
         }
     }
@@ -127,7 +139,7 @@ mod test {
                 EId::NoCurrentScopeLevel => E::NoCurrentScopeLevel,
                 EId::FailToFindScope => E::FailToFindScope(Uuid::new_v4()),
                 EId::InvalidIfStatement => E::InvalidIfStatement,
-                EId::VariableIsNotDefined => E::VariableIsNotDefined(String::new()),
+                EId::VariableIsNotDefined => E::VariableIsNotDefined(String::new(), Vec::new()),
                 EId::NegationToNotBool => E::NegationToNotBool,
                 EId::UnexpectedNode => E::UnexpectedNode(NodeId::Root),
                 EId::EmptyTypeDeclaration => E::EmptyTypeDeclaration,
@@ -143,8 +155,10 @@ mod test {
                 EId::InvalidFnArg => E::InvalidFnArg,
                 EId::FnDeclarationError => E::FnDeclarationError(String::new()),
                 EId::FailInferDeterminedType => E::FailInferDeterminedType(Ty::Undefined),
-                EId::FnNotFound => E::FnNotFound(String::new()),
-                EId::FnArgsNumberDismatch => E::FnArgsNumberDismatch(String::new(), 0, 0),
+                EId::FnNotFound => E::FnNotFound(String::new(), Vec::new()),
+                EId::FnArgsNumberDismatch => {
+                    E::FnArgsNumberDismatch(String::new(), 0, 0, Vec::new())
+                }
                 EId::FailInferFnResultType => E::FailInferFnResultType(String::new()),
                 EId::FuncAlreadyRegistered => E::FuncAlreadyRegistered(String::new()),
                 EId::ClosureNotInited => E::ClosureNotInited(Uuid::new_v4()),
@@ -152,9 +166,17 @@ mod test {
                 EId::InvalidTaskArg => E::InvalidTaskArg,
                 EId::FailToGetMasterOfTask => E::FailToGetMasterOfTask,
                 EId::TaskNotFound => E::TaskNotFound(String::new()),
-                EId::TaskArgsNumberDismatch => E::TaskArgsNumberDismatch(String::new(), 0, 0),
+                EId::TaskArgsNumberDismatch => {
+                    E::TaskArgsNumberDismatch(String::new(), 0, 0, Vec::new())
+                }
                 EId::TypeCannotUsedInContext => E::TypeCannotUsedInContext,
                 EId::InvalidIterationSource => E::InvalidIterationSource,
+                EId::NotAssignedContinue => E::NotAssignedContinue,
+                EId::UnknownLoopLabel => E::UnknownLoopLabel(String::new()),
+                EId::NotAllowedBreakValue => E::NotAllowedBreakValue,
+                EId::UnknownArgument => E::UnknownArgument(String::new(), String::new()),
+                EId::DuplicatedArgument => E::DuplicatedArgument(String::new()),
+                EId::PositionalArgAfterNamed => E::PositionalArgAfterNamed,
                 EId::RtError => E::RtError(RtError::NoCurrentScope),
             }
         }
@@ -0,0 +1,187 @@
+use lexer::{LinkedPosition, TextPosition, Token};
+
+use crate::*;
+
+/// Max number of suggested names
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Kind of suggested fix
+#[derive(Debug, Clone, PartialEq)]
+pub enum FixKind {
+    /// Replace unknown name with the name of existing variable or function
+    Rename,
+    /// Attach file of module with `mod from`. Includes the name of module. Such fix
+    /// makes sense only if the file of module exists.
+    Import(String),
+    /// Add missed arguments into the call
+    Arguments,
+    /// Add type annotation to the declaration of variable
+    Annotation,
+}
+
+/// Change of the source code
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixEdit {
+    /// Position of the text to be replaced. If position is empty (`from == to`),
+    /// text will be inserted.
+    pub position: LinkedPosition,
+    pub text: String,
+}
+
+/// Suggested fix of semantic error
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fix {
+    pub kind: FixKind,
+    /// Short description of fix
+    pub title: String,
+    pub edits: Vec<FixEdit>,
+}
+
+impl Fix {
+    pub fn rename<S: ToString>(name: S, position: LinkedPosition) -> Self {
+        Self {
+            kind: FixKind::Rename,
+            title: format!("Change to \"{}\"", name.to_string()),
+            edits: vec![FixEdit {
+                position,
+                text: name.to_string(),
+            }],
+        }
+    }
+
+    /// Suggests `mod from "./{name}.sibs";` at the beginning of source
+    pub fn import<S: AsRef<str>>(name: S, src: &Uuid) -> Self {
+        let statement = format!("mod from \"./{}.sibs\";", name.as_ref());
+        Self {
+            kind: FixKind::Import(name.as_ref().to_owned()),
+            title: format!("Add {statement}"),
+            edits: vec![FixEdit {
+                position: LinkedPosition::new(
+                    TextPosition::default(),
+                    TextPosition::default(),
+                    src,
+                ),
+                text: format!("{statement}\n"),
+            }],
+        }
+    }
+
+    /// Suggests placeholders of missed arguments. Placeholders are inserted before
+    /// closing bracket of call.
+    pub fn arguments(tys: &[&Ty], close: &Token, has_args: bool) -> Option<Self> {
+        let placeholders = tys
+            .iter()
+            .filter(|ty| !matches!(ty, Ty::Repeated(..)))
+            .map(|ty| Self::placeholder(ty))
+            .collect::<Vec<String>>();
+        if placeholders.is_empty() {
+            return None;
+        }
+        Some(Self {
+            kind: FixKind::Arguments,
+            title: format!(
+                "Add missed argument{}",
+                if placeholders.len() > 1 { "s" } else { "" }
+            ),
+            edits: vec![FixEdit {
+                position: LinkedPosition::new(close.pos.from, close.pos.from, &close.src),
+                text: format!(
+                    "{}{}",
+                    if has_args { ", " } else { "" },
+                    placeholders.join(", ")
+                ),
+            }],
+        })
+    }
+
+    /// Suggests type annotation. Annotation is inserted into given position (right after
+    /// the name of variable).
+    pub fn annotation(ty: &Ty, position: LinkedPosition) -> Option<Self> {
+        let annotation = Self::annotation_of(ty.determined()?)?;
+        Some(Self {
+            kind: FixKind::Annotation,
+            title: format!("Add type annotation \"{annotation}\""),
+            edits: vec![FixEdit {
+                position,
+                text: format!(": {annotation}"),
+            }],
+        })
+    }
+
+    /// Returns type as it's written in the source code. Returns `None` if the type cannot
+    /// be used as annotation.
+    fn annotation_of(ty: &DeterminedTy) -> Option<String> {
+        match ty {
+            DeterminedTy::Num => Some(String::from("num")),
+            DeterminedTy::Bool => Some(String::from("bool")),
+            DeterminedTy::Str => Some(String::from("str")),
+            DeterminedTy::Vec(Some(ty)) => Some(format!("Vec<{}>", Self::annotation_of(ty)?)),
+            _ => None,
+        }
+    }
+
+    fn placeholder(ty: &Ty) -> String {
+        match ty {
+            Ty::Determined(ty) | Ty::Variants(ty) | Ty::Optional(ty) => match ty {
+                DeterminedTy::Num => String::from("0"),
+                DeterminedTy::Bool => String::from("false"),
+                DeterminedTy::Str | DeterminedTy::PathBuf => String::from("\"\""),
+                DeterminedTy::Vec(..) => String::from("[]"),
+                _ => String::from("_"),
+            },
+            Ty::OneOf(tys) => tys
+                .first()
+                .map(|ty| Self::placeholder(&Ty::Determined(ty.clone())))
+                .unwrap_or_else(|| String::from("_")),
+            _ => String::from("_"),
+        }
+    }
+}
+
+/// Returns names similar to the given one, the most similar first.
+pub(crate) fn similar<'a, S: AsRef<str>, I: Iterator<Item = &'a str>>(
+    name: S,
+    candidates: I,
+) -> Vec<String> {
+    let name = name.as_ref();
+    let limit = (name.chars().count() / 3).max(1);
+    let mut similar = candidates
+        .filter(|candidate| *candidate != name)
+        .filter_map(|candidate| {
+            let distance = distance(name, candidate);
+            (distance <= limit).then_some((distance, candidate))
+        })
+        .collect::<Vec<(usize, &str)>>();
+    similar.sort();
+    similar.dedup();
+    similar
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.to_owned())
+        .collect()
+}
+
+/// Edit distance (insertions, deletions, substitutions and transpositions of adjacent chars)
+fn distance(left: &str, right: &str) -> usize {
+    let left = left.chars().collect::<Vec<char>>();
+    let right = right.chars().collect::<Vec<char>>();
+    let mut matrix = vec![vec![0; right.len() + 1]; left.len() + 1];
+    for (i, row) in matrix.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in matrix[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    for i in 1..=left.len() {
+        for j in 1..=right.len() {
+            let cost = usize::from(left[i - 1] != right[j - 1]);
+            matrix[i][j] = (matrix[i - 1][j] + 1)
+                .min(matrix[i][j - 1] + 1)
+                .min(matrix[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && left[i - 1] == right[j - 2] && left[i - 2] == right[j - 1] {
+                matrix[i][j] = matrix[i][j].min(matrix[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    matrix[left.len()][right.len()]
+}
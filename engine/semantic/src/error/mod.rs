@@ -1,8 +1,12 @@
 mod codes;
+mod fix;
+
+pub use fix::*;
 
 use crate::*;
 
 use enum_ids::enum_ids;
+use lexer::Token;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -41,7 +45,7 @@ pub enum E {
     #[error("If statement doesn't have any blocks")]
     InvalidIfStatement,
     #[error("Variable \"{0}\" isn't defined")]
-    VariableIsNotDefined(String),
+    VariableIsNotDefined(String, Vec<Fix>),
     #[error("Nagation condition can be used only with bool types")]
     NegationToNotBool,
     #[error("Unexpected node: {0}")]
@@ -73,9 +77,9 @@ pub enum E {
     #[error("Fail to infer determined type from: {0}")]
     FailInferDeterminedType(Ty),
     #[error("Function \"{0}\" not found")]
-    FnNotFound(String),
+    FnNotFound(String, Vec<Fix>),
     #[error("Function \"{0}\" expect {1} arguments; got: {2}")]
-    FnArgsNumberDismatch(String, usize, usize),
+    FnArgsNumberDismatch(String, usize, usize, Vec<Fix>),
     #[error("Fail to infer type of function \"{0}\"")]
     FailInferFnResultType(String),
     #[error("Function \"{0}\" has been registred already")]
@@ -91,16 +95,51 @@ pub enum E {
     #[error("Task \"{0}\" not found")]
     TaskNotFound(String),
     #[error("Task \"{0}\" expect {1} arguments; got: {2}")]
-    TaskArgsNumberDismatch(String, usize, usize),
+    TaskArgsNumberDismatch(String, usize, usize, Vec<Fix>),
     #[error("This type cannot be used in this context")]
     TypeCannotUsedInContext,
     #[error("Invalid iteration source; available: Range, Vec, Str")]
     InvalidIterationSource,
+    #[error("Continue can be used only inside loops")]
+    NotAssignedContinue,
+    #[error("Loop with label {0} isn't found")]
+    UnknownLoopLabel(String),
+    #[error("Break with value can be used only inside \"loop\"")]
+    NotAllowedBreakValue,
+    #[error("\"{0}\" doesn't have argument \"{1}\"")]
+    UnknownArgument(String, String),
+    #[error("Argument \"{0}\" is given multiple times")]
+    DuplicatedArgument(String),
+    #[error("Positional argument cannot follow named argument")]
+    PositionalArgAfterNamed,
 
     #[error("Runtime error: {0}")]
     RtError(RtError),
 }
 
+impl E {
+    /// Creates `VariableIsNotDefined` with suggestions of similar names of variables
+    pub(crate) fn not_defined(ident: &str, token: &Token, scx: &SemanticCx) -> Self {
+        Self::VariableIsNotDefined(
+            ident.to_owned(),
+            scx.similar_variables(ident)
+                .into_iter()
+                .map(|name| Fix::rename(name, token.into()))
+                .collect(),
+        )
+    }
+    /// Returns suggested fixes of error
+    pub fn fixes(&self) -> &[Fix] {
+        match self {
+            Self::VariableIsNotDefined(.., fixes)
+            | Self::FnNotFound(.., fixes)
+            | Self::FnArgsNumberDismatch(.., fixes)
+            | Self::TaskArgsNumberDismatch(.., fixes) => fixes,
+            _ => &[],
+        }
+    }
+}
+
 impl From<RtError> for E {
     fn from(err: RtError) -> Self {
         Self::RtError(err)
@@ -11,6 +11,7 @@ pub use context::*;
 pub(crate) use diagnostics::*;
 pub use error::E as SemanticError;
 pub(crate) use error::*;
+pub use error::{Fix, FixEdit, FixKind};
 pub(crate) use runtime::*;
 pub use token::*;
 pub(crate) use uuid::Uuid;
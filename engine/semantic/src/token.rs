@@ -22,6 +22,8 @@ pub enum SemanticToken {
     Task,
     Component,
     Module,
+    /// Label of loop
+    Label,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -5,7 +5,7 @@ use syn::{GenericArgument, Ident, PathArguments, Type};
 fn get_ty_by_ident(ident: &Ident) -> Result<TokenStream, String> {
     match ident.to_string().as_str() {
         "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
-        | "isize" => Ok(quote! { DeterminedTy::Num }),
+        | "isize" | "f64" => Ok(quote! { DeterminedTy::Num }),
         "bool" => Ok(quote! { DeterminedTy::Bool}),
         "String" => Ok(quote! { DeterminedTy::Str}),
         "PathBuf" => Ok(quote! { DeterminedTy::PathBuf }),